@@ -112,7 +112,9 @@ def load_rust_data(test, size):
     result = dict()
 
     for alg in algorithms.keys():
-        result[alg] = np.loadtxt(f"rust/{alg}-{test}-e{size}.csv", skiprows=1)
+        result[alg] = np.loadtxt(
+            f"rust/{alg}-{test}-e{size}.csv", delimiter=",", skiprows=1, usecols=0
+        )
 
     return result
 
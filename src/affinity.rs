@@ -0,0 +1,81 @@
+//! Pinning the measuring thread to a single core, see `--pin-core`.
+
+/// Pins the current thread to the given `core`, so it is not migrated between cores while
+/// measuring.
+///
+/// # Errors
+///
+/// Returns the OS error if the thread could not be pinned, e.g. because `core` does not exist.
+#[cfg(target_os = "linux")]
+pub fn pin_current_thread(core: usize) -> std::io::Result<()> {
+    if core >= usize::try_from(libc::CPU_SETSIZE).unwrap() {
+        return Err(std::io::Error::from(std::io::ErrorKind::InvalidInput));
+    }
+
+    // SAFETY: `cpu_set_t` is a plain bit set, for which all zeros is the valid empty set. `core` is
+    // within the bounds of the set, and the set outlives the system call reading it.
+    let result = unsafe {
+        let mut set: libc::cpu_set_t = std::mem::zeroed();
+        libc::CPU_SET(core, &mut set);
+        libc::sched_setaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &set)
+    };
+
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(std::io::Error::last_os_error())
+    }
+}
+
+/// Pinning threads is only supported on Linux, so this only prints a warning.
+#[cfg(not(target_os = "linux"))]
+pub fn pin_current_thread(core: usize) -> std::io::Result<()> {
+    eprintln!("Pinning to core {core} is only supported on Linux, the thread may migrate");
+    Ok(())
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    /// Returns the first core the current thread is allowed to run on.
+    fn allowed_core() -> usize {
+        // SAFETY: `cpu_set_t` is a plain bit set, for which all zeros is the valid empty set, and
+        // it outlives the system call writing it.
+        let (result, set) = unsafe {
+            let mut set: libc::cpu_set_t = std::mem::zeroed();
+            let result =
+                libc::sched_getaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &mut set);
+            (result, set)
+        };
+        assert_eq!(
+            result,
+            0,
+            "sched_getaffinity failed: {}",
+            std::io::Error::last_os_error()
+        );
+
+        (0..usize::try_from(libc::CPU_SETSIZE).unwrap())
+            // SAFETY: The core is within the bounds of the set
+            .find(|&core| unsafe { libc::CPU_ISSET(core, &set) })
+            .expect("The current thread is allowed to run on some core")
+    }
+
+    #[test]
+    fn test_pin_current_thread() {
+        // Pin a separate thread, to not restrict the other tests
+        std::thread::spawn(|| {
+            // Not every core may be available, e.g. in containers or with `taskset`
+            let core = allowed_core();
+            pin_current_thread(core).unwrap();
+
+            // SAFETY: `sched_getcpu` has no preconditions
+            let current = unsafe { libc::sched_getcpu() };
+            assert_eq!(usize::try_from(current).unwrap(), core);
+
+            assert!(pin_current_thread(usize::MAX).is_err());
+        })
+        .join()
+        .unwrap();
+    }
+}
@@ -1,8 +1,24 @@
 //! Contains various sorting algorithms see e.g. [`Sort`].
 
+/// Asserts an internal invariant of a sorting algorithm, only if the `validate` feature is enabled
+/// or when testing.
+///
+/// The condition is still type checked without the feature, but compiled out, so benchmark builds
+/// do not pay for it.
+macro_rules! validate_assert {
+    ($($arg:tt)*) => {
+        if cfg!(any(feature = "validate", test)) {
+            assert!($($arg)*);
+        }
+    };
+}
+
+pub mod blocksort;
+pub mod cyclesort;
 pub mod insertionsort;
 pub mod mergesort;
 pub mod merging;
+pub mod patiencesort;
 pub mod peeksort;
 pub mod powersort;
 pub mod quicksort;
@@ -19,8 +35,48 @@ pub trait Sort {
     /// Returns an iterator over the algorithm parameters and their values.
     fn parameters() -> impl Iterator<Item = (&'static str, String)>;
 
+    /// Returns the name of the algorithm including its parameters, e.g.
+    /// `powersort(min-run-len=24, ...)`, identifying it in reports and panic messages.
+    fn name() -> String {
+        let parameters: Vec<String> = Self::parameters()
+            .map(|(key, value)| format!("{key}={value}"))
+            .collect();
+
+        if parameters.is_empty() {
+            Self::BASE_NAME.to_string()
+        } else {
+            format!("{}({})", Self::BASE_NAME, parameters.join(", "))
+        }
+    }
+
+    /// Returns the number of bytes of auxiliary buffer memory allocated by [`Self::sort`] for a
+    /// slice of `len` elements of type `T`.
+    ///
+    /// Sorts not overriding this are assumed to sort in place.
+    fn buffer_bytes<T>(_len: usize) -> usize {
+        0
+    }
+
+    /// Returns the description of the merging method used by [`Self::sort`], including its
+    /// stability, see [`crate::cli::display_merging()`].
+    ///
+    /// Sorts not overriding this are assumed not to merge runs.
+    fn merging() -> Option<String> {
+        None
+    }
+
     /// Sorts the given slice.
     fn sort<T: Ord>(slice: &mut [T]);
+
+    /// Sorts the given slice in descending order.
+    ///
+    /// By default the result of [`Self::sort`] is reversed, which reverses the relative order of
+    /// equal elements as well. Stable sorts can override this to sort with the reversed ordering
+    /// instead, keeping equal elements in their initial order, see [`Descending`].
+    fn sort_descending<T: Ord>(slice: &mut [T]) {
+        Self::sort(slice);
+        slice.reverse();
+    }
 }
 
 /// A sorting algorithm that takes slices with a prefix partition already sorted
@@ -34,6 +90,15 @@ pub trait PostfixSort: Sort {
     fn sort_with_sorted_prefix<T: Ord>(slice: &mut [T], split_point: usize);
 }
 
+/// A sorting algorithm that can sort only the smallest elements of a slice
+pub trait PartialSort: Sort {
+    /// Moves the `k` smallest elements of `slice` into `slice[..k]` in sorted order, leaving
+    /// `slice[k..]` in an unspecified order.
+    ///
+    /// If `k >= slice.len()`, the whole slice is sorted.
+    fn sort_first_k<T: Ord>(slice: &mut [T], k: usize);
+}
+
 /// The Standard library sort
 pub struct StdSort<const STABLE: bool = true>;
 
@@ -55,6 +120,231 @@ impl<const STABLE: bool> Sort for StdSort<STABLE> {
     }
 }
 
+/// The Standard library sort, comparing through a closure passed to `sort_by`/`sort_unstable_by`
+///
+/// Every comparison goes through [`Ord::cmp`], so comparison counting wrappers like
+/// [`CountComparisons`](crate::data::CountComparisons) observe all of them, even though std
+/// does not expose its comparison counts otherwise.
+#[cfg(any(feature = "counters", test))]
+pub struct StdSortBy<const STABLE: bool = true>;
+
+#[cfg(any(feature = "counters", test))]
+impl<const STABLE: bool> Sort for StdSortBy<STABLE> {
+    const IS_STABLE: bool = STABLE;
+
+    const BASE_NAME: &str = "std-by";
+
+    fn parameters() -> impl Iterator<Item = (&'static str, String)> {
+        vec![("stable", STABLE.to_string())].into_iter()
+    }
+
+    fn sort<T: Ord>(slice: &mut [T]) {
+        if STABLE {
+            slice.sort_by(T::cmp);
+        } else {
+            slice.sort_unstable_by(T::cmp);
+        }
+    }
+}
+
+/// A [`Sort`] running `S` and unconditionally checking afterwards, that the slice is sorted (and
+/// that equal elements kept their initial relative ordering, if `S` is stable).
+///
+/// Panics with the first index violating the postcondition. Stable sorts are performed on index
+/// tagged references, which are applied to the slice afterwards, so running times are distorted.
+pub struct Checked<S: Sort>(std::marker::PhantomData<S>);
+
+impl<S: Sort> Sort for Checked<S> {
+    const IS_STABLE: bool = S::IS_STABLE;
+
+    const BASE_NAME: &str = S::BASE_NAME;
+
+    fn parameters() -> impl Iterator<Item = (&'static str, String)> {
+        S::parameters().chain(std::iter::once(("checked", true.to_string())))
+    }
+
+    fn buffer_bytes<T>(len: usize) -> usize {
+        // Does not account for stable sorts sorting index tagged references instead
+        S::buffer_bytes::<T>(len)
+    }
+
+    fn merging() -> Option<String> {
+        S::merging()
+    }
+
+    fn sort<T: Ord>(slice: &mut [T]) {
+        if !S::IS_STABLE {
+            S::sort(slice);
+            Self::check_sorted(slice);
+            return;
+        }
+
+        let mut tagged: Vec<_> = crate::data::IndexedOrdered::map_iter(slice.iter()).collect();
+        S::sort(&mut tagged);
+
+        Self::check_sorted(&tagged);
+        if let Some(index) = crate::data::IndexedOrdered::first_unstable_index(&tagged) {
+            panic!(
+                "{name} is not stable: {error}",
+                name = S::name(),
+                error = crate::VerificationError::Unstable(index),
+            );
+        }
+
+        let mut permutation: Vec<usize> = tagged.iter().map(|element| element.index()).collect();
+        drop(tagged);
+
+        gather_permutation(slice, &mut permutation);
+    }
+}
+
+impl<S: Sort> Checked<S> {
+    /// Panics if `slice` is not sorted, reporting the first element smaller than its predecessor.
+    fn check_sorted<T: Ord>(slice: &[T]) {
+        if let Some(index) = slice.windows(2).position(|pair| pair[0] > pair[1]) {
+            panic!(
+                "{name} did not sort: {error}",
+                name = S::name(),
+                error = crate::VerificationError::Unsorted(index + 1),
+            );
+        }
+    }
+}
+
+/// A [`Sort`] sorting pairs of keys and initial indices with `S`, permuting the slice accordingly
+/// afterwards, see [`CachedKeySort::sort_by_cached_key()`].
+///
+/// Equal keys are ordered by their initial index, so the result is stable even for unstable `S`.
+/// As a [`Sort`], elements are their own keys and only references to them are sorted.
+pub struct CachedKeySort<S: Sort = StdSort>(std::marker::PhantomData<S>);
+
+impl<S: Sort> Sort for CachedKeySort<S> {
+    const IS_STABLE: bool = true;
+
+    const BASE_NAME: &str = "cached-key";
+
+    fn parameters() -> impl Iterator<Item = (&'static str, String)> {
+        vec![("sort", crate::cli::display_inline::<S>())].into_iter()
+    }
+
+    fn buffer_bytes<T>(len: usize) -> usize {
+        // The keys, the permutation and the buffer of `S` for sorting the keys
+        len * (std::mem::size_of::<(&T, usize)>() + std::mem::size_of::<usize>())
+            + S::buffer_bytes::<(&T, usize)>(len)
+    }
+
+    fn sort<T: Ord>(slice: &mut [T]) {
+        let mut keys: Vec<(&T, usize)> = slice.iter().zip(0..).collect();
+        S::sort(&mut keys);
+
+        let mut permutation: Vec<usize> = keys.iter().map(|&(_, index)| index).collect();
+        drop(keys);
+
+        gather_permutation(slice, &mut permutation);
+    }
+}
+
+impl<S: Sort> CachedKeySort<S> {
+    /// Sorts `slice` by the keys `f` returns, evaluating `f` exactly once per element, like
+    /// [`slice::sort_by_cached_key()`].
+    #[allow(dead_code, reason = "Currently only used in tests")]
+    pub fn sort_by_cached_key<T, K: Ord, F: Fn(&T) -> K>(slice: &mut [T], f: F) {
+        let mut keys: Vec<(K, usize)> = slice.iter().map(f).zip(0..).collect();
+        S::sort(&mut keys);
+
+        let mut permutation: Vec<usize> = keys.iter().map(|&(_, index)| index).collect();
+        drop(keys);
+
+        gather_permutation(slice, &mut permutation);
+    }
+}
+
+/// Sorts `slice` stable with [`powersort::PowerSort`] and returns the applied permutation, i.e.
+/// the initial index of the element at each position of the sorted `slice`.
+#[allow(dead_code, reason = "Currently only used in tests")]
+pub fn sort_tracked<T: Ord>(slice: &mut [T]) -> Vec<usize> {
+    let mut keys: Vec<(&T, usize)> = slice.iter().zip(0..).collect();
+    <powersort::PowerSort as Sort>::sort(&mut keys);
+
+    let indices: Vec<usize> = keys.iter().map(|&(_, index)| index).collect();
+    drop(keys);
+
+    gather_permutation(slice, &mut indices.clone());
+
+    indices
+}
+
+/// An element compared in reversed order, so sorting a slice of them sorts the inner elements in
+/// descending order, see [`Sort::sort_descending()`].
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct Descending<T>(T);
+
+impl<T> Descending<T> {
+    /// Views `slice` as a slice of [`Descending`] elements.
+    pub fn from_mut_slice(slice: &mut [T]) -> &mut [Self] {
+        // SAFETY: `Descending<T>` is a transparent wrapper around `T`, so both slices have the
+        // same layout. The lifetime of the new slice is bound to the borrow of `slice`.
+        unsafe { std::slice::from_raw_parts_mut(slice.as_mut_ptr().cast(), slice.len()) }
+    }
+}
+
+impl<T: Ord> PartialEq for Descending<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: Ord> Eq for Descending<T> {}
+
+impl<T: Ord> PartialOrd for Descending<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Ord> Ord for Descending<T> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        other.0.cmp(&self.0)
+    }
+}
+
+/// Moves the element initially at `slice[permutation[i]]` to `slice[i]`, cycle by cycle.
+///
+/// `permutation` is left as the identity.
+///
+/// # Panics
+///
+/// Panics if `permutation` is not a permutation of `0..slice.len()`, e.g. because a broken sort
+/// duplicated an element and lost another. Gathering would not terminate otherwise.
+fn gather_permutation<T>(slice: &mut [T], permutation: &mut [usize]) {
+    assert_eq!(
+        permutation.len(),
+        slice.len(),
+        "Permutation of the wrong length"
+    );
+
+    let mut seen = vec![false; permutation.len()];
+    for &index in &*permutation {
+        assert!(
+            seen.get(index).is_some_and(|seen| !seen),
+            "Index {index} occurs twice or out of bounds, elements were lost or duplicated"
+        );
+        seen[index] = true;
+    }
+
+    for start in 0..permutation.len() {
+        let mut current = start;
+        while permutation[current] != start {
+            let next = permutation[current];
+            slice.swap(current, next);
+            permutation[current] = current;
+            current = next;
+        }
+        permutation[current] = current;
+    }
+}
+
 /// A trait to parameterize random number generation
 pub trait RngFactory {
     /// The [`rand::Rng`] type produced by this factory
@@ -90,3 +380,489 @@ pub struct DefaultBufGuardFactory;
 impl BufGuardFactory for DefaultBufGuardFactory {
     type Guard<T> = Vec<T>;
 }
+
+/// The [`BufGuardFactory`] producing [`BoxedBuffer<T>`](merging::BoxedBuffer) types
+pub struct BoxedBufGuardFactory;
+
+impl BufGuardFactory for BoxedBufGuardFactory {
+    type Guard<T> = merging::BoxedBuffer<T>;
+}
+
+/// The [`BufGuardFactory`] producing [`AlignedBuffer<T, ALIGN>`](merging::AlignedBuffer) types
+pub struct AlignedBufGuardFactory<const ALIGN: usize = { merging::CACHE_LINE_SIZE }>;
+
+impl<const ALIGN: usize> BufGuardFactory for AlignedBufGuardFactory<ALIGN> {
+    type Guard<T> = merging::AlignedBuffer<T, ALIGN>;
+}
+
+/// The [`BufGuardFactory`] producing [`LazyBuffer`](merging::LazyBuffer) types, deferring the
+/// allocation of the guards produced by `F`
+pub struct LazyBufGuardFactory<F: BufGuardFactory = DefaultBufGuardFactory>(
+    std::marker::PhantomData<F>,
+);
+
+impl<F: BufGuardFactory> BufGuardFactory for LazyBufGuardFactory<F> {
+    type Guard<T> = merging::LazyBuffer<F::Guard<T>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A [`Sort`] claiming to be stable, while sorting unstably.
+    struct FalselyStableSort;
+
+    impl Sort for FalselyStableSort {
+        const IS_STABLE: bool = true;
+
+        const BASE_NAME: &str = "falsely-stable";
+
+        fn parameters() -> impl Iterator<Item = (&'static str, String)> {
+            std::iter::empty()
+        }
+
+        fn sort<T: Ord>(slice: &mut [T]) {
+            slice.sort_unstable();
+        }
+    }
+
+    /// A [`Sort`] doing nothing.
+    struct NoopSort;
+
+    impl Sort for NoopSort {
+        const IS_STABLE: bool = false;
+
+        const BASE_NAME: &str = "noop";
+
+        fn parameters() -> impl Iterator<Item = (&'static str, String)> {
+            std::iter::empty()
+        }
+
+        fn sort<T: Ord>(_slice: &mut [T]) {}
+    }
+
+    #[test]
+    fn test_checked_sorts() {
+        use rand::seq::SliceRandom as _;
+
+        let mut rng = crate::test::test_rng();
+        let mut values: Vec<usize> = (0..crate::test::DEFAULT_TEST_SIZE).map(|i| i / 4).collect();
+        values.shuffle(&mut rng);
+
+        let mut expected = values.clone();
+        expected.sort();
+
+        let mut stable_values = values.clone();
+        <Checked<StdSort>>::sort(&mut stable_values);
+        assert_eq!(stable_values, expected);
+
+        <Checked<StdSort<false>>>::sort(&mut values);
+        assert_eq!(values, expected);
+
+        crate::test::assert_stable_sort::<Checked<StdSort>>(values.len(), &mut rng);
+    }
+
+    #[test]
+    fn test_std_sort_by_comparisons() {
+        use rand::seq::SliceRandom as _;
+
+        /// Returns the number of comparisons `S` performs sorting a random permutation of size `n`.
+        fn random_comparisons<S: Sort>(n: usize) -> u64 {
+            let mut values: Vec<_> = (0..n).map(crate::test::CountingOrdered).collect();
+            values.shuffle(&mut crate::test::test_rng());
+
+            let comparisons = crate::test::count_comparisons(|| S::sort(&mut values));
+            assert!(values.is_sorted());
+
+            comparisons
+        }
+
+        let n = crate::test::DEFAULT_TEST_SIZE;
+        #[expect(clippy::as_conversions, reason = "Small test sizes fit into a f64")]
+        let n_log_n = n as f64 * (n as f64).log2();
+
+        for comparisons in [
+            random_comparisons::<StdSortBy>(n),
+            random_comparisons::<StdSortBy<false>>(n),
+        ] {
+            #[expect(clippy::as_conversions, reason = "Small counts fit into a f64")]
+            let comparisons = comparisons as f64;
+            assert!(
+                (0.8 * n_log_n..1.5 * n_log_n).contains(&comparisons),
+                "{comparisons} comparisons are not within the n log n ballpark of {n_log_n}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_merge_counts() {
+        use rand::seq::SliceRandom as _;
+
+        /// Returns the number of merges `S` performs sorting a random permutation of size `n`.
+        fn random_merges<S: Sort>(n: usize) -> u64 {
+            let mut values: Vec<usize> = (0..n).collect();
+            values.shuffle(&mut crate::test::test_rng());
+
+            merging::reset_merge_count();
+            S::sort(&mut values);
+            assert!(values.is_sorted());
+
+            merging::merge_count()
+        }
+
+        let n = crate::test::DEFAULT_TEST_SIZE;
+
+        // Every internal node of the recursion tree is a merge
+        type TopDownMergeSort = mergesort::MergeSort<
+            mergesort::DefaultInsertionSort,
+            mergesort::DefaultMergingMethod,
+            mergesort::DefaultBufGuardFactory,
+            false,
+            1,
+            false,
+        >;
+        let mergesort_merges = random_merges::<TopDownMergeSort>(n);
+        assert_eq!(mergesort_merges, u64::try_from(n - 1).unwrap());
+
+        // Every run but the last one has at least the minimum run length, and each merge
+        // reduces the number of runs by one
+        let powersort_merges = random_merges::<powersort::PowerSort>(n);
+        assert!(powersort_merges > 0);
+        assert!(
+            powersort_merges <= u64::try_from(n / powersort::DEFAULT_MIN_RUN_LENGTH).unwrap(),
+            "{powersort_merges} merges exceed the number of runs"
+        );
+        assert!(powersort_merges < mergesort_merges);
+    }
+
+    #[test]
+    fn test_sort_by_cached_key() {
+        use rand::seq::SliceRandom as _;
+
+        let mut rng = crate::test::test_rng();
+        let mut strings: Vec<String> = (0..crate::test::DEFAULT_TEST_SIZE)
+            .map(|i| format!("{}{}", "x".repeat(i % 100), i % 7))
+            .collect();
+        strings.shuffle(&mut rng);
+
+        let mut expected = strings.clone();
+        expected.sort_by_key(String::len);
+
+        fn check<S: Sort>(strings: &[String], expected: &[String]) {
+            let mut values = strings.to_vec();
+            let evaluations = std::cell::Cell::new(0);
+
+            CachedKeySort::<S>::sort_by_cached_key(&mut values, |string| {
+                evaluations.set(evaluations.get() + 1);
+                string.len()
+            });
+
+            // Equal lengths keep their initial order, even for unstable sorts
+            assert_eq!(values, expected);
+            assert_eq!(evaluations.get(), strings.len());
+        }
+
+        check::<StdSort>(&strings, &expected);
+        check::<StdSort<false>>(&strings, &expected);
+        check::<quicksort::QuickSort>(&strings, &expected);
+        check::<powersort::PowerSort>(&strings, &expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index 1 occurs twice or out of bounds")]
+    fn test_gather_duplicated_indices() {
+        // A broken stable sort may duplicate an element and lose another while staying sorted
+        gather_permutation(&mut ['a', 'b'], &mut [1, 1]);
+    }
+
+    #[test]
+    fn test_sort_tracked() {
+        use rand::Rng as _;
+
+        let mut rng = crate::test::test_rng();
+
+        for size in [0, 1, 2, 100, crate::test::DEFAULT_TEST_SIZE] {
+            for distinct in [size, size / 10, 2] {
+                let input: Vec<usize> = (0..size)
+                    .map(|_| rng.random_range(0..distinct.max(1)))
+                    .collect();
+
+                let mut sorted = input.clone();
+                let permutation = sort_tracked(&mut sorted);
+
+                let mut expected = input.clone();
+                expected.sort();
+                assert_eq!(sorted, expected);
+
+                let mut indices = permutation.clone();
+                indices.sort_unstable();
+                assert!(indices.into_iter().eq(0..size));
+
+                // Applying the permutation to the input reproduces the sorted output
+                assert!(permutation.iter().map(|&index| input[index]).eq(sorted));
+
+                // Equal keys keep their initial order
+                assert!(
+                    permutation
+                        .windows(2)
+                        .all(|pair| input[pair[0]] < input[pair[1]] || pair[0] < pair[1]),
+                    "Unstable permutation for size {size}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_sort_descending() {
+        use rand::Rng as _;
+
+        /// Checks that `S` sorts tagged duplicates descending, keeping equal keys in their
+        /// initial order if `stable`.
+        fn check<S: Sort>(values: &[usize], stable: bool) {
+            let mut tagged: Vec<_> =
+                crate::data::IndexedOrdered::map_iter(values.iter().copied()).collect();
+            S::sort_descending(&mut tagged);
+
+            assert!(
+                tagged.is_sorted_by(|a, b| a >= b),
+                "{} did not sort",
+                S::name()
+            );
+            if stable {
+                assert!(
+                    tagged
+                        .windows(2)
+                        .all(|pair| pair[0] != pair[1] || pair[0].index() < pair[1].index()),
+                    "{} reordered equal elements",
+                    S::name()
+                );
+            }
+        }
+
+        let mut rng = crate::test::test_rng();
+
+        for size in [0, 1, 2, 100, crate::test::DEFAULT_TEST_SIZE] {
+            let values: Vec<usize> = (0..size)
+                .map(|_| rng.random_range(0..size / 10 + 1))
+                .collect();
+
+            check::<powersort::PowerSort>(&values, true);
+            check::<timsort::TimSort>(&values, true);
+            check::<quicksort::QuickSort>(&values, false);
+        }
+
+        // Reversing the sorted slice reverses the order of equal elements
+        let mut tagged: Vec<_> =
+            crate::data::IndexedOrdered::map_iter([1, 1].into_iter()).collect();
+        <mergesort::MergeSort as Sort>::sort_descending(&mut tagged);
+        assert_eq!(tagged[0].index(), 1);
+    }
+
+    #[test]
+    fn test_boxed_buffer() {
+        use merging::BufGuard as _;
+
+        for capacity in [0, 1, 100, 10_000] {
+            let mut buffer = merging::BoxedBuffer::<u64>::with_capacity(capacity);
+            assert_eq!(buffer.as_uninit_slice_mut().len(), capacity);
+        }
+
+        crate::test::test_random_stable_sorted::<
+            { crate::test::DEFAULT_TEST_RUNS },
+            { crate::test::DEFAULT_TEST_SIZE },
+            powersort::PowerSort<
+                powersort::DefaultNodePowerMethod,
+                powersort::DefaultInsertionSort,
+                powersort::DefaultMergingMethod,
+                BoxedBufGuardFactory,
+            >,
+        >();
+    }
+
+    #[test]
+    fn test_boxed_buffer_uninit_slice() {
+        use merging::BufGuard as _;
+
+        /// Fills the buffer of `capacity` elements created by `value`, and reads them back.
+        fn check<T: Clone + PartialEq + std::fmt::Debug>(capacity: usize, value: T) {
+            let mut buffer = merging::BoxedBuffer::<T>::with_capacity(capacity);
+            let slice = buffer.as_uninit_slice_mut();
+            assert_eq!(slice.len(), capacity);
+
+            for element in slice.iter_mut() {
+                element.write(value.clone());
+            }
+            for element in slice.iter_mut() {
+                // SAFETY: every element was initialized above
+                let element = unsafe { element.assume_init_read() };
+                assert_eq!(element, value);
+            }
+        }
+
+        // Elements spanning several pages, page sized ones and ones not dividing the page size
+        for capacity in [0, 1, 3, 1_000] {
+            check(capacity, [7u8; 5_000]);
+            check(capacity, [3u16; 2_048]);
+            check(capacity, (1u8, 2u16, 3u8));
+            check(capacity, ());
+            check(capacity, String::from("not zeroed"));
+        }
+    }
+
+    #[test]
+    fn test_lazy_buffer() {
+        use rand::seq::SliceRandom as _;
+
+        type Lazy = LazyBufGuardFactory<crate::test::RecordingBufGuardFactory>;
+
+        /// Checks that `S` only allocates its buffer if `values` need to be merged.
+        fn check<S: Sort>() {
+            let mut values: Vec<u32> = (0..10_000).collect();
+
+            // A single (reversed) run is never merged
+            for _ in 0..2 {
+                let allocated = crate::test::count_allocated_bytes(|| S::sort(&mut values));
+                assert!(values.is_sorted());
+                assert_eq!(allocated, 0, "{} allocated for a single run", S::name());
+
+                values.reverse();
+            }
+
+            values.shuffle(&mut crate::test::test_rng());
+            let allocated = crate::test::count_allocated_bytes(|| S::sort(&mut values));
+            assert!(values.is_sorted());
+            assert_eq!(allocated, S::buffer_bytes::<u32>(values.len()));
+        }
+
+        check::<
+            powersort::PowerSort<
+                powersort::DefaultNodePowerMethod,
+                powersort::DefaultInsertionSort,
+                powersort::DefaultMergingMethod,
+                Lazy,
+            >,
+        >();
+        check::<
+            powersort::MultiwayPowerSort<
+                powersort::DefaultNodePowerMethod,
+                powersort::DefaultInsertionSort,
+                powersort::DefaultMultiMergingMethod,
+                powersort::DefaultMergingMethod,
+                Lazy,
+            >,
+        >();
+    }
+
+    /// Checks that [`Sort::buffer_bytes()`] of `S` matches the buffer capacity it allocates.
+    fn test_buffer_bytes<S: Sort>() {
+        use rand::seq::SliceRandom as _;
+
+        let mut rng = crate::test::test_rng();
+
+        for len in [0, 1, 2, 10, 100, crate::test::DEFAULT_TEST_SIZE] {
+            let mut values: Vec<u32> = (0..len).map(|i| u32::try_from(i).unwrap()).collect();
+            values.shuffle(&mut rng);
+
+            let allocated = crate::test::count_allocated_bytes(|| S::sort(&mut values));
+
+            assert!(values.is_sorted());
+            assert_eq!(
+                S::buffer_bytes::<u32>(len),
+                allocated,
+                "{} reported a wrong buffer size for length {len}",
+                S::name()
+            );
+        }
+    }
+
+    #[test]
+    fn test_reported_buffer_bytes() {
+        use crate::test::RecordingBufGuardFactory as Recording;
+
+        test_buffer_bytes::<insertionsort::InsertionSort>();
+        test_buffer_bytes::<quicksort::QuickSort>();
+        test_buffer_bytes::<
+            peeksort::PeekSort<
+                peeksort::DefaultInsertionSort,
+                peeksort::DefaultMergingMethod,
+                Recording,
+            >,
+        >();
+        test_buffer_bytes::<
+            peeksort::MultiwayPeekSort<
+                peeksort::DefaultInsertionSort,
+                peeksort::DefaultMultiMergingMethod,
+                Recording,
+            >,
+        >();
+        test_buffer_bytes::<
+            mergesort::MergeSort<
+                mergesort::DefaultInsertionSort,
+                mergesort::DefaultMergingMethod,
+                Recording,
+            >,
+        >();
+        test_buffer_bytes::<
+            patiencesort::PatienceSort<patiencesort::DefaultMultiMergingMethod, Recording>,
+        >();
+        test_buffer_bytes::<
+            mergesort::MergeSort<
+                mergesort::DefaultInsertionSort,
+                mergesort::DefaultMergingMethod,
+                Recording,
+                { mergesort::DEFAULT_BOTTOM_UP },
+                { mergesort::DEFAULT_INSERTION_THRESHOLD },
+                { mergesort::DEFAULT_CHECK_SORTED },
+                true,
+            >,
+        >();
+        test_buffer_bytes::<
+            timsort::TimSort<
+                timsort::DefaultInsertionSort,
+                timsort::DefaultMergingMethod,
+                Recording,
+            >,
+        >();
+        test_buffer_bytes::<
+            timsort::MultiwayTimSort<
+                timsort::DefaultInsertionSort,
+                timsort::DefaultMultiMergingMethod,
+                Recording,
+            >,
+        >();
+        test_buffer_bytes::<
+            powersort::PowerSort<
+                powersort::DefaultNodePowerMethod,
+                powersort::DefaultInsertionSort,
+                powersort::DefaultMergingMethod,
+                Recording,
+            >,
+        >();
+        test_buffer_bytes::<
+            powersort::MultiwayPowerSort<
+                powersort::DefaultNodePowerMethod,
+                powersort::DefaultInsertionSort,
+                powersort::DefaultMultiMergingMethod,
+                powersort::DefaultMergingMethod,
+                Recording,
+            >,
+        >();
+    }
+
+    #[test]
+    #[should_panic(expected = "index 2 is smaller than its predecessor")]
+    fn test_checked_unsorted() {
+        <Checked<NoopSort>>::sort(&mut [0, 2, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "is not stable")]
+    fn test_checked_unstable() {
+        use rand::seq::SliceRandom as _;
+
+        let mut values: Vec<usize> = (0..crate::test::DEFAULT_TEST_SIZE).map(|i| i / 4).collect();
+        values.shuffle(&mut crate::test::test_rng());
+
+        <Checked<FalselyStableSort>>::sort(&mut values);
+    }
+}
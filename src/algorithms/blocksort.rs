@@ -0,0 +1,344 @@
+//! The Block sort implementation, see [`BlockSort`].
+
+use crate::algorithms::merging::BufGuard as _;
+
+/// The default insertion sort to use.
+pub type DefaultInsertionSort = super::insertionsort::InsertionSort;
+
+/// The default [`super::BufGuardFactory`] to use.
+pub type DefaultBufGuardFactory = super::DefaultBufGuardFactory;
+
+/// The default `MIN_RUN_LENGTH` to use.
+pub const DEFAULT_MIN_RUN_LENGTH: usize = 16;
+
+/// The Block sort [`super::Sort`], a stable bottom-up mergesort in the style of WikiSort using only
+/// `O(sqrt(n))` auxiliary memory.
+///
+/// Runs too long for the buffer are merged by splitting the left run into blocks of roughly
+/// `sqrt(len)` elements, rolling them through the right run and dropping each block behind the
+/// elements of the right run preceding it, see [`merge()`]. Each dropped block is then merged
+/// locally with these elements using the buffer.
+///
+/// Unlike WikiSort, the buffer and the block tags are kept outside of the slice instead of being
+/// extracted from unique values, so inputs with few distinct values need no special casing.
+///
+/// - `I` is the insertion sort used to sort the initial runs.
+/// - `B` is the [`super::BufGuardFactory`] used to create the buffer for merging.
+/// - `MIN_RUN_LENGTH` determines the length of the initial runs sorted by `I`.
+pub struct BlockSort<
+    I: super::PostfixSort = DefaultInsertionSort,
+    B: super::BufGuardFactory = DefaultBufGuardFactory,
+    const MIN_RUN_LENGTH: usize = DEFAULT_MIN_RUN_LENGTH,
+>(std::marker::PhantomData<I>, std::marker::PhantomData<B>);
+
+impl<I: super::PostfixSort, B: super::BufGuardFactory, const MIN_RUN_LENGTH: usize> super::Sort
+    for BlockSort<I, B, MIN_RUN_LENGTH>
+{
+    const IS_STABLE: bool = true;
+
+    const BASE_NAME: &str = "blocksort";
+
+    fn parameters() -> impl Iterator<Item = (&'static str, String)> {
+        vec![
+            ("i-sort", crate::cli::display_inline::<I>()),
+            ("min-run", MIN_RUN_LENGTH.to_string()),
+        ]
+        .into_iter()
+    }
+
+    fn merging() -> Option<String> {
+        Some(crate::cli::format_merging("block-rolling", true))
+    }
+
+    fn buffer_bytes<T>(len: usize) -> usize {
+        if len <= MIN_RUN_LENGTH.max(1) {
+            return 0;
+        }
+
+        buffer_length(len) * std::mem::size_of::<T>()
+            + block_count(len) * std::mem::size_of::<usize>()
+    }
+
+    fn sort<T: Ord>(slice: &mut [T]) {
+        use super::merging::util::{RunOrdering, weakly_increasing_or_strictly_decreasing_index};
+
+        const { assert!(MIN_RUN_LENGTH > 0, "MIN_RUN_LENGTH has to be positive") };
+
+        if slice.len() < 2 {
+            return;
+        }
+
+        // Sort the initial runs, reusing existing ones at their start
+        for run in slice.chunks_mut(MIN_RUN_LENGTH) {
+            let (run_length, ordering) = weakly_increasing_or_strictly_decreasing_index(run);
+
+            if let RunOrdering::StrictlyDecreasing = ordering {
+                run[..run_length].reverse();
+            }
+
+            I::sort_with_sorted_prefix(run, run_length);
+        }
+
+        if slice.len() <= MIN_RUN_LENGTH {
+            return;
+        }
+
+        let mut buffer = <B::Guard<T>>::with_capacity(buffer_length(slice.len()));
+        let mut blocks = Vec::with_capacity(block_count(slice.len()));
+
+        // Merge runs of doubling width bottom-up
+        let mut width = MIN_RUN_LENGTH;
+        while width < slice.len() {
+            for start in (0..slice.len() - width).step_by(2 * width) {
+                let end = std::cmp::min(start + 2 * width, slice.len());
+
+                super::merging::record_merge();
+                merge(
+                    &mut slice[start..end],
+                    width,
+                    buffer.as_uninit_slice_mut(),
+                    &mut blocks,
+                );
+            }
+
+            width *= 2;
+        }
+    }
+}
+
+/// Returns the length of the merging buffer used to sort `len` elements.
+fn buffer_length(len: usize) -> usize {
+    len.isqrt() + 1
+}
+
+/// Returns an upper bound on the number of blocks a left run of at most `len` elements is split
+/// into by [`merge()`].
+fn block_count(len: usize) -> usize {
+    len.isqrt() + 2
+}
+
+/// Merges `slice[..run_length]` and `slice[run_length..]` stably, using `buffer` for local merges
+/// and `blocks` to tag the blocks of the left run.
+///
+/// If either run fits into `buffer`, the runs are merged directly. Otherwise the left run `A` is
+/// split into an uneven first block and blocks of `sqrt(run_length)` elements, which have to fit
+/// into `buffer`. Repeatedly, the next block of the right run `B` is swapped in front of the `A`
+/// blocks, until its last element is not less than the first element of the `A` block with the
+/// smallest tag. That block is then rotated behind the preceding `B` elements less than its first
+/// element, and the previously dropped `A` block is merged with the `B` elements in between.
+fn merge<T: Ord>(
+    slice: &mut [T],
+    run_length: usize,
+    buffer: &mut [std::mem::MaybeUninit<T>],
+    blocks: &mut Vec<usize>,
+) {
+    use super::merging::two_way::BlockMerge;
+
+    let right_length = slice.len() - run_length;
+
+    if run_length == 0 || right_length == 0 || slice[run_length - 1] <= slice[run_length] {
+        return;
+    }
+
+    // Merge directly if either run fits into the buffer
+    if run_length <= buffer.len() {
+        <BlockMerge>::merge_low(slice, run_length, buffer, &mut T::cmp);
+        return;
+    } else if right_length <= buffer.len() {
+        <BlockMerge>::merge_high(slice, run_length, buffer, &mut T::cmp);
+        return;
+    }
+
+    let block_size = run_length.isqrt();
+    validate_assert!(block_size <= buffer.len());
+
+    // The tags of the `A` blocks in `block_a`, in their current order
+    blocks.clear();
+    blocks.extend(0..run_length / block_size);
+
+    let first_a = run_length % block_size;
+    let mut last_a = 0..first_a;
+    let mut last_b = first_a..first_a;
+    let mut block_a = first_a..run_length;
+    let mut block_b = run_length..std::cmp::min(run_length + block_size, slice.len());
+
+    loop {
+        let min_index = blocks
+            .iter()
+            .enumerate()
+            .min_by_key(|&(_, tag)| tag)
+            .map(|(index, _)| index)
+            .unwrap();
+        let min_a = block_a.start + min_index * block_size;
+
+        if block_b.is_empty() || (!last_b.is_empty() && slice[last_b.end - 1] >= slice[min_a]) {
+            // Drop the `A` block with the smallest tag behind the `B` elements less than its first
+            // element. Equal `B` elements stay behind it, keeping the merge stable.
+            let b_split = last_b.start
+                + slice[last_b.clone()].partition_point(|element| *element < slice[min_a]);
+            let b_remaining = last_b.end - b_split;
+
+            if min_index != 0 {
+                let (first, min) = slice[block_a.start..].split_at_mut(min_index * block_size);
+                first[..block_size].swap_with_slice(&mut min[..block_size]);
+                blocks.swap(0, min_index);
+            }
+            blocks.remove(0);
+            slice[b_split..block_a.start + block_size].rotate_right(block_size);
+
+            // Merge the previous `A` block with the `B` elements in front of the dropped block
+            merge_local(&mut slice[last_a.start..b_split], last_a.len(), buffer);
+
+            last_a = b_split..b_split + block_size;
+            last_b = last_a.end..last_a.end + b_remaining;
+            block_a.start += block_size;
+
+            if block_a.is_empty() {
+                break;
+            }
+        } else if block_b.len() < block_size {
+            // Move the uneven last `B` block in front of the `A` blocks
+            slice[block_a.start..block_b.end].rotate_right(block_b.len());
+
+            last_b = block_a.start..block_a.start + block_b.len();
+            block_a = block_a.start + block_b.len()..block_b.end;
+            block_b = block_a.end..block_a.end;
+        } else {
+            // Roll the first `A` block behind the next `B` block
+            let (a, b) = slice[block_a.start..block_b.end].split_at_mut(block_a.len());
+            a[..block_size].swap_with_slice(b);
+            blocks.rotate_left(1);
+
+            last_b = block_a.start..block_a.start + block_size;
+            block_a = block_a.start + block_size..block_b.end;
+            block_b = block_a.end..std::cmp::min(block_a.end + block_size, slice.len());
+        }
+    }
+
+    // Merge the last `A` block with the remaining `B` elements
+    merge_local(&mut slice[last_a.start..], last_a.len(), buffer);
+}
+
+/// Merges `slice[..run_length]` into `slice[run_length..]` using `buffer`, which has to hold at
+/// least `run_length` elements.
+fn merge_local<T: Ord>(
+    slice: &mut [T],
+    run_length: usize,
+    buffer: &mut [std::mem::MaybeUninit<T>],
+) {
+    if run_length == 0 || run_length == slice.len() || slice[run_length - 1] <= slice[run_length] {
+        return;
+    }
+
+    <super::merging::two_way::BlockMerge>::merge_low(slice, run_length, buffer, &mut T::cmp);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generate_test_suite;
+
+    use super::super::Sort as _;
+    use super::*;
+
+    generate_test_suite! {
+        TEST_SIZE: crate::test::DEFAULT_TEST_SIZE;
+        TEST_RUNS: crate::test::DEFAULT_TEST_RUNS;
+
+        BlockSort,
+        BlockSort<DefaultInsertionSort, DefaultBufGuardFactory, 1>,
+        BlockSort<DefaultInsertionSort, DefaultBufGuardFactory, 7>,
+        BlockSort<DefaultInsertionSort, crate::algorithms::BoxedBufGuardFactory, 64>,
+    }
+
+    #[test]
+    fn test_block_merge() {
+        use rand::Rng as _;
+
+        const SIZE: usize = 2_000;
+
+        let mut rng = crate::test::test_rng();
+        let mut buffer = Vec::with_capacity(buffer_length(SIZE));
+        let mut blocks = Vec::new();
+
+        for run_length in [0, 1, 45, 46, 200, SIZE / 2, SIZE - 100, SIZE - 1, SIZE] {
+            for distinct in [SIZE, 10, 1] {
+                let mut values: Vec<_> = crate::test::IndexedOrdered::map_iter(
+                    (0..SIZE).map(|_| rng.random_range(0..distinct)),
+                )
+                .collect();
+                values[..run_length].sort();
+                values[run_length..].sort();
+
+                merge(
+                    &mut values,
+                    run_length,
+                    buffer.spare_capacity_mut(),
+                    &mut blocks,
+                );
+
+                assert!(values.is_sorted());
+                assert_eq!(
+                    crate::test::IndexedOrdered::first_unstable_index(&values),
+                    None,
+                    "Merging at {run_length} with {distinct} distinct values was not stable"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_adversarial_data() {
+        use crate::data::{
+            BoundaryRunsData, DescendingData, FewDistinctData, OrganPipeData, RandomRunsSqrtData,
+        };
+
+        /// Sorts index tagged data of `D` with `BlockSort`, checking it is sorted stably.
+        fn check<D: crate::data::DataGenerator<u32>>() {
+            let mut rng = crate::data::seeded_rng(crate::test::TEST_SEED);
+            let mut generator = D::default();
+
+            for size in [100, 1_000, crate::test::DEFAULT_TEST_SIZE] {
+                let values = generator.initialize(size, &mut rng);
+                let mut values: Vec<_> =
+                    crate::test::IndexedOrdered::map_iter(values.into_iter()).collect();
+
+                <BlockSort>::sort(&mut values);
+
+                assert!(
+                    values.is_sorted(),
+                    "{} data was not sorted",
+                    std::any::type_name::<D>()
+                );
+                assert_eq!(
+                    crate::test::IndexedOrdered::first_unstable_index(&values),
+                    None,
+                    "{} data was not sorted stably",
+                    std::any::type_name::<D>()
+                );
+            }
+        }
+
+        check::<FewDistinctData>();
+        check::<OrganPipeData>();
+        check::<DescendingData>();
+        check::<BoundaryRunsData>();
+        check::<RandomRunsSqrtData>();
+    }
+
+    #[test]
+    fn test_sqrt_buffer() {
+        let mut values: Vec<u32> = (0..100_000).rev().collect();
+
+        let allocated = crate::test::count_allocated_bytes(|| {
+            BlockSort::<DefaultInsertionSort, crate::test::RecordingBufGuardFactory>::sort(
+                &mut values,
+            )
+        });
+
+        assert!(values.is_sorted());
+        assert_eq!(
+            allocated,
+            buffer_length(values.len()) * std::mem::size_of::<u32>()
+        );
+    }
+}
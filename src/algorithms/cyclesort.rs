@@ -0,0 +1,191 @@
+//! The Cycle sort implementation.
+
+/// The Cycle sort [`super::Sort`].
+///
+/// Decomposes the permutation sorting the slice into its cycles and rotates each cycle, writing
+/// every element directly into its final position. Elements already in place are never written,
+/// so sorting needs the minimum number of writes, at the cost of quadratically many comparisons.
+pub struct CycleSort;
+
+impl super::Sort for CycleSort {
+    const IS_STABLE: bool = false;
+
+    const BASE_NAME: &str = "cyclesort";
+
+    fn parameters() -> impl Iterator<Item = (&'static str, String)> {
+        std::iter::empty()
+    }
+
+    fn sort<T: Ord>(slice: &mut [T]) {
+        let len = slice.len();
+        let base = slice.as_mut_ptr();
+
+        for cycle_start in 0..len.saturating_sub(1) {
+            // SAFETY: `cycle_start` is in bounds and `slice` is fully initialized here
+            let mut position =
+                unsafe { Self::position(base, len, cycle_start, &*base.add(cycle_start)) };
+
+            // The element is already in place
+            if position == cycle_start {
+                continue;
+            }
+
+            // SAFETY: The element is moved out of `slice`, leaving a hole at `cycle_start`, which
+            // is filled again when `hole` is dropped, even if a comparison panics. The hole is
+            // never read from or compared, since positions are only searched after it.
+            unsafe {
+                let mut hole = CycleHole {
+                    element: std::mem::ManuallyDrop::new(std::ptr::read(base.add(cycle_start))),
+                    destination: base.add(cycle_start),
+                };
+
+                // A cycle holds at most every element after its start, more rotations are only
+                // possible with an inconsistent ordering
+                for _ in cycle_start..len {
+                    if position == cycle_start {
+                        break;
+                    }
+
+                    // Swap the held element into its position, picking up the one it displaces
+                    std::ptr::swap(&raw mut *hole.element, base.add(position));
+                    record_writes(1);
+
+                    position = Self::position(base, len, cycle_start, &hole.element);
+                }
+
+                // Dropping `hole` writes the last element of the cycle into its start
+                drop(hole);
+                record_writes(1);
+            }
+        }
+    }
+}
+
+impl CycleSort {
+    /// Returns the final position of `element` in the sorted `slice[cycle_start..len]`, where
+    /// `slice` starts at `base` and every element before `cycle_start` is already in place.
+    ///
+    /// Equal elements already placed are skipped, so the returned position is either
+    /// `cycle_start` or occupied by an element different from `element`, if the ordering is
+    /// consistent.
+    ///
+    /// # Safety
+    ///
+    /// `base` has to point to `len` elements, which have to be initialized from `cycle_start + 1`
+    /// onwards.
+    unsafe fn position<T: Ord>(base: *mut T, len: usize, cycle_start: usize, element: &T) -> usize {
+        // SAFETY: All accessed indices are in `cycle_start + 1..len`, see the caller contract
+        unsafe {
+            let mut position = cycle_start
+                + (cycle_start + 1..len)
+                    .filter(|&index| *base.add(index) < *element)
+                    .count();
+
+            if position != cycle_start {
+                // Bounded by `len`, which can only be reached with an inconsistent ordering
+                while position + 1 < len && *element == *base.add(position) {
+                    position += 1;
+                }
+            }
+
+            position
+        }
+    }
+}
+
+/// Writes `element` into `destination` when dropped, used to fill the hole at the start of a
+/// cycle.
+struct CycleHole<T> {
+    /// The element currently moved out of the slice
+    element: std::mem::ManuallyDrop<T>,
+    /// The unoccupied position in the slice
+    destination: *mut T,
+}
+
+impl<T> Drop for CycleHole<T> {
+    fn drop(&mut self) {
+        // SAFETY: `destination` is the single unoccupied position in the slice, see construction.
+        unsafe { std::ptr::copy_nonoverlapping(&*self.element, self.destination, 1) };
+    }
+}
+
+thread_local! {
+    /// The number of elements written by [`CycleSort`] on this thread, see [`writes()`]
+    static WRITES: std::cell::Cell<u64> = const { std::cell::Cell::new(0) };
+}
+
+/// Returns the number of element writes into the sorted slice performed by [`CycleSort`] on this
+/// thread since the last [`reset_writes()`].
+///
+/// The writes are only recorded if the `counters` feature is active or during tests.
+pub fn writes() -> u64 {
+    WRITES.get()
+}
+
+/// Resets the number of writes recorded on this thread.
+pub fn reset_writes() {
+    WRITES.set(0);
+}
+
+/// Records `writes` element writes performed on this thread, see [`writes()`].
+fn record_writes(writes: u64) {
+    if cfg!(any(feature = "counters", test)) {
+        WRITES.set(WRITES.get() + writes);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generate_test_suite;
+
+    use super::*;
+
+    generate_test_suite! {
+        TEST_SIZE: 100;
+        TEST_RUNS: 100;
+
+        CycleSort,
+    }
+
+    #[test]
+    fn test_soundness() {
+        crate::test::test_soundness::<100, 100, CycleSort>();
+    }
+
+    #[test]
+    fn test_minimum_writes() {
+        use crate::algorithms::Sort as _;
+        use rand::seq::SliceRandom as _;
+
+        let mut rng = crate::test::test_rng();
+
+        for size in [0, 1, 2, 10, 100, 1_000] {
+            let mut values: Vec<usize> = (0..size).collect();
+
+            // Random permutations, as well as ones with most elements already placed
+            for shuffled in [size, size / 10, 2] {
+                values.partial_shuffle(&mut rng, shuffled.min(size));
+                let placed = values
+                    .iter()
+                    .enumerate()
+                    .filter(|&(index, &value)| index == value)
+                    .count();
+
+                reset_writes();
+                CycleSort::sort(&mut values);
+
+                assert!(values.iter().copied().eq(0..size));
+                assert_eq!(
+                    writes(),
+                    u64::try_from(size - placed).unwrap(),
+                    "Unexpected writes for size {size}"
+                );
+            }
+        }
+
+        // Sorted input is never written
+        reset_writes();
+        CycleSort::sort(&mut [1, 1, 2, 3, 3]);
+        assert_eq!(writes(), 0);
+    }
+}
@@ -43,7 +43,9 @@ impl<const BINARY: bool> InsertionSort<BINARY> {
             (0..=slice.len()).contains(&partition_point),
             "Partition point needs to be in bounds"
         );
-        debug_assert!(slice[..partition_point].is_sorted());
+        debug_assert!(crate::data::uncounted(
+            || slice[..partition_point].is_sorted()
+        ));
 
         for i in partition_point..slice.len() {
             for j in (0..i).rev() {
@@ -65,7 +67,9 @@ impl<const BINARY: bool> InsertionSort<BINARY> {
             (0..=slice.len()).contains(&partition_point),
             "Partition point needs to be in bounds"
         );
-        debug_assert!(slice[..partition_point].is_sorted());
+        debug_assert!(crate::data::uncounted(
+            || slice[..partition_point].is_sorted()
+        ));
 
         for i in partition_point..slice.len() {
             let j = slice[..i].partition_point(|x| x <= &slice[i]);
@@ -75,8 +79,303 @@ impl<const BINARY: bool> InsertionSort<BINARY> {
     }
 }
 
+/// The galloping Insertion [`super::Sort`].
+///
+/// Finds the insertion point of each element using an exponential search, starting from the end
+/// of the sorted prefix, which needs few comparisons for elements that belong close to the end.
+pub struct GallopingInsertionSort;
+
+impl super::Sort for GallopingInsertionSort {
+    const IS_STABLE: bool = true;
+
+    const BASE_NAME: &str = "galloping-insertionsort";
+
+    fn parameters() -> impl Iterator<Item = (&'static str, String)> {
+        std::iter::empty()
+    }
+
+    fn sort<T: Ord>(slice: &mut [T]) {
+        <Self as super::PostfixSort>::sort_with_sorted_prefix(slice, 1);
+    }
+}
+
+impl super::PostfixSort for GallopingInsertionSort {
+    fn sort_with_sorted_prefix<T: Ord>(slice: &mut [T], split_point: usize) {
+        if slice.len() < 2 {
+            return;
+        }
+
+        assert!(
+            (0..=slice.len()).contains(&split_point),
+            "Split point needs to be in bounds"
+        );
+        debug_assert!(crate::data::uncounted(|| slice[..split_point].is_sorted()));
+
+        for i in std::cmp::max(split_point, 1)..slice.len() {
+            let key = &slice[i];
+
+            // Element is already in place
+            if slice[i - 1] <= *key {
+                continue;
+            }
+
+            // Gallop from the end, until `slice[start - 1] <= key < slice[end]`
+            let mut end = i - 1;
+            let mut offset = 1;
+            let start = loop {
+                if offset > end {
+                    break 0;
+                }
+
+                let probe = end - offset;
+                if slice[probe] <= *key {
+                    break probe + 1;
+                }
+
+                end = probe;
+                offset *= 2;
+            };
+
+            // Insert after equal elements to stay stable
+            let j = start + slice[start..end].partition_point(|x| x <= key);
+
+            slice[j..=i].rotate_right(1);
+        }
+    }
+}
+
+/// Writes `element` into `destination` when dropped, used to fill the hole left while shifting.
+struct InsertionHole<T> {
+    /// The element moved out of the slice
+    element: std::mem::ManuallyDrop<T>,
+    /// The unoccupied position in the slice
+    destination: *mut T,
+}
+
+impl<T> Drop for InsertionHole<T> {
+    fn drop(&mut self) {
+        // SAFETY: `destination` is the single unoccupied position in the slice, see construction.
+        unsafe { std::ptr::copy_nonoverlapping(&*self.element, self.destination, 1) };
+    }
+}
+
+/// The shifting Insertion [`super::Sort`].
+///
+/// Like [`InsertionSort`] with linear search, but moves each element only once, by shifting the
+/// larger elements in front of it by one position instead of repeatedly swapping.
+pub struct ShiftingInsertionSort;
+
+impl super::Sort for ShiftingInsertionSort {
+    const IS_STABLE: bool = true;
+
+    const BASE_NAME: &str = "shifting-insertionsort";
+
+    fn parameters() -> impl Iterator<Item = (&'static str, String)> {
+        std::iter::empty()
+    }
+
+    fn sort<T: Ord>(slice: &mut [T]) {
+        <Self as super::PostfixSort>::sort_with_sorted_prefix(slice, 1);
+    }
+}
+
+impl super::PostfixSort for ShiftingInsertionSort {
+    fn sort_with_sorted_prefix<T: Ord>(slice: &mut [T], split_point: usize) {
+        if slice.len() < 2 {
+            return;
+        }
+
+        assert!(
+            (0..=slice.len()).contains(&split_point),
+            "Split point needs to be in bounds"
+        );
+        debug_assert!(crate::data::uncounted(|| slice[..split_point].is_sorted()));
+
+        for i in std::cmp::max(split_point, 1)..slice.len() {
+            // Find the insertion point after all elements less than or equal to `slice[i]`.
+            // All comparisons happen before moving anything, so a panicking comparison leaves
+            // `slice` untouched.
+            let j = slice[..i]
+                .iter()
+                .rposition(|element| element <= &slice[i])
+                .map_or(0, |index| index + 1);
+
+            shift_into(&mut slice[..=i], j);
+        }
+    }
+}
+
+/// Specifies how [`MovingInsertionSort`] inserts an element into the sorted elements before it.
+pub trait InsertionMethod {
+    /// Returns the string representation of this insertion method.
+    fn display() -> String;
+
+    /// Inserts the last element of `slice` into the sorted `slice[..slice.len() - 1]`, after all
+    /// elements equal to it.
+    fn insert<T: Ord>(slice: &mut [T]);
+}
+
+/// An [`InsertionMethod`] swapping the element with its predecessor, until it is in place.
+#[derive(Debug, Clone, Copy)]
+pub struct Swapping;
+
+impl InsertionMethod for Swapping {
+    fn display() -> String {
+        "swapping".to_string()
+    }
+
+    fn insert<T: Ord>(slice: &mut [T]) {
+        for j in (1..slice.len()).rev() {
+            if slice[j] >= slice[j - 1] {
+                break;
+            }
+
+            slice.swap(j, j - 1);
+            record_element_moves(2);
+        }
+    }
+}
+
+/// An [`InsertionMethod`] searching the insertion point linearly from the end, then shifting the
+/// larger elements by one position with a single `memmove`, see [`ShiftingInsertionSort`].
+#[derive(Debug, Clone, Copy)]
+pub struct Shifting;
+
+impl InsertionMethod for Shifting {
+    fn display() -> String {
+        "shifting".to_string()
+    }
+
+    fn insert<T: Ord>(slice: &mut [T]) {
+        let Some((key, prefix)) = slice.split_last() else {
+            return;
+        };
+
+        let j = prefix
+            .iter()
+            .rposition(|element| element <= key)
+            .map_or(0, |index| index + 1);
+
+        shift_into(slice, j);
+    }
+}
+
+/// An [`InsertionMethod`] searching the insertion point with a binary search, then shifting the
+/// larger elements by one position with a single `memmove`.
+#[derive(Debug, Clone, Copy)]
+pub struct BinaryShifting;
+
+impl InsertionMethod for BinaryShifting {
+    fn display() -> String {
+        "binary-shifting".to_string()
+    }
+
+    fn insert<T: Ord>(slice: &mut [T]) {
+        let Some((key, prefix)) = slice.split_last() else {
+            return;
+        };
+
+        let j = prefix.partition_point(|element| element <= key);
+
+        shift_into(slice, j);
+    }
+}
+
+/// Moves the last element of `slice` to index `j`, shifting `slice[j..slice.len() - 1]` one
+/// position to the right.
+fn shift_into<T>(slice: &mut [T], j: usize) {
+    let i = slice.len() - 1;
+    if j == i {
+        return;
+    }
+
+    // SAFETY: `j < i < slice.len()`, so all pointers are in bounds. `slice[i]` is moved into the
+    // hole, `slice[j..i]` is shifted into `slice[j + 1..=i]` and the hole then writes the element
+    // into the now unoccupied `slice[j]` exactly once.
+    unsafe {
+        let base = slice.as_mut_ptr();
+
+        let hole = InsertionHole {
+            element: std::mem::ManuallyDrop::new(std::ptr::read(base.add(i))),
+            destination: base.add(j),
+        };
+
+        std::ptr::copy(base.add(j), base.add(j + 1), i - j);
+
+        drop(hole);
+    }
+
+    record_element_moves(u64::try_from(i - j + 1).unwrap());
+}
+
+/// The Insertion [`super::Sort`] with an exchangeable [`InsertionMethod`], for comparing the ways
+/// of moving elements into place.
+///
+/// - `M` is the [`InsertionMethod`] used to insert each element.
+pub struct MovingInsertionSort<M: InsertionMethod = Shifting>(std::marker::PhantomData<M>);
+
+impl<M: InsertionMethod> super::Sort for MovingInsertionSort<M> {
+    const IS_STABLE: bool = true;
+
+    const BASE_NAME: &str = "moving-insertionsort";
+
+    fn parameters() -> impl Iterator<Item = (&'static str, String)> {
+        vec![("method", M::display())].into_iter()
+    }
+
+    fn sort<T: Ord>(slice: &mut [T]) {
+        <Self as super::PostfixSort>::sort_with_sorted_prefix(slice, 1);
+    }
+}
+
+impl<M: InsertionMethod> super::PostfixSort for MovingInsertionSort<M> {
+    fn sort_with_sorted_prefix<T: Ord>(slice: &mut [T], split_point: usize) {
+        if slice.len() < 2 {
+            return;
+        }
+
+        assert!(
+            (0..=slice.len()).contains(&split_point),
+            "Split point needs to be in bounds"
+        );
+        debug_assert!(crate::data::uncounted(|| slice[..split_point].is_sorted()));
+
+        for i in std::cmp::max(split_point, 1)..slice.len() {
+            M::insert(&mut slice[..=i]);
+        }
+    }
+}
+
+thread_local! {
+    /// The number of elements moved by [`InsertionMethod`]s on this thread, see
+    /// [`element_moves()`]
+    static ELEMENT_MOVES: std::cell::Cell<u64> = const { std::cell::Cell::new(0) };
+}
+
+/// Returns the number of element writes performed by [`InsertionMethod`]s on this thread since
+/// the last [`reset_element_moves()`].
+///
+/// A swap writes two elements, shifting writes every shifted element and the inserted one. The
+/// moves are only recorded if the `counters` feature is active or during tests.
+pub fn element_moves() -> u64 {
+    ELEMENT_MOVES.get()
+}
+
+/// Resets the number of element moves recorded on this thread.
+pub fn reset_element_moves() {
+    ELEMENT_MOVES.set(0);
+}
+
+/// Records `moves` element writes performed on this thread, see [`element_moves()`].
+fn record_element_moves(moves: u64) {
+    if cfg!(any(feature = "counters", test)) {
+        ELEMENT_MOVES.set(ELEMENT_MOVES.get() + moves);
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::algorithms::Sort as _;
     use crate::generate_test_suite;
 
     use super::*;
@@ -87,5 +386,130 @@ mod tests {
 
         InsertionSort,
         InsertionSort<true>,
+        GallopingInsertionSort,
+        ShiftingInsertionSort,
+        MovingInsertionSort<Swapping>,
+        MovingInsertionSort<Shifting>,
+        MovingInsertionSort<BinaryShifting>,
+    }
+
+    #[test]
+    fn test_soundness() {
+        crate::test::test_soundness::<100, 100, InsertionSort>();
+        crate::test::test_soundness::<100, 100, InsertionSort<true>>();
+        crate::test::test_soundness::<100, 100, GallopingInsertionSort>();
+        crate::test::test_soundness::<100, 100, ShiftingInsertionSort>();
+        crate::test::test_soundness::<100, 100, MovingInsertionSort<Swapping>>();
+        crate::test::test_soundness::<100, 100, MovingInsertionSort<Shifting>>();
+        crate::test::test_soundness::<100, 100, MovingInsertionSort<BinaryShifting>>();
+    }
+
+    /// Returns the number of comparisons needed by `S` to sort a reverse sorted slice of `size`.
+    fn reverse_sorted_comparisons<S: crate::algorithms::Sort>(size: usize) -> u64 {
+        let mut slice: Box<[_]> = (0..size).rev().map(crate::test::CountingOrdered).collect();
+
+        let comparisons = crate::test::count_comparisons(|| S::sort(&mut slice));
+        assert!(slice.is_sorted());
+
+        comparisons
+    }
+
+    #[test]
+    fn test_galloping_comparisons() {
+        const SIZE: u64 = 1_000;
+        let size = usize::try_from(SIZE).unwrap();
+
+        // On reverse sorted input galloping needs about twice as many comparisons as binary search
+        let galloping = reverse_sorted_comparisons::<GallopingInsertionSort>(size);
+        let bound = 2 * SIZE * u64::from(SIZE.ilog2() + 2);
+        assert!(
+            galloping <= bound,
+            "{galloping} comparisons exceeded {bound}"
+        );
+
+        // On nearly sorted input each element only travels a few positions
+        let mut slice: Box<[_]> = (0..size)
+            .map(|i| crate::test::CountingOrdered(i + 4 * (i % 2)))
+            .collect();
+        let binary = {
+            let mut slice = slice.clone();
+            crate::test::count_comparisons(|| InsertionSort::<true>::sort(&mut slice))
+        };
+        let galloping = crate::test::count_comparisons(|| GallopingInsertionSort::sort(&mut slice));
+        assert!(slice.is_sorted());
+        assert!(
+            galloping < binary,
+            "Galloping needed {galloping} comparisons, binary needed {binary}"
+        );
+    }
+
+    #[test]
+    fn test_shifting_comparisons() {
+        const SIZE: u64 = 1_000;
+
+        // Shifting compares exactly like linear insertion
+        let shifting =
+            reverse_sorted_comparisons::<ShiftingInsertionSort>(usize::try_from(SIZE).unwrap());
+        assert_eq!(shifting, SIZE * (SIZE - 1) / 2);
+    }
+
+    #[test]
+    fn test_binary_dispatch() {
+        const SIZE: u64 = 1_000;
+        let size = usize::try_from(SIZE).unwrap();
+
+        // Linear insertion compares each element with all previous ones
+        let linear = reverse_sorted_comparisons::<InsertionSort<false>>(size);
+        assert_eq!(linear, SIZE * (SIZE - 1) / 2);
+
+        // Binary insertion needs about `log2(n)` comparisons per element, leave some slack for
+        // the binary search implementation of `partition_point`
+        let binary = reverse_sorted_comparisons::<InsertionSort<true>>(size);
+        let bound = SIZE * u64::from(SIZE.ilog2() + 2);
+        assert!(binary <= bound, "{binary} comparisons exceeded {bound}");
+    }
+
+    #[test]
+    fn test_element_moves() {
+        const SIZE: u64 = 1_000;
+        let size = usize::try_from(SIZE).unwrap();
+
+        /// Returns the comparisons and element moves needed by `S` on a reverse sorted slice.
+        fn reverse_sorted_moves<S: crate::algorithms::Sort>(size: usize) -> (u64, u64) {
+            reset_element_moves();
+            let comparisons = reverse_sorted_comparisons::<S>(size);
+
+            (comparisons, element_moves())
+        }
+
+        // Every element passes all previous ones, swapping writes two elements per step while
+        // shifting writes each passed element once, plus the inserted element itself
+        let passed = SIZE * (SIZE - 1) / 2;
+        let (swapping_comparisons, swapping) =
+            reverse_sorted_moves::<MovingInsertionSort<Swapping>>(size);
+        let (shifting_comparisons, shifting) =
+            reverse_sorted_moves::<MovingInsertionSort<Shifting>>(size);
+        let (binary_comparisons, binary) =
+            reverse_sorted_moves::<MovingInsertionSort<BinaryShifting>>(size);
+
+        assert_eq!(swapping, 2 * passed);
+        assert_eq!(shifting, passed + SIZE - 1);
+        assert_eq!(binary, shifting);
+
+        // Only the search differs in comparisons
+        assert_eq!(swapping_comparisons, passed);
+        assert_eq!(shifting_comparisons, passed);
+        let bound = SIZE * u64::from(SIZE.ilog2() + 2);
+        assert!(
+            binary_comparisons <= bound,
+            "{binary_comparisons} comparisons exceeded {bound}"
+        );
+
+        // Sorted input moves nothing
+        reset_element_moves();
+        let mut slice: Vec<_> = (0..size).collect();
+        MovingInsertionSort::<Shifting>::sort(&mut slice);
+        MovingInsertionSort::<Swapping>::sort(&mut slice);
+        assert_eq!(element_moves(), 0);
     }
 }
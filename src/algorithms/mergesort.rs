@@ -20,6 +20,21 @@ pub const DEFAULT_INSERTION_THRESHOLD: usize = 24;
 /// The default `CHECK_SORTED` to use.
 pub const DEFAULT_CHECK_SORTED: bool = true;
 
+/// The default `PING_PONG` to use.
+pub const DEFAULT_PING_PONG: bool = false;
+
+/// The default `ITERATIVE` to use.
+pub const DEFAULT_ITERATIVE: bool = false;
+
+/// The default `BALANCED` to use.
+pub const DEFAULT_BALANCED: bool = false;
+
+/// The default [`super::merging::MultiMergingMethod`] to use for [`MultiwayMergeSort`].
+pub type DefaultMultiMergingMethod = super::merging::multi_way::TournamentTree;
+
+/// The default `MERGE_K_RUNS` to use.
+pub const DEFAULT_MERGE_K_RUNS: usize = 4;
+
 /// Mergesort [`super::Sort`].
 ///
 /// - `I` is the insertion sort, used to sort small sub slices.
@@ -28,6 +43,17 @@ pub const DEFAULT_CHECK_SORTED: bool = true;
 /// - `BOTTOM_UP` indicates whether bottom-up mergesort is used as opposed to top-down mergesort.
 /// - `INSERTION_THRESHOLD` determines the maximum length of sub slices which are sorted by `I`.
 /// - `CHECK_SORTED` enables a check for pre-sortedness before merging two runs.
+/// - `PING_PONG` enables ping-pong merging, alternating between `slice` and the buffer on each
+///   level instead of copying back before every merge, see
+///   [`super::merging::two_way::PingPongMerge`]. `M` is not used in that case.
+/// - `ITERATIVE` makes top-down mergesort simulate its recursion with an explicit stack, performing
+///   the exact same sorts and merges. It is only used if neither `BOTTOM_UP` nor `PING_PONG` is
+///   enabled.
+/// - `BALANCED` makes bottom-up mergesort split the slice into a power of two number of equally
+///   long chunks, so the merged runs differ in length by at most one element, like in top-down
+///   mergesort. Otherwise chunks of exactly `INSERTION_THRESHOLD` elements are merged from left to
+///   right, which leaves the last merges unbalanced if the number of chunks is not a power of two.
+///   It is only used if `BOTTOM_UP` is enabled and `PING_PONG` is not.
 pub struct MergeSort<
     I: super::Sort = DefaultInsertionSort,
     M: super::merging::MergingMethod = DefaultMergingMethod,
@@ -35,6 +61,9 @@ pub struct MergeSort<
     const BOTTOM_UP: bool = DEFAULT_BOTTOM_UP,
     const INSERTION_THRESHOLD: usize = DEFAULT_INSERTION_THRESHOLD,
     const CHECK_SORTED: bool = DEFAULT_CHECK_SORTED,
+    const PING_PONG: bool = DEFAULT_PING_PONG,
+    const ITERATIVE: bool = DEFAULT_ITERATIVE,
+    const BALANCED: bool = DEFAULT_BALANCED,
 >(
     std::marker::PhantomData<I>,
     std::marker::PhantomData<M>,
@@ -48,9 +77,23 @@ impl<
     const BOTTOM_UP: bool,
     const INSERTION_THRESHOLD: usize,
     const CHECK_SORTED: bool,
-> super::Sort for MergeSort<I, M, B, BOTTOM_UP, INSERTION_THRESHOLD, CHECK_SORTED>
+    const PING_PONG: bool,
+    const ITERATIVE: bool,
+    const BALANCED: bool,
+> super::Sort
+    for MergeSort<
+        I,
+        M,
+        B,
+        BOTTOM_UP,
+        INSERTION_THRESHOLD,
+        CHECK_SORTED,
+        PING_PONG,
+        ITERATIVE,
+        BALANCED,
+    >
 {
-    const IS_STABLE: bool = I::IS_STABLE && M::IS_STABLE;
+    const IS_STABLE: bool = I::IS_STABLE && (PING_PONG || M::IS_STABLE);
 
     const BASE_NAME: &str = "mergesort";
 
@@ -58,24 +101,73 @@ impl<
         vec![
             ("bottom-up", BOTTOM_UP.to_string()),
             ("i-sort", crate::cli::display_inline::<I>()),
-            ("merging", M::display()),
+            (
+                "merging",
+                if PING_PONG {
+                    "ping-pong".to_string()
+                } else {
+                    M::display()
+                },
+            ),
             ("i-threshold", INSERTION_THRESHOLD.to_string()),
             ("check_sorted", CHECK_SORTED.to_string()),
+            ("iterative", ITERATIVE.to_string()),
+            ("balanced", BALANCED.to_string()),
         ]
         .into_iter()
     }
 
+    fn merging() -> Option<String> {
+        Some(if PING_PONG {
+            crate::cli::format_merging("ping-pong", true)
+        } else {
+            crate::cli::display_merging::<M, 2>()
+        })
+    }
+
+    fn buffer_bytes<T>(len: usize) -> usize {
+        if len < 2 {
+            return 0;
+        }
+
+        // Ping-pong merging always needs to hold the complete slice in the buffer
+        let capacity = if PING_PONG {
+            len
+        } else {
+            M::required_capacity(len)
+        };
+
+        capacity * std::mem::size_of::<T>()
+    }
+
     fn sort<T: Ord>(slice: &mut [T]) {
         if slice.len() < 2 {
             return;
         }
 
+        if PING_PONG {
+            // Ping-pong merging always needs to hold the complete slice in the buffer
+            let mut buffer = <B::Guard<T>>::with_capacity(slice.len());
+
+            if BOTTOM_UP {
+                Self::ping_pong_bottom_up_mergesort(slice, buffer.as_uninit_slice_mut());
+            } else {
+                Self::ping_pong_top_down_mergesort(slice, buffer.as_uninit_slice_mut(), false);
+            }
+
+            return;
+        }
+
         // Conservatively initiate a buffer big enough to merge the complete array
         let mut buffer = <B::Guard<T>>::with_capacity(M::required_capacity(slice.len()));
 
         // Delegate to helper function
-        if BOTTOM_UP {
+        if BOTTOM_UP && BALANCED {
+            Self::balanced_bottom_up_mergesort(slice, buffer.as_uninit_slice_mut());
+        } else if BOTTOM_UP {
             Self::bottom_up_mergesort(slice, buffer.as_uninit_slice_mut());
+        } else if ITERATIVE {
+            Self::iterative_top_down_mergesort(slice, buffer.as_uninit_slice_mut());
         } else {
             Self::top_down_mergesort(slice, buffer.as_uninit_slice_mut());
         }
@@ -89,7 +181,10 @@ impl<
     const BOTTOM_UP: bool,
     const INSERTION_THRESHOLD: usize,
     const CHECK_SORTED: bool,
-> MergeSort<I, M, B, BOTTOM_UP, INSERTION_THRESHOLD, CHECK_SORTED>
+    const PING_PONG: bool,
+    const ITERATIVE: bool,
+    const BALANCED: bool,
+> MergeSort<I, M, B, BOTTOM_UP, INSERTION_THRESHOLD, CHECK_SORTED, PING_PONG, ITERATIVE, BALANCED>
 {
     /// The actual top-down mergesort implementation, sorts `slice`
     fn top_down_mergesort<T: Ord>(slice: &mut [T], buffer: &mut [std::mem::MaybeUninit<T>]) {
@@ -104,14 +199,64 @@ impl<
 
             if CHECK_SORTED {
                 if left.last().unwrap() > right.first().unwrap() {
+                    super::merging::record_merge();
                     M::merge(slice, middle, buffer);
                 }
             } else {
+                super::merging::record_merge();
                 M::merge(slice, middle, buffer);
             }
         }
     }
 
+    /// The top-down mergesort implementation using an explicit stack instead of recursion, sorts
+    /// `slice`.
+    ///
+    /// Performs the same sorts and merges in the same order as [`Self::top_down_mergesort()`].
+    fn iterative_top_down_mergesort<T: Ord>(
+        slice: &mut [T],
+        buffer: &mut [std::mem::MaybeUninit<T>],
+    ) {
+        /// A pending step of the simulated recursion.
+        enum Task {
+            /// Sort `slice[start..end]`.
+            Sort { start: usize, end: usize },
+            /// Merge the sorted `slice[start..middle]` and `slice[middle..end]`.
+            Merge {
+                start: usize,
+                middle: usize,
+                end: usize,
+            },
+        }
+
+        let mut stack = vec![Task::Sort {
+            start: 0,
+            end: slice.len(),
+        }];
+
+        while let Some(task) = stack.pop() {
+            match task {
+                Task::Sort { start, end } if end - start <= INSERTION_THRESHOLD => {
+                    I::sort(&mut slice[start..end]);
+                }
+                Task::Sort { start, end } => {
+                    let middle = start + (end - start) / 2;
+
+                    // Push in reverse order, so the left half is sorted first
+                    stack.push(Task::Merge { start, middle, end });
+                    stack.push(Task::Sort { start: middle, end });
+                    stack.push(Task::Sort { start, end: middle });
+                }
+                Task::Merge { start, middle, end } => {
+                    if !CHECK_SORTED || slice[middle - 1] > slice[middle] {
+                        super::merging::record_merge();
+                        M::merge(&mut slice[start..end], middle - start, buffer);
+                    }
+                }
+            }
+        }
+    }
+
     /// The actual bottom-up mergesort implementation, sorts `slice`
     fn bottom_up_mergesort<T: Ord>(slice: &mut [T], buffer: &mut [std::mem::MaybeUninit<T>]) {
         assert!(
@@ -134,9 +279,11 @@ impl<
 
                 if CHECK_SORTED {
                     if slice[start + merge_size] < slice[start + merge_size - 1] {
+                        super::merging::record_merge();
                         M::merge(&mut slice[start..end], merge_size, buffer);
                     }
                 } else {
+                    super::merging::record_merge();
                     M::merge(&mut slice[start..end], merge_size, buffer);
                 }
             }
@@ -144,13 +291,485 @@ impl<
             merge_size *= 2;
         }
     }
+
+    /// The balanced bottom-up mergesort implementation, sorts `slice`.
+    ///
+    /// Splits `slice` into the smallest power of two number of chunks of at most
+    /// `INSERTION_THRESHOLD` elements, with the chunk `i` of `c` chunks starting at
+    /// `i * slice.len() / c`. Each level then merges pairs of adjacent chunks, halving `c`.
+    fn balanced_bottom_up_mergesort<T: Ord>(
+        slice: &mut [T],
+        buffer: &mut [std::mem::MaybeUninit<T>],
+    ) {
+        assert!(
+            INSERTION_THRESHOLD >= 1,
+            "Insertion threshold has to be greater than or equal to 1"
+        );
+
+        let len = slice.len();
+        let chunks = len.div_ceil(INSERTION_THRESHOLD).next_power_of_two();
+
+        // The start of chunk `index` of `count` chunks, calculated wide to avoid overflows
+        let boundary = |index: usize, count: usize| {
+            let wide = |value: usize| u128::try_from(value).unwrap();
+            usize::try_from(wide(index) * wide(len) / wide(count)).unwrap()
+        };
+
+        // Sort each chunk
+        for index in 0..chunks {
+            I::sort(&mut slice[boundary(index, chunks)..boundary(index + 1, chunks)]);
+        }
+
+        // Iterate through merge tree levels from the bottom up, the chunks of the previous level
+        // are the halves of the current chunks
+        let mut count = chunks;
+        while count > 1 {
+            for index in 0..count / 2 {
+                let start = boundary(2 * index, count);
+                let middle = boundary(2 * index + 1, count);
+                let end = boundary(2 * index + 2, count);
+
+                // Chunks may be empty for small insertion thresholds
+                if start == middle || middle == end {
+                    continue;
+                }
+
+                if !CHECK_SORTED || slice[middle - 1] > slice[middle] {
+                    super::merging::record_merge();
+                    M::merge(&mut slice[start..end], middle - start, buffer);
+                }
+            }
+
+            count /= 2;
+        }
+    }
+
+    /// The ping-pong top-down mergesort implementation, sorts `slice`.
+    ///
+    /// If `into_buffer` is `true`, the sorted elements end up in `buffer[..slice.len()]` instead,
+    /// while `slice` is left with bitwise copies of the same elements in unspecified order.
+    /// This way `slice` always stays a permutation of its initial elements, even if a comparison
+    /// panics.
+    fn ping_pong_top_down_mergesort<T: Ord>(
+        slice: &mut [T],
+        buffer: &mut [std::mem::MaybeUninit<T>],
+        into_buffer: bool,
+    ) {
+        let buffer = &mut buffer[..slice.len()];
+        super::merging::record_buffer_use(buffer.len());
+
+        if slice.len() <= INSERTION_THRESHOLD {
+            I::sort(slice);
+
+            if into_buffer {
+                // SAFETY: `slice` and `buffer` are disjoint with equal length.
+                unsafe {
+                    Self::copy_into(
+                        slice.as_mut_ptr(),
+                        slice.len(),
+                        buffer.as_mut_ptr().cast(),
+                        true,
+                    )
+                };
+            }
+
+            return;
+        }
+
+        let middle = slice.len() / 2;
+
+        // Sort both halves into the respective other location
+        let (left, right) = slice.split_at_mut(middle);
+        let (left_buffer, right_buffer) = buffer.split_at_mut(middle);
+        Self::ping_pong_top_down_mergesort(left, left_buffer, !into_buffer);
+        Self::ping_pong_top_down_mergesort(right, right_buffer, !into_buffer);
+
+        let (source, destination) = if into_buffer {
+            (slice.as_mut_ptr(), buffer.as_mut_ptr().cast())
+        } else {
+            (buffer.as_mut_ptr().cast(), slice.as_mut_ptr())
+        };
+
+        // SAFETY: Both halves of `source` have just been sorted (and initialized) by the recursive
+        // calls and `source` and `destination` are disjoint with length `slice.len()`.
+        unsafe { Self::ping_pong_merge(source, slice.len(), middle, destination, into_buffer) };
+    }
+
+    /// The ping-pong bottom-up mergesort implementation, sorts `slice`.
+    fn ping_pong_bottom_up_mergesort<T: Ord>(
+        slice: &mut [T],
+        buffer: &mut [std::mem::MaybeUninit<T>],
+    ) {
+        assert!(
+            INSERTION_THRESHOLD >= 1,
+            "Insertion threshold has to be greater than or equal to 1"
+        );
+
+        let buffer = &mut buffer[..slice.len()];
+        super::merging::record_buffer_use(buffer.len());
+
+        // Sort each chunk of insertion threshold
+        for chunk in slice.chunks_mut(INSERTION_THRESHOLD) {
+            I::sort(chunk);
+        }
+
+        let mut merge_size = INSERTION_THRESHOLD;
+        let mut into_buffer = true;
+
+        // Iterate through merge tree levels from the bottom up, alternating between `slice` and
+        // `buffer`. Since every chunk contains the same elements on each level, the chunks in
+        // `slice` stay permutations of their elements, even if a comparison panics.
+        while merge_size < slice.len() {
+            let (source, destination): (*mut T, *mut T) = if into_buffer {
+                (slice.as_mut_ptr(), buffer.as_mut_ptr().cast())
+            } else {
+                (buffer.as_mut_ptr().cast(), slice.as_mut_ptr())
+            };
+
+            // Merge all runs of length `merge_size`
+            for start in (0..slice.len()).step_by(merge_size * 2) {
+                let end = std::cmp::min(start + 2 * merge_size, slice.len());
+
+                // SAFETY: `start..end` lies within both `source` and `destination`, which are
+                // disjoint. The sorted runs of this level have been written to `source` by the
+                // previous level.
+                unsafe {
+                    let (source, destination) = (source.add(start), destination.add(start));
+
+                    if start + merge_size < end {
+                        Self::ping_pong_merge(
+                            source,
+                            end - start,
+                            merge_size,
+                            destination,
+                            into_buffer,
+                        );
+                    } else {
+                        // The last run has no partner, copy it over as is
+                        Self::copy_into(source, end - start, destination, into_buffer);
+                    }
+                }
+            }
+
+            into_buffer = !into_buffer;
+            merge_size *= 2;
+        }
+
+        // Copy the result back if it ended up in the buffer
+        if !into_buffer {
+            // SAFETY: `buffer` has been initialized by the last level, `slice` and `buffer` are
+            // disjoint with equal length.
+            unsafe {
+                Self::copy_into(
+                    buffer.as_mut_ptr().cast(),
+                    slice.len(),
+                    slice.as_mut_ptr(),
+                    false,
+                )
+            };
+        }
+    }
+
+    /// Merges the sorted runs `source[..run_length]` and `source[run_length..len]` into
+    /// `destination`, see [`super::merging::two_way::PingPongMerge::merge_into()`].
+    ///
+    /// If `CHECK_SORTED` is enabled and the runs are already in order, they are only copied.
+    ///
+    /// # Safety
+    ///
+    /// See [`super::merging::two_way::PingPongMerge::merge_into()`].
+    unsafe fn ping_pong_merge<T: Ord>(
+        source: *mut T,
+        len: usize,
+        run_length: usize,
+        destination: *mut T,
+        into_buffer: bool,
+    ) {
+        // SAFETY: see method doc
+        unsafe {
+            if CHECK_SORTED && *source.add(run_length - 1) <= *source.add(run_length) {
+                Self::copy_into(source, len, destination, into_buffer);
+            } else {
+                #[cfg(feature = "counters")]
+                Self::count_writes(len, into_buffer);
+
+                super::merging::record_merge();
+                super::merging::two_way::PingPongMerge::merge_into(
+                    source,
+                    len,
+                    run_length,
+                    destination,
+                    &mut T::cmp,
+                );
+            }
+        }
+    }
+
+    /// Copies `source[..len]` into `destination[..len]`.
+    ///
+    /// # Safety
+    ///
+    /// See [`std::ptr::copy_nonoverlapping()`].
+    unsafe fn copy_into<T>(source: *mut T, len: usize, destination: *mut T, into_buffer: bool) {
+        #[cfg(feature = "counters")]
+        Self::count_writes(len, into_buffer);
+        #[cfg(not(feature = "counters"))]
+        let _ = into_buffer;
+
+        // SAFETY: see method doc
+        unsafe { std::ptr::copy_nonoverlapping(source, destination, len) };
+    }
+
+    /// Counts `len` elements written into `buffer` or `slice` depending on `into_buffer`.
+    #[cfg(feature = "counters")]
+    fn count_writes(len: usize, into_buffer: bool) {
+        let len = u64::try_from(len).expect("Slice lengths should fit into u64");
+
+        if into_buffer {
+            crate::GLOBAL_COUNTERS.merge_buffer.increase(len);
+        } else {
+            crate::GLOBAL_COUNTERS.merge_slice.increase(len);
+        }
+    }
+}
+
+/// Multiway bottom-up mergesort [`super::Sort`].
+///
+/// Sorts chunks of `INSERTION_THRESHOLD` elements and then repeatedly merges groups of
+/// `MERGE_K_RUNS` adjacent runs, until a single run is left. Unlike the multiway variants of the
+/// adaptive sorts, existing runs are never detected.
+///
+/// - `I` is the insertion sort, used to sort the initial chunks.
+/// - `M` is the [`super::merging::MultiMergingMethod`] used to merge runs.
+/// - `B` is the [`super::BufGuardFactory`] used to create the merging buffer.
+/// - `MERGE_K_RUNS` determines how many runs are merged together.
+/// - `INSERTION_THRESHOLD` determines the length of the initial chunks sorted by `I`.
+/// - `CHECK_SORTED` enables a check for pre-sortedness before merging a group of runs.
+///
+/// For `MERGE_K_RUNS = 2` this merges exactly like bottom-up [`MergeSort`].
+pub struct MultiwayMergeSort<
+    I: super::Sort = DefaultInsertionSort,
+    M: super::merging::MultiMergingMethod<MERGE_K_RUNS> = DefaultMultiMergingMethod,
+    B: super::BufGuardFactory = DefaultBufGuardFactory,
+    const MERGE_K_RUNS: usize = DEFAULT_MERGE_K_RUNS,
+    const INSERTION_THRESHOLD: usize = DEFAULT_INSERTION_THRESHOLD,
+    const CHECK_SORTED: bool = DEFAULT_CHECK_SORTED,
+>(
+    std::marker::PhantomData<I>,
+    std::marker::PhantomData<M>,
+    std::marker::PhantomData<B>,
+);
+
+impl<
+    I: super::Sort,
+    M: super::merging::MultiMergingMethod<MERGE_K_RUNS>,
+    B: super::BufGuardFactory,
+    const MERGE_K_RUNS: usize,
+    const INSERTION_THRESHOLD: usize,
+    const CHECK_SORTED: bool,
+> super::Sort for MultiwayMergeSort<I, M, B, MERGE_K_RUNS, INSERTION_THRESHOLD, CHECK_SORTED>
+{
+    const IS_STABLE: bool = I::IS_STABLE && M::IS_STABLE;
+
+    const BASE_NAME: &str = "multiway-mergesort";
+
+    fn parameters() -> impl Iterator<Item = (&'static str, String)> {
+        vec![
+            ("K", MERGE_K_RUNS.to_string()),
+            ("i-sort", crate::cli::display_inline::<I>()),
+            ("merging", M::display()),
+            ("i-threshold", INSERTION_THRESHOLD.to_string()),
+            ("check_sorted", CHECK_SORTED.to_string()),
+        ]
+        .into_iter()
+    }
+
+    fn merging() -> Option<String> {
+        Some(crate::cli::display_merging::<M, MERGE_K_RUNS>())
+    }
+
+    fn buffer_bytes<T>(len: usize) -> usize {
+        if len < 2 {
+            return 0;
+        }
+
+        M::required_capacity(len) * std::mem::size_of::<T>()
+    }
+
+    fn sort<T: Ord>(slice: &mut [T]) {
+        assert!(
+            INSERTION_THRESHOLD >= 1,
+            "Insertion threshold has to be greater than or equal to 1"
+        );
+        assert!(MERGE_K_RUNS >= 2, "At least two runs have to be merged");
+
+        if slice.len() < 2 {
+            return;
+        }
+
+        // Conservatively initiate a buffer big enough to merge the complete array
+        let mut buffer = <B::Guard<T>>::with_capacity(M::required_capacity(slice.len()));
+        let buffer = buffer.as_uninit_slice_mut();
+
+        // Sort each chunk of insertion threshold
+        for chunk in slice.chunks_mut(INSERTION_THRESHOLD) {
+            I::sort(chunk);
+        }
+
+        let mut merge_size = INSERTION_THRESHOLD;
+
+        // Iterate through merge tree levels from the bottom up
+        while merge_size < slice.len() {
+            let group_size = merge_size.saturating_mul(MERGE_K_RUNS);
+            let run_lengths = [merge_size; MERGE_K_RUNS];
+
+            // Merge all groups of up to `MERGE_K_RUNS` runs of length `merge_size`
+            for start in (0..slice.len()).step_by(group_size) {
+                let end = start.saturating_add(group_size).min(slice.len());
+                let group = &mut slice[start..end];
+                let runs = group.len().div_ceil(merge_size);
+
+                if runs < 2 {
+                    continue;
+                }
+
+                if CHECK_SORTED
+                    && (1..runs).all(|run| group[run * merge_size - 1] <= group[run * merge_size])
+                {
+                    continue;
+                }
+
+                // The last length is implied by the group
+                super::merging::record_merge();
+                M::merge(group, &run_lengths[..runs - 1], buffer);
+            }
+
+            merge_size = group_size;
+        }
+    }
+}
+
+/// Natural Mergesort [`super::Sort`].
+///
+/// Detects the natural runs of the slice, reversing strictly decreasing ones, and then merges
+/// pairs of adjacent runs level by level from the bottom up, until a single run is left. Unlike
+/// [`super::powersort::PowerSort`], the merge order only depends on the number of runs and not on
+/// their lengths, and short runs are never extended.
+///
+/// - `M` is the [`super::merging::MergingMethod`] used to merge two runs.
+/// - `B` is the [`super::BufGuardFactory`] used to create the merging buffer.
+pub struct NaturalMergeSort<
+    M: super::merging::MergingMethod = DefaultMergingMethod,
+    B: super::BufGuardFactory = DefaultBufGuardFactory,
+>(std::marker::PhantomData<M>, std::marker::PhantomData<B>);
+
+impl<M: super::merging::MergingMethod, B: super::BufGuardFactory> super::Sort
+    for NaturalMergeSort<M, B>
+{
+    const IS_STABLE: bool = M::IS_STABLE;
+
+    const BASE_NAME: &str = "natural-mergesort";
+
+    fn parameters() -> impl Iterator<Item = (&'static str, String)> {
+        vec![("merging", M::display())].into_iter()
+    }
+
+    fn merging() -> Option<String> {
+        Some(crate::cli::display_merging::<M, 2>())
+    }
+
+    fn buffer_bytes<T>(len: usize) -> usize {
+        if len < 2 {
+            return 0;
+        }
+
+        M::required_capacity(len) * std::mem::size_of::<T>()
+    }
+
+    fn sort<T: Ord>(slice: &mut [T]) {
+        use super::merging::util::{RunOrdering, weakly_increasing_or_strictly_decreasing_index};
+
+        if slice.len() < 2 {
+            return;
+        }
+
+        // Find the end of every natural run
+        let mut run_ends = Vec::new();
+        let mut start = 0;
+        while start < slice.len() {
+            let (len, ordering) =
+                weakly_increasing_or_strictly_decreasing_index(&mut slice[start..]);
+            if let RunOrdering::StrictlyDecreasing = ordering {
+                slice[start..start + len].reverse();
+            }
+
+            #[cfg(feature = "instrument")]
+            crate::instrument::on_run(start, len);
+
+            start += len;
+            run_ends.push(start);
+        }
+
+        // A single run is already sorted, no buffer needed
+        if run_ends.len() == 1 {
+            return;
+        }
+
+        let mut buffer = <B::Guard<T>>::with_capacity(M::required_capacity(slice.len()));
+
+        // Merge pairs of adjacent runs, until a single run is left
+        while run_ends.len() > 1 {
+            let mut merged = 0;
+            let mut start = 0;
+
+            for pair in 0..run_ends.len().div_ceil(2) {
+                let end = run_ends[(2 * pair + 1).min(run_ends.len() - 1)];
+
+                // An odd run at the end is left for the next level
+                if 2 * pair + 1 < run_ends.len() {
+                    super::merging::record_merge();
+                    M::merge(
+                        &mut slice[start..end],
+                        run_ends[2 * pair] - start,
+                        buffer.as_uninit_slice_mut(),
+                    );
+                }
+
+                run_ends[merged] = end;
+                merged += 1;
+                start = end;
+            }
+
+            run_ends.truncate(merged);
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use rand::seq::SliceRandom as _;
+
     const TEST_RUNS: usize = crate::test::DEFAULT_TEST_RUNS;
     const TEST_SIZE: usize = crate::test::DEFAULT_TEST_SIZE;
 
+    /// Checks that the sorted result ends up in the slice for every size up to a few levels deep,
+    /// regardless of whether the last merge level has written into the buffer.
+    fn test_ping_pong_result_in_slice<S: crate::algorithms::Sort>() {
+        let mut rng = crate::test::test_rng();
+
+        for size in 0..4 * super::DEFAULT_INSERTION_THRESHOLD {
+            let mut values: Box<[usize]> = (0..size).collect();
+            values.shuffle(&mut rng);
+
+            S::sort(&mut values);
+
+            assert!(
+                values.iter().copied().eq(0..size),
+                "Slice of size {size} did not contain the sorted result"
+            );
+        }
+    }
+
     mod bottom_up {
         use crate::generate_test_suite;
 
@@ -173,12 +792,123 @@ mod tests {
             false,
         >;
 
+        type BottomUpMergeSortPingPong = MergeSort<
+            DefaultInsertionSort,
+            DefaultMergingMethod,
+            DefaultBufGuardFactory,
+            true,
+            DEFAULT_INSERTION_THRESHOLD,
+            DEFAULT_CHECK_SORTED,
+            true,
+        >;
+        type BottomUpMergeSortPingPongUnchecked = MergeSort<
+            DefaultInsertionSort,
+            DefaultMergingMethod,
+            DefaultBufGuardFactory,
+            true,
+            1,
+            false,
+            true,
+        >;
+
+        type BottomUpMergeSortGalloping = MergeSort<
+            DefaultInsertionSort,
+            crate::algorithms::merging::two_way::Galloping,
+            DefaultBufGuardFactory,
+            true,
+        >;
+
+        type BottomUpMergeSortBalanced = MergeSort<
+            DefaultInsertionSort,
+            DefaultMergingMethod,
+            DefaultBufGuardFactory,
+            true,
+            DEFAULT_INSERTION_THRESHOLD,
+            DEFAULT_CHECK_SORTED,
+            DEFAULT_PING_PONG,
+            DEFAULT_ITERATIVE,
+            true,
+        >;
+        type BottomUpMergeSortBalancedUnchecked = MergeSort<
+            DefaultInsertionSort,
+            DefaultMergingMethod,
+            DefaultBufGuardFactory,
+            true,
+            1,
+            false,
+            DEFAULT_PING_PONG,
+            DEFAULT_ITERATIVE,
+            true,
+        >;
+
         generate_test_suite! {
             TEST_SIZE: super::TEST_SIZE;
             TEST_RUNS: super::TEST_RUNS;
 
             BottomUpMergeSort,
             BottomUpMergeSortUnchecked,
+            BottomUpMergeSortPingPong,
+            BottomUpMergeSortPingPongUnchecked,
+            BottomUpMergeSortGalloping,
+            BottomUpMergeSortBalanced,
+            BottomUpMergeSortBalancedUnchecked,
+        }
+
+        #[test]
+        fn test_balanced_comparisons() {
+            use crate::algorithms::Sort as _;
+            use rand::seq::SliceRandom as _;
+
+            type LeftToRight = MergeSort<
+                DefaultInsertionSort,
+                DefaultMergingMethod,
+                DefaultBufGuardFactory,
+                true,
+                1,
+                false,
+            >;
+
+            let mut rng = crate::test::test_rng();
+
+            // For powers of two both merge orders build the same merge tree, otherwise merging
+            // from left to right ends with a merge of a long and a short run
+            for size in [1_usize << 10, (1 << 10) + 1, 3 << 9, 100_000] {
+                let mut values: Vec<_> = (0..size).map(crate::test::CountingOrdered).collect();
+                values.shuffle(&mut rng);
+                let mut balanced_values = values.clone();
+
+                let left_to_right =
+                    crate::test::count_comparisons(|| LeftToRight::sort(&mut values));
+                let balanced = crate::test::count_comparisons(|| {
+                    BottomUpMergeSortBalancedUnchecked::sort(&mut balanced_values)
+                });
+
+                assert!(balanced_values.is_sorted());
+                if size.is_power_of_two() {
+                    assert_eq!(
+                        balanced, left_to_right,
+                        "Different merge trees for size {size}"
+                    );
+                } else {
+                    assert!(
+                        balanced < left_to_right,
+                        "Balanced order took {balanced} comparisons, left to right order \
+                         {left_to_right} for size {size}"
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn test_ping_pong_result_in_slice() {
+            super::test_ping_pong_result_in_slice::<BottomUpMergeSortPingPong>();
+            super::test_ping_pong_result_in_slice::<BottomUpMergeSortPingPongUnchecked>();
+        }
+
+        #[test]
+        fn test_ping_pong_soundness() {
+            crate::test::test_soundness::<TEST_RUNS, 1_000, BottomUpMergeSortPingPong>();
+            crate::test::test_soundness::<TEST_RUNS, 1_000, BottomUpMergeSortPingPongUnchecked>();
         }
     }
 
@@ -196,12 +926,279 @@ mod tests {
             false,
         >;
 
+        type MergeSortPingPong = MergeSort<
+            DefaultInsertionSort,
+            DefaultMergingMethod,
+            DefaultBufGuardFactory,
+            DEFAULT_BOTTOM_UP,
+            DEFAULT_INSERTION_THRESHOLD,
+            DEFAULT_CHECK_SORTED,
+            true,
+        >;
+        type MergeSortPingPongUnchecked = MergeSort<
+            DefaultInsertionSort,
+            DefaultMergingMethod,
+            DefaultBufGuardFactory,
+            DEFAULT_BOTTOM_UP,
+            1,
+            false,
+            true,
+        >;
+
+        type MergeSortIterative = MergeSort<
+            DefaultInsertionSort,
+            DefaultMergingMethod,
+            DefaultBufGuardFactory,
+            DEFAULT_BOTTOM_UP,
+            2,
+            DEFAULT_CHECK_SORTED,
+            DEFAULT_PING_PONG,
+            true,
+        >;
+
+        type MergeSortGalloping =
+            MergeSort<DefaultInsertionSort, crate::algorithms::merging::two_way::Galloping>;
+
         generate_test_suite! {
             TEST_SIZE: super::TEST_SIZE;
             TEST_RUNS: super::TEST_RUNS;
 
             MergeSort,
             MergesortUnchecked,
+            MergeSortIterative,
+            MergeSortPingPong,
+            MergeSortPingPongUnchecked,
+            MergeSortGalloping,
+        }
+
+        #[test]
+        fn test_galloping_comparisons() {
+            use crate::algorithms::Sort as _;
+            use rand::seq::SliceRandom as _;
+
+            const SIZE: usize = 100_000;
+            const CLUSTER_LENGTH: usize = 500;
+
+            /// Returns the comparisons of galloping and copying both runs sorting `values`.
+            fn comparisons(values: Vec<usize>) -> (u64, u64) {
+                let mut copy_both_values: Vec<_> = values
+                    .into_iter()
+                    .map(crate::test::CountingOrdered)
+                    .collect();
+                let mut galloping_values = copy_both_values.clone();
+
+                let copy_both =
+                    crate::test::count_comparisons(|| <MergeSort>::sort(&mut copy_both_values));
+                let galloping = crate::test::count_comparisons(|| {
+                    MergeSortGalloping::sort(&mut galloping_values)
+                });
+
+                assert!(galloping_values.is_sorted());
+                assert_eq!(galloping_values, copy_both_values);
+
+                (galloping, copy_both)
+            }
+
+            let mut rng = crate::test::test_rng();
+
+            let mut values: Vec<usize> = (0..SIZE).collect();
+            values.shuffle(&mut rng);
+            let (random_galloping, random_copy_both) = comparisons(values);
+
+            let (clustered_galloping, clustered_copy_both) = comparisons(
+                crate::test::clustered_permutation(SIZE, CLUSTER_LENGTH, &mut rng),
+            );
+
+            assert!(
+                clustered_galloping < clustered_copy_both,
+                "Galloping took {clustered_galloping} comparisons on clustered data, copying \
+                 both runs {clustered_copy_both}"
+            );
+
+            let random_ratio = crate::test::comparison_ratio(random_galloping, random_copy_both);
+            let clustered_ratio =
+                crate::test::comparison_ratio(clustered_galloping, clustered_copy_both);
+            assert!(
+                clustered_ratio < 0.9 * random_ratio,
+                "Galloping took {clustered_ratio:.3} times the comparisons of copying both runs \
+                 on clustered data, {random_ratio:.3} times on random data"
+            );
+        }
+
+        #[test]
+        fn test_iterative_matches_recursive() {
+            use crate::algorithms::Sort as _;
+            use rand::seq::SliceRandom as _;
+
+            type Recursive = MergeSort<
+                DefaultInsertionSort,
+                DefaultMergingMethod,
+                DefaultBufGuardFactory,
+                DEFAULT_BOTTOM_UP,
+                2,
+            >;
+
+            let mut rng = crate::test::test_rng();
+            let mut values: Box<[_]> = (0..100_000)
+                .map(|i| crate::test::CountingOrdered(i / 4))
+                .collect();
+            values.shuffle(&mut rng);
+
+            let mut iterative_values: Box<[_]> =
+                crate::test::IndexedOrdered::map_iter(values.iter().copied()).collect();
+            let mut values: Box<[_]> =
+                crate::test::IndexedOrdered::map_iter(values.iter().copied()).collect();
+
+            let comparisons = crate::test::count_comparisons(|| Recursive::sort(&mut values));
+            let iterative_comparisons =
+                crate::test::count_comparisons(|| MergeSortIterative::sort(&mut iterative_values));
+
+            assert!(values.is_sorted());
+            assert_eq!(comparisons, iterative_comparisons);
+            assert!(
+                values
+                    .iter()
+                    .map(crate::test::IndexedOrdered::index)
+                    .eq(iterative_values
+                        .iter()
+                        .map(crate::test::IndexedOrdered::index))
+            );
+        }
+
+        #[test]
+        fn test_ping_pong_result_in_slice() {
+            super::test_ping_pong_result_in_slice::<MergeSortPingPong>();
+            super::test_ping_pong_result_in_slice::<MergeSortPingPongUnchecked>();
+        }
+
+        #[test]
+        fn test_ping_pong_soundness() {
+            crate::test::test_soundness::<TEST_RUNS, 1_000, MergeSortPingPong>();
+            crate::test::test_soundness::<TEST_RUNS, 1_000, MergeSortPingPongUnchecked>();
+        }
+    }
+
+    mod multiway {
+        use crate::generate_test_suite;
+
+        use super::super::*;
+
+        type MultiwayMergeSort8 = MultiwayMergeSort<
+            DefaultInsertionSort,
+            DefaultMultiMergingMethod,
+            DefaultBufGuardFactory,
+            8,
+        >;
+        type MultiwayMergeSortFourwayUnchecked = MultiwayMergeSort<
+            DefaultInsertionSort,
+            crate::algorithms::merging::multi_way::Fourway,
+            DefaultBufGuardFactory,
+            4,
+            1,
+            false,
+        >;
+
+        generate_test_suite! {
+            TEST_SIZE: super::TEST_SIZE;
+            TEST_RUNS: super::TEST_RUNS;
+
+            MultiwayMergeSort,
+            MultiwayMergeSort8,
+            MultiwayMergeSortFourwayUnchecked,
+        }
+
+        #[test]
+        fn test_two_way_like_bottom_up() {
+            use crate::algorithms::Sort as _;
+            use rand::seq::SliceRandom as _;
+
+            type BottomUpMergeSort =
+                MergeSort<DefaultInsertionSort, DefaultMergingMethod, DefaultBufGuardFactory, true>;
+            type TwoWayMergeSort = MultiwayMergeSort<
+                DefaultInsertionSort,
+                DefaultMergingMethod,
+                DefaultBufGuardFactory,
+                2,
+            >;
+
+            let mut rng = crate::test::test_rng();
+            let mut values: Vec<_> = (0..super::TEST_SIZE)
+                .map(crate::test::CountingOrdered)
+                .collect();
+            values.shuffle(&mut rng);
+            let mut multiway_values = values.clone();
+
+            let comparisons =
+                crate::test::count_comparisons(|| BottomUpMergeSort::sort(&mut values));
+            let multiway_comparisons =
+                crate::test::count_comparisons(|| TwoWayMergeSort::sort(&mut multiway_values));
+
+            assert!(multiway_values.is_sorted());
+            assert_eq!(comparisons, multiway_comparisons);
+        }
+    }
+
+    mod natural {
+        use crate::generate_test_suite;
+
+        use super::super::*;
+
+        type NaturalMergeSortGalloping =
+            NaturalMergeSort<crate::algorithms::merging::two_way::Galloping>;
+
+        generate_test_suite! {
+            TEST_SIZE: super::TEST_SIZE;
+            TEST_RUNS: super::TEST_RUNS;
+
+            NaturalMergeSort,
+            NaturalMergeSortGalloping,
+        }
+
+        #[test]
+        fn test_soundness() {
+            crate::test::test_soundness::<{ super::TEST_RUNS }, 1_000, NaturalMergeSort>();
+        }
+
+        #[test]
+        fn test_linear_on_runs() {
+            use crate::algorithms::Sort as _;
+            use crate::test::{CountingOrdered, count_comparisons};
+
+            let sort = |values: &mut Vec<CountingOrdered<usize>>| {
+                crate::algorithms::merging::reset_merge_count();
+                let comparisons = count_comparisons(|| <NaturalMergeSort>::sort(values));
+                assert!(values.is_sorted());
+
+                (comparisons, crate::algorithms::merging::merge_count())
+            };
+
+            for size in [2, 100, super::TEST_SIZE] {
+                let len = u64::try_from(size).unwrap();
+
+                // Sorted and strictly decreasing input form a single run, found in linear time
+                let mut sorted: Vec<_> = (0..size).map(CountingOrdered).collect();
+                assert_eq!(sort(&mut sorted), (len - 1, 0));
+                let mut decreasing: Vec<_> = (0..size).rev().map(CountingOrdered).collect();
+                assert_eq!(sort(&mut decreasing), (len - 1, 0));
+                let mut equal: Vec<_> = (0..size).map(|_| CountingOrdered(0)).collect();
+                assert_eq!(sort(&mut equal), (len - 1, 0));
+
+                // `r` runs are merged in `r - 1` merges over `ceil(log2(r))` levels
+                for runs in [2_usize, 3, 8].into_iter().filter(|&runs| size >= 2 * runs) {
+                    let mut values: Vec<_> = (0..size)
+                        .map(|i| CountingOrdered(i % size.div_ceil(runs)))
+                        .collect();
+                    let runs = size.div_ceil(size.div_ceil(runs));
+
+                    let (comparisons, merges) = sort(&mut values);
+                    let levels = u64::from(runs.next_power_of_two().ilog2());
+                    assert_eq!(merges, u64::try_from(runs - 1).unwrap());
+                    assert!(
+                        comparisons <= len * (levels + 1),
+                        "{comparisons} comparisons for {runs} runs of size {size}"
+                    );
+                }
+            }
         }
     }
 }
@@ -6,6 +6,14 @@ pub mod two_way;
 pub use multi_way::MultiMergingMethod;
 pub use two_way::MergingMethod;
 
+/// A runtime comparison function of elements of `T`, used by the `merge_by` methods of
+/// [`MergingMethod`] and [`MultiMergingMethod`].
+///
+/// This is implemented by every `FnMut(&T, &T) -> Ordering`, e.g. by `T::cmp` for `T: Ord`.
+pub trait Comparator<T>: FnMut(&T, &T) -> std::cmp::Ordering {}
+
+impl<T, F: FnMut(&T, &T) -> std::cmp::Ordering> Comparator<T> for F {}
+
 /// Contains various utility methods for the detection of runs.
 /// Contains various utility methods for the detection of runs.
 pub mod util {
@@ -34,7 +42,11 @@ pub mod util {
         Ok(None)
     }
 
-    /// Returns the largest `index`, such that `slice[..index]` is weakly increasing.
+    /// Returns the largest `index`, such that `slice[..index]` is weakly increasing, i.e. the end
+    /// of the run starting at `0`.
+    ///
+    /// This is `slice.len()` if the whole slice is weakly increasing, in particular if it has
+    /// fewer than 2 elements.
     pub fn weakly_increasing_prefix_index<T: Ord>(slice: &[T]) -> usize {
         let iter = slice.iter().enumerate();
 
@@ -49,7 +61,11 @@ pub mod util {
         }
     }
 
-    /// Returns the smallest `index`, such that `slice[index..]` is weakly increasing.
+    /// Returns the smallest `index`, such that `slice[index..]` is weakly increasing, i.e. the
+    /// start of the run ending at `slice.len()`.
+    ///
+    /// This is `0` if the whole slice is weakly increasing, in particular if it has fewer than 2
+    /// elements.
     pub fn weakly_increasing_suffix_index<T: Ord>(slice: &[T]) -> usize {
         let iter = slice.iter().enumerate().rev();
 
@@ -64,7 +80,11 @@ pub mod util {
         }
     }
 
-    /// Returns the largest `index`, such that `slice[..index]` is strictly decreasing
+    /// Returns the largest `index`, such that `slice[..index]` is strictly decreasing, i.e. the
+    /// end of the run starting at `0`.
+    ///
+    /// This is `slice.len()` if the whole slice is strictly decreasing, in particular if it has
+    /// fewer than 2 elements.
     pub fn strictly_decreasing_prefix_index<T: Ord>(slice: &[T]) -> usize {
         let iter = slice.iter().enumerate();
 
@@ -79,7 +99,11 @@ pub mod util {
         }
     }
 
-    /// Returns the smallest `index`, such that `slice[index..]` is strictly decreasing
+    /// Returns the smallest `index`, such that `slice[index..]` is strictly decreasing, i.e. the
+    /// start of the run ending at `slice.len()`.
+    ///
+    /// This is `0` if the whole slice is strictly decreasing, in particular if it has fewer than 2
+    /// elements.
     pub fn strictly_decreasing_suffix_index<T: Ord>(slice: &[T]) -> usize {
         let iter = slice.iter().enumerate().rev();
 
@@ -134,6 +158,59 @@ pub mod util {
     }
 }
 
+thread_local! {
+    /// The number of merges performed by sorts on this thread, see [`merge_count()`]
+    static MERGE_COUNT: std::cell::Cell<u64> = const { std::cell::Cell::new(0) };
+}
+
+/// Returns the number of merges performed by sorts on this thread since the last
+/// [`reset_merge_count()`].
+///
+/// A merge of `K` runs counts as a single merge. The merges are only recorded if the `counters`
+/// feature is active or during tests.
+pub fn merge_count() -> u64 {
+    MERGE_COUNT.get()
+}
+
+/// Resets the number of merges recorded on this thread.
+pub fn reset_merge_count() {
+    MERGE_COUNT.set(0);
+}
+
+/// Records a single merge performed by a sort on this thread, see [`merge_count()`].
+pub fn record_merge() {
+    if cfg!(any(feature = "counters", test)) {
+        MERGE_COUNT.set(MERGE_COUNT.get() + 1);
+    }
+}
+
+thread_local! {
+    /// The largest merging buffer prefix used on this thread, see [`buffer_prefix()`]
+    static BUFFER_PREFIX: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+/// Returns the length of the largest merging buffer prefix used by merges on this thread since
+/// the last [`reset_buffer_prefix()`].
+///
+/// Merges only ever use a prefix of their buffer, so this bounds the part of the allocated buffer
+/// needed. Each merge records the whole prefix it takes, not the offsets it actually writes. The
+/// prefixes are only recorded if the `counters` feature is active or during tests.
+pub fn buffer_prefix() -> usize {
+    BUFFER_PREFIX.get()
+}
+
+/// Resets the largest merging buffer prefix recorded on this thread.
+pub fn reset_buffer_prefix() {
+    BUFFER_PREFIX.set(0);
+}
+
+/// Records a merge using `buffer[..len]` on this thread, see [`buffer_prefix()`].
+pub fn record_buffer_use(len: usize) {
+    if cfg!(any(feature = "counters", test)) {
+        BUFFER_PREFIX.set(BUFFER_PREFIX.get().max(len));
+    }
+}
+
 /// Copied from [`std::slice::sort::stable::BufGuard<T>`]
 pub trait BufGuard<T> {
     /// Creates new buffer that holds at least `capacity` memory.
@@ -149,9 +226,19 @@ impl<T> BufGuard<T> for Vec<T> {
             clippy::as_conversions,
             reason = "This will always be accurate (capacity will realistically not be too high)"
         )]
-        crate::GLOBAL_COUNTERS.merge_alloc.increase(capacity as u64);
+        {
+            crate::GLOBAL_COUNTERS.merge_alloc.increase(capacity as u64);
+            crate::GLOBAL_COUNTERS
+                .merge_alloc_largest
+                .increase_to(capacity as u64);
+        }
 
-        Vec::with_capacity(capacity)
+        #[cfg_attr(not(feature = "poison-buffer"), expect(unused_mut))]
+        let mut buffer = Vec::with_capacity(capacity);
+        #[cfg(feature = "poison-buffer")]
+        poison::fill(buffer.spare_capacity_mut());
+
+        buffer
     }
 
     fn as_uninit_slice_mut(&mut self) -> &mut [std::mem::MaybeUninit<T>] {
@@ -159,6 +246,183 @@ impl<T> BufGuard<T> for Vec<T> {
     }
 }
 
+/// The size of the pages [`BoxedBuffer`] touches when allocating.
+const PAGE_SIZE: usize = 4096;
+
+/// A [`BufGuard`] backed by a boxed slice, which is touched page by page when allocating.
+///
+/// Merging therefore never page faults on the buffer, in contrast to a freshly allocated `Vec<T>`.
+pub struct BoxedBuffer<T>(Box<[std::mem::MaybeUninit<T>]>);
+
+impl<T> BufGuard<T> for BoxedBuffer<T> {
+    fn with_capacity(capacity: usize) -> Self {
+        #[cfg(feature = "counters")]
+        #[expect(
+            clippy::as_conversions,
+            reason = "This will always be accurate (capacity will realistically not be too high)"
+        )]
+        {
+            crate::GLOBAL_COUNTERS.merge_alloc.increase(capacity as u64);
+            crate::GLOBAL_COUNTERS
+                .merge_alloc_largest
+                .increase_to(capacity as u64);
+        }
+
+        let mut buffer = Box::new_uninit_slice(capacity);
+        let len = capacity * std::mem::size_of::<T>();
+
+        // Fault in every page of the buffer up front
+        let bytes = buffer.as_mut_ptr().cast::<u8>();
+        for offset in (0..len).step_by(PAGE_SIZE) {
+            // SAFETY: `offset` is within the allocation and any byte may be written to
+            // uninitialized memory
+            unsafe { bytes.add(offset).write_volatile(0) };
+        }
+
+        Self(buffer)
+    }
+
+    fn as_uninit_slice_mut(&mut self) -> &mut [std::mem::MaybeUninit<T>] {
+        &mut self.0
+    }
+}
+
+/// The size of the cache lines [`AlignedBuffer`] is aligned to by default.
+pub const CACHE_LINE_SIZE: usize = 64;
+
+/// A [`BufGuard`] whose memory starts at a multiple of `ALIGN` bytes, see
+/// [`crate::aligned::AlignedBox`].
+pub struct AlignedBuffer<T, const ALIGN: usize = CACHE_LINE_SIZE>(
+    crate::aligned::AlignedBox<std::mem::MaybeUninit<T>>,
+);
+
+impl<T, const ALIGN: usize> BufGuard<T> for AlignedBuffer<T, ALIGN> {
+    fn with_capacity(capacity: usize) -> Self {
+        #[cfg(feature = "counters")]
+        #[expect(
+            clippy::as_conversions,
+            reason = "This will always be accurate (capacity will realistically not be too high)"
+        )]
+        {
+            crate::GLOBAL_COUNTERS.merge_alloc.increase(capacity as u64);
+            crate::GLOBAL_COUNTERS
+                .merge_alloc_largest
+                .increase_to(capacity as u64);
+        }
+
+        Self(crate::aligned::AlignedBox::new_uninit_slice(
+            capacity, ALIGN,
+        ))
+    }
+
+    fn as_uninit_slice_mut(&mut self) -> &mut [std::mem::MaybeUninit<T>] {
+        &mut self.0
+    }
+}
+
+/// A [`BufGuard`] deferring the allocation of the buffer `G` until its memory is first accessed.
+///
+/// Sorts only accessing the buffer when merging, like [`crate::algorithms::powersort::PowerSort`],
+/// therefore never allocate for inputs consisting of a single run.
+pub struct LazyBuffer<G> {
+    /// The capacity to allocate `G` with
+    capacity: usize,
+    /// The allocated buffer, if its memory was accessed already
+    buffer: Option<G>,
+}
+
+impl<T, G: BufGuard<T>> BufGuard<T> for LazyBuffer<G> {
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            capacity,
+            buffer: None,
+        }
+    }
+
+    fn as_uninit_slice_mut(&mut self) -> &mut [std::mem::MaybeUninit<T>] {
+        let capacity = self.capacity;
+
+        self.buffer
+            .get_or_insert_with(|| G::with_capacity(capacity))
+            .as_uninit_slice_mut()
+    }
+}
+
+/// Poisoning of merging buffers, to detect elements copied out of the buffer which were never
+/// written to it.
+///
+/// [`Vec`] buffers are filled with [`POISON_BYTE`] when allocated and [`Run`] copies assert that
+/// none of the copied primitive integers consists only of that byte, which is checked only with
+/// debug assertions. Other types are not checked. Since this would also report an actual value
+/// of that pattern, e.g. `0xA5A5_A5A5_u32`, the sorted data should not contain any.
+#[cfg(feature = "poison-buffer")]
+pub mod poison {
+    /// The byte every byte of a new buffer is set to
+    pub const POISON_BYTE: u8 = 0xA5;
+
+    /// Sets every byte of `buffer` to [`POISON_BYTE`].
+    pub fn fill<T>(buffer: &mut [std::mem::MaybeUninit<T>]) {
+        // SAFETY: Any bytes are valid for `MaybeUninit<T>`, and `buffer` is valid for writes of
+        // `buffer.len()` elements
+        unsafe { std::ptr::write_bytes(buffer.as_mut_ptr(), POISON_BYTE, buffer.len()) };
+    }
+
+    /// Debug asserts that none of the `count` elements starting at `source` is poisoned, see
+    /// [`Poisonable`].
+    ///
+    /// # Safety
+    ///
+    /// `source` has to be valid for reads of `count` elements, which have to be initialized or
+    /// poisoned with [`fill()`].
+    pub unsafe fn check<T>(source: *const T, count: usize) {
+        for index in 0..count {
+            // SAFETY: see method doc
+            debug_assert!(
+                !unsafe { T::is_poisoned(source.add(index)) },
+                "Copied a poisoned element, which was never written to the merging buffer"
+            );
+        }
+    }
+
+    /// Types whose poisoned values can be recognized, specialized for the primitive integers.
+    trait Poisonable: Sized {
+        /// Returns whether `element` consists only of [`POISON_BYTE`]s.
+        ///
+        /// # Safety
+        ///
+        /// `element` has to be valid for reads and initialized or poisoned with [`fill()`].
+        unsafe fn is_poisoned(element: *const Self) -> bool;
+    }
+
+    impl<T> Poisonable for T {
+        default unsafe fn is_poisoned(_element: *const T) -> bool {
+            // The bytes of other types may contain uninitialized padding, so they are not checked
+            false
+        }
+    }
+
+    /// Specializes [`Poisonable`] for the given integers, which have no padding and are valid
+    /// for any bytes.
+    macro_rules! impl_poisonable {
+        ($($int:ty),*) => {
+            $(
+                impl Poisonable for $int {
+                    unsafe fn is_poisoned(element: *const Self) -> bool {
+                        const POISONED: $int =
+                            <$int>::from_ne_bytes([POISON_BYTE; std::mem::size_of::<$int>()]);
+
+                        // SAFETY: see method doc
+                        unsafe { element.read_unaligned() == POISONED }
+                    }
+                }
+            )*
+        };
+    }
+
+    // Single bytes are left out, since they would regularly hit the pattern
+    impl_poisonable!(u16, u32, u64, u128, usize, i16, i32, i64, i128, isize);
+}
+
 /// A thin wrapper around a pointer range, offering some convenience methods.
 #[derive(Debug)]
 pub struct Run<T>(std::ops::Range<*mut T>);
@@ -229,6 +493,8 @@ impl<T> Run<T> {
         // SAFETY: see method doc
         unsafe {
             debug_assert!(self.len() >= count && other.len() >= count);
+            #[cfg(feature = "poison-buffer")]
+            poison::check(self.0.start, count);
 
             std::ptr::copy_nonoverlapping(self.0.start, other.0.start, count);
 
@@ -248,6 +514,8 @@ impl<T> Run<T> {
         // SAFETY: see method doc
         unsafe {
             debug_assert!(self.len() >= count && other.len() >= count);
+            #[cfg(feature = "poison-buffer")]
+            poison::check(self.0.start, count);
 
             std::ptr::copy(self.0.start, other.0.start, count);
 
@@ -270,6 +538,8 @@ impl<T> Run<T> {
 
             self.0.end = self.0.end.sub(count);
             other.0.end = other.0.end.sub(count);
+            #[cfg(feature = "poison-buffer")]
+            poison::check(self.0.end, count);
 
             std::ptr::copy_nonoverlapping(self.0.end, other.0.end, count);
         }
@@ -289,6 +559,8 @@ impl<T> Run<T> {
 
             self.0.end = self.0.end.sub(count);
             other.0.end = other.0.end.sub(count);
+            #[cfg(feature = "poison-buffer")]
+            poison::check(self.0.end, count);
 
             std::ptr::copy(self.0.end, other.0.end, count);
         }
@@ -362,3 +634,141 @@ impl<T, const N: usize> Drop for MergingDropGuard<T, N> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::util::*;
+
+    #[cfg(feature = "poison-buffer")]
+    #[test]
+    fn test_poison_correct_merges() {
+        use crate::algorithms::{Sort as _, mergesort, powersort};
+        use rand::seq::SliceRandom as _;
+
+        let mut rng = crate::test::test_rng();
+
+        for size in [0, 1, 2, 10, 100, crate::test::DEFAULT_TEST_SIZE] {
+            let mut values: Vec<u32> = (0..u32::try_from(size).unwrap()).collect();
+
+            for sort in
+                [
+                    <mergesort::MergeSort>::sort,
+                    mergesort::MergeSort::<
+                        mergesort::DefaultInsertionSort,
+                        super::two_way::Galloping,
+                    >::sort,
+                    <powersort::PowerSort>::sort,
+                    <powersort::MultiwayPowerSort>::sort,
+                    <mergesort::NaturalMergeSort>::sort,
+                ]
+            {
+                values.shuffle(&mut rng);
+                sort(&mut values);
+                assert!(values.iter().copied().eq(0..u32::try_from(size).unwrap()));
+            }
+        }
+    }
+
+    #[cfg(feature = "poison-buffer")]
+    #[test]
+    #[should_panic(expected = "Copied a poisoned element")]
+    fn test_poison_broken_merge() {
+        use super::BufGuard as _;
+
+        let mut slice = [3_u32, 4, 1, 2];
+        let mut buffer = <Vec<u32> as super::BufGuard<u32>>::with_capacity(slice.len());
+        let buffer = buffer.as_uninit_slice_mut();
+
+        // A broken merge copying back one element more than it moved into the buffer
+        // SAFETY: Both ranges are valid and disjoint, the buffer is poisoned
+        unsafe {
+            let mut left = super::Run(slice.as_mut_ptr()..slice.as_mut_ptr().add(2));
+            let mut copy =
+                super::Run(buffer.as_mut_ptr()..buffer.as_mut_ptr().add(4)).assume_init();
+            left.copy_nonoverlapping_prefix_to(&mut copy, 2);
+
+            let mut output = super::Run(slice.as_mut_ptr()..slice.as_mut_ptr().add(4));
+            let mut copied =
+                super::Run(buffer.as_mut_ptr()..buffer.as_mut_ptr().add(4)).assume_init();
+            copied.copy_nonoverlapping_prefix_to(&mut output, 3);
+        }
+    }
+
+    #[test]
+    fn test_run_indices_tiny() {
+        // Every ordering of up to two elements, with the expected weakly increasing prefix and
+        // suffix and strictly decreasing prefix and suffix indices
+        let cases: [(&[u32], [usize; 4]); 5] = [
+            (&[], [0, 0, 0, 0]),
+            (&[0], [1, 0, 1, 0]),
+            (&[0, 1], [2, 0, 1, 1]),
+            (&[1, 1], [2, 0, 1, 1]),
+            (&[1, 0], [1, 1, 2, 0]),
+        ];
+
+        for (slice, expected) in cases {
+            assert_eq!(
+                [
+                    weakly_increasing_prefix_index(slice),
+                    weakly_increasing_suffix_index(slice),
+                    strictly_decreasing_prefix_index(slice),
+                    strictly_decreasing_suffix_index(slice),
+                ],
+                expected,
+                "Unexpected indices for {slice:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_run_indices_exhaustive() {
+        let weakly_increasing: fn(&[u32]) -> bool = |run| run.is_sorted();
+        let strictly_decreasing: fn(&[u32]) -> bool = |run| run.is_sorted_by(|a, b| a > b);
+
+        // Every slice of up to 5 elements from 3 distinct values
+        for len in 0..=5 {
+            for code in 0..3_u32.pow(len) {
+                let slice: Vec<u32> = (0..len).map(|digit| code / 3_u32.pow(digit) % 3).collect();
+
+                for (is_run, prefix, suffix) in [
+                    (
+                        weakly_increasing,
+                        weakly_increasing_prefix_index(&slice),
+                        weakly_increasing_suffix_index(&slice),
+                    ),
+                    (
+                        strictly_decreasing,
+                        strictly_decreasing_prefix_index(&slice),
+                        strictly_decreasing_suffix_index(&slice),
+                    ),
+                ] {
+                    let expected_prefix = (0..=slice.len())
+                        .rev()
+                        .find(|&index| is_run(&slice[..index]))
+                        .unwrap();
+                    let expected_suffix = (0..=slice.len())
+                        .find(|&index| is_run(&slice[index..]))
+                        .unwrap();
+
+                    assert_eq!(prefix, expected_prefix, "Unexpected prefix for {slice:?}");
+                    assert_eq!(suffix, expected_suffix, "Unexpected suffix for {slice:?}");
+                }
+
+                // Either run starting at `0` is detected with its ordering
+                let mut detected = slice.clone();
+                let (index, ordering) =
+                    weakly_increasing_or_strictly_decreasing_index(&mut detected);
+                assert_eq!(detected, slice);
+                match ordering {
+                    RunOrdering::WeaklyIncreasing => {
+                        assert_eq!(index, weakly_increasing_prefix_index(&slice));
+                    }
+                    RunOrdering::StrictlyDecreasing => {
+                        assert!(index >= 2);
+                        assert_eq!(index, strictly_decreasing_prefix_index(&slice));
+                    }
+                }
+            }
+        }
+    }
+}
@@ -8,6 +8,11 @@ pub trait MultiMergingMethod<const K: usize> {
     /// Returns the string representation of this merging method.
     fn display() -> String;
 
+    /// Returns [`Self::IS_STABLE`], convenient where the method is only known at runtime.
+    fn is_stable() -> bool {
+        Self::IS_STABLE
+    }
+
     /// Merge the up to `K` sorted runs `slice[0..run_lengths[0]]`,
     /// `slice[run_lengths[0]..run_lengths[1]]`, ... using `buffer`.
     ///
@@ -18,6 +23,18 @@ pub trait MultiMergingMethod<const K: usize> {
         slice: &mut [T],
         run_lengths: &[usize],
         buffer: &mut [std::mem::MaybeUninit<T>],
+    ) {
+        Self::merge_by(slice, run_lengths, buffer, &mut T::cmp);
+    }
+
+    /// Like [`Self::merge()`], but orders the elements by `compare` instead of [`Ord`].
+    ///
+    /// All runs have to be sorted according to `compare`.
+    fn merge_by<T, F: super::Comparator<T>>(
+        slice: &mut [T],
+        run_lengths: &[usize],
+        buffer: &mut [std::mem::MaybeUninit<T>],
+        compare: &mut F,
     );
 
     /// The required capacity of the buffer, needed for merging slices with length less than
@@ -28,6 +45,9 @@ pub trait MultiMergingMethod<const K: usize> {
 }
 
 /// Merges multiple runs using a tournament tree.
+///
+/// After taking the minimum, only the path from its run's leaf to the root is replayed, so each
+/// element takes at most `ceil(log2(K))` comparisons.
 #[derive(Debug, Clone, Copy)]
 pub struct TournamentTree;
 
@@ -38,10 +58,11 @@ impl<const K: usize> MultiMergingMethod<K> for TournamentTree {
         format!("tournament-tree-{K}")
     }
 
-    fn merge<T: Ord>(
+    fn merge_by<T, F: super::Comparator<T>>(
         slice: &mut [T],
         run_lengths: &[usize],
         buffer: &mut [std::mem::MaybeUninit<T>],
+        compare: &mut F,
     ) {
         if slice.is_empty() {
             return;
@@ -71,6 +92,7 @@ impl<const K: usize> MultiMergingMethod<K> for TournamentTree {
         );
 
         let buffer = &mut buffer[..slice.len()];
+        super::record_buffer_use(buffer.len());
 
         // SAFETY: We copy each element into buffer and back exactly once, such that slice ends up
         // permuted. Since we have exclusive access to slice and buffer, the constructed pointer
@@ -106,7 +128,7 @@ impl<const K: usize> MultiMergingMethod<K> for TournamentTree {
             let output = &mut guard.output;
 
             // Perform the actual merge
-            Self::tournament_tree_merge(runs, output);
+            Self::tournament_tree_merge(runs, output, compare);
 
             debug_assert!(guard.is_empty());
 
@@ -117,16 +139,17 @@ impl<const K: usize> MultiMergingMethod<K> for TournamentTree {
 }
 
 impl TournamentTree {
-    /// Merges `runs` into `output` using a tournament tree.
+    /// Merges `runs` into `output` using a tournament tree, ordering the elements by `compare`.
     ///
     /// # Safety
     ///
     /// `runs` have to be valid to read and `output` has to be valid to write to.
     /// The sum of run lengths has to be equal to the length of output.
     /// Additionally, no memory regions of `runs` must overlap with `output`.
-    unsafe fn tournament_tree_merge<T: Ord, const K: usize>(
+    unsafe fn tournament_tree_merge<T, F: super::Comparator<T>, const K: usize>(
         runs: &mut [super::Run<T>; K],
         output: &mut super::Run<T>,
+        compare: &mut F,
     ) {
         /// Returns the index of the run with the smaller first element.
         ///
@@ -134,10 +157,11 @@ impl TournamentTree {
         ///
         /// # Safety
         /// each run in `runs` has to be valid to read from.
-        unsafe fn min_run<T: Ord, const K: usize>(
+        unsafe fn min_run<T, F: super::Comparator<T>, const K: usize>(
             index_a: usize,
             index_b: usize,
             runs: &[super::Run<T>; K],
+            compare: &mut F,
         ) -> usize {
             // SAFETY: see method doc.
             unsafe {
@@ -147,7 +171,9 @@ impl TournamentTree {
                 // We use the index as a second parameter of comparison to ensure stability.
                 if runs[index_b].is_empty()
                     || (!runs[index_a].is_empty()
-                        && (&*runs[index_a].start(), index_a) <= (&*runs[index_b].start(), index_b))
+                        && compare(&*runs[index_a].start(), &*runs[index_b].start())
+                            .then(index_a.cmp(&index_b))
+                            .is_le())
                 {
                     index_a
                 } else {
@@ -176,7 +202,7 @@ impl TournamentTree {
                 let left_child = index * 2 + 1;
                 let right_child = index * 2 + 2;
 
-                let min = min_run(nodes[left_child], nodes[right_child], runs);
+                let min = min_run(nodes[left_child], nodes[right_child], runs, compare);
                 nodes[index] = min;
             }
 
@@ -195,7 +221,7 @@ impl TournamentTree {
                     let left_child = node_index * 2 + 1;
                     let right_child = node_index * 2 + 2;
 
-                    let min = min_run(nodes[left_child], nodes[right_child], runs);
+                    let min = min_run(nodes[left_child], nodes[right_child], runs, compare);
 
                     nodes[node_index] = min;
                 }
@@ -215,10 +241,11 @@ impl MultiMergingMethod<4> for Fourway {
         "fourway".to_string()
     }
 
-    fn merge<T: Ord>(
+    fn merge_by<T, F: super::Comparator<T>>(
         slice: &mut [T],
         run_lengths: &[usize],
         buffer: &mut [std::mem::MaybeUninit<T>],
+        compare: &mut F,
     ) {
         if slice.is_empty() {
             return;
@@ -247,6 +274,7 @@ impl MultiMergingMethod<4> for Fourway {
         );
 
         let buffer = &mut buffer[..slice.len()];
+        super::record_buffer_use(buffer.len());
 
         // SAFETY: We copy each element into buffer and back exactly once, such that slice ends up
         // permuted. Since we have exclusive access to slice and buffer, the constructed pointer
@@ -282,7 +310,7 @@ impl MultiMergingMethod<4> for Fourway {
             let output = &mut guard.output;
 
             // Perform the actual merge
-            Self::merge(runs, output);
+            Self::merge(runs, output, compare);
 
             debug_assert!(guard.is_empty());
 
@@ -293,24 +321,30 @@ impl MultiMergingMethod<4> for Fourway {
 }
 
 impl Fourway {
-    /// Merges `runs` into `output` using a four-way tournament tree.
-    unsafe fn merge<T: Ord>(runs: &mut [super::Run<T>; 4], output: &mut super::Run<T>) {
+    /// Merges `runs` into `output` using a four-way tournament tree, ordering the elements by
+    /// `compare`.
+    unsafe fn merge<T, F: super::Comparator<T>>(
+        runs: &mut [super::Run<T>; 4],
+        output: &mut super::Run<T>,
+        compare: &mut F,
+    ) {
         /// Returns the index of the run with the smaller first element.
         ///
         /// Guaranteed to always return the index of an inhabited run unless both are empty.
         ///
         /// # Safety
         /// each run in `runs` has to be valid to read from.
-        unsafe fn min_run<T: Ord>(
+        unsafe fn min_run<T, F: super::Comparator<T>>(
             index_a: usize,
             index_b: usize,
             runs: &[super::Run<T>; 4],
+            compare: &mut F,
         ) -> usize {
             // SAFETY: see method doc.
             unsafe {
                 if runs[index_b].is_empty()
                     || (!runs[index_a].is_empty()
-                        && *runs[index_a].start() <= *runs[index_b].start())
+                        && compare(&*runs[index_a].start(), &*runs[index_b].start()).is_le())
                 {
                     index_a
                 } else {
@@ -324,9 +358,9 @@ impl Fourway {
         // all `output.len()` elements there exists at least one of these runs.
         unsafe {
             // Construct initial tournament tree
-            let mut left = min_run(0, 1, runs);
-            let mut right = min_run(2, 3, runs);
-            let mut root = min_run(left, right, runs);
+            let mut left = min_run(0, 1, runs, compare);
+            let mut right = min_run(2, 3, runs, compare);
+            let mut root = min_run(left, right, runs, compare);
 
             for _ in 0..output.len() {
                 // Copy minimum run
@@ -334,12 +368,323 @@ impl Fourway {
 
                 // Update tournament tree
                 if root < 2 {
-                    left = min_run(0, 1, runs);
+                    left = min_run(0, 1, runs, compare);
                 } else {
-                    right = min_run(2, 3, runs);
+                    right = min_run(2, 3, runs, compare);
+                }
+                root = min_run(left, right, runs, compare);
+            }
+        }
+    }
+}
+
+/// A [`MultiMergingMethod`] that copies all runs into `buffer` and merges them back by
+/// repeatedly taking the minimum of all run heads.
+///
+/// This is the simplest correct reference, performing `K - 1` comparisons per element.
+#[derive(Debug, Clone, Copy)]
+pub struct CopyAll;
+
+impl<const K: usize> MultiMergingMethod<K> for CopyAll {
+    const IS_STABLE: bool = true;
+
+    fn display() -> String {
+        "copy-all".to_string()
+    }
+
+    fn merge_by<T, F: super::Comparator<T>>(
+        slice: &mut [T],
+        run_lengths: &[usize],
+        buffer: &mut [std::mem::MaybeUninit<T>],
+        compare: &mut F,
+    ) {
+        if slice.is_empty() {
+            return;
+        }
+
+        #[cfg(feature = "counters")]
+        #[expect(
+            clippy::as_conversions,
+            reason = "slice.len() will realistically stay way below u64::MAX, so this is lossless"
+        )]
+        {
+            crate::GLOBAL_COUNTERS
+                .merge_slice
+                .increase(slice.len() as u64);
+            crate::GLOBAL_COUNTERS
+                .merge_buffer
+                .increase(slice.len() as u64);
+        }
+
+        assert!(
+            buffer.len() >= slice.len(),
+            "Buffer needs to have at least the size of slice"
+        );
+        assert!(
+            (run_lengths).iter().sum::<usize>() <= slice.len(),
+            "Run length sum must be smaller or equal to slice.len()"
+        );
+
+        let buffer = &mut buffer[..slice.len()];
+        super::record_buffer_use(buffer.len());
+
+        // SAFETY: We copy each element into buffer and back exactly once, such that slice ends up
+        // permuted. Since we have exclusive access to slice and buffer, the constructed pointer
+        // ranges are safe to read from and write to.
+        unsafe {
+            // Copy entire slice into buffer
+            std::ptr::copy_nonoverlapping(slice.as_ptr(), buffer.as_mut_ptr().cast(), slice.len());
+
+            let ptr_range = buffer.as_mut_ptr_range();
+            let mut run_end = ptr_range.start;
+
+            // Construct the runs from run_lengths
+            let runs: [_; K] = std::array::from_fn(|i| {
+                let run_start = run_end;
+                run_end = run_lengths
+                    .get(i)
+                    .map(|len| run_start.add(*len))
+                    .unwrap_or(ptr_range.end);
+
+                // Assume init, since we just copied the elements into buffer
+                super::Run(run_start..run_end).assume_init()
+            });
+
+            // We write back output into slice
+            let output = super::Run(slice.as_mut_ptr_range());
+
+            // We know all runs and output are valid by construction.
+            // This guard ensures all elements end up copied back, even if a comparison panics.
+            let mut guard = super::MergingDropGuard::new(runs, output);
+
+            // References for easier access, guard is still responsible for cleaning up
+            let runs = &mut guard.runs;
+            let output = &mut guard.output;
+
+            // Repeatedly copy the minimum head, taking the first run on ties to ensure stability
+            for _ in 0..output.len() {
+                let mut min = 0;
+                for index in 1..K {
+                    if !runs[index].is_empty()
+                        && (runs[min].is_empty()
+                            || compare(&*runs[index].start(), &*runs[min].start()).is_lt())
+                    {
+                        min = index;
+                    }
+                }
+
+                runs[min].copy_nonoverlapping_prefix_to(output, 1);
+            }
+
+            debug_assert!(guard.is_empty());
+
+            // At this point we are done, so this guard is unnecessary
+            guard.disarm();
+        }
+    }
+}
+
+/// Integers whose run heads [`SimdMinMerge`] compares all at once in a single vector.
+#[cfg(feature = "simd")]
+pub trait SimdOrd: Ord + Copy {
+    /// The largest value, filling the lanes of exhausted runs
+    const MAX: Self;
+
+    /// Returns the first of the `active` lanes holding the minimum of `fronts`.
+    ///
+    /// At least one lane has to be active and all inactive lanes have to hold [`Self::MAX`].
+    fn first_min_lane<const K: usize>(fronts: [Self; K], active: [bool; K]) -> usize
+    where
+        std::simd::LaneCount<K>: std::simd::SupportedLaneCount;
+}
+
+/// Implements [`SimdOrd`] for the given unsigned integers.
+#[cfg(feature = "simd")]
+macro_rules! impl_simd_ord {
+    ($($int:ty),*) => {
+        $(
+            impl SimdOrd for $int {
+                const MAX: Self = <$int>::MAX;
+
+                fn first_min_lane<const K: usize>(fronts: [Self; K], active: [bool; K]) -> usize
+                where
+                    std::simd::LaneCount<K>: std::simd::SupportedLaneCount,
+                {
+                    use std::simd::cmp::SimdPartialEq as _;
+                    use std::simd::num::SimdUint as _;
+
+                    let fronts = std::simd::Simd::<$int, K>::from_array(fronts);
+                    let min = fronts.reduce_min();
+
+                    // Exhausted runs hold the maximum as well, so they are masked out
+                    (fronts.simd_eq(std::simd::Simd::splat(min))
+                        & std::simd::Mask::from_array(active))
+                    .first_set()
+                    .expect("At least one lane is active")
                 }
-                root = min_run(left, right, runs);
             }
+        )*
+    };
+}
+
+#[cfg(feature = "simd")]
+impl_simd_ord!(u32, u64);
+
+/// A [`MultiMergingMethod`] selecting the next element by loading all `K` run heads into a vector
+/// and finding the first lane holding their minimum, for integers implementing [`SimdOrd`].
+///
+/// Taking the first minimal lane keeps the merge stable. Other element types, as well as merges
+/// with a custom comparator, fall back to the scalar [`CopyAll`].
+#[cfg(feature = "simd")]
+#[derive(Debug, Clone, Copy)]
+pub struct SimdMinMerge;
+
+#[cfg(feature = "simd")]
+impl<const K: usize> MultiMergingMethod<K> for SimdMinMerge
+where
+    std::simd::LaneCount<K>: std::simd::SupportedLaneCount,
+{
+    const IS_STABLE: bool = true;
+
+    fn display() -> String {
+        format!("simd-min-{K}")
+    }
+
+    fn merge<T: Ord>(
+        slice: &mut [T],
+        run_lengths: &[usize],
+        buffer: &mut [std::mem::MaybeUninit<T>],
+    ) {
+        <T as SimdMinMergeElement<K>>::merge(slice, run_lengths, buffer);
+    }
+
+    fn merge_by<T, F: super::Comparator<T>>(
+        slice: &mut [T],
+        run_lengths: &[usize],
+        buffer: &mut [std::mem::MaybeUninit<T>],
+        compare: &mut F,
+    ) {
+        <CopyAll as MultiMergingMethod<K>>::merge_by(slice, run_lengths, buffer, compare);
+    }
+}
+
+/// Selects the vectorized [`SimdMinMerge`] for the [`SimdOrd`] integers and [`CopyAll`] otherwise.
+#[cfg(feature = "simd")]
+trait SimdMinMergeElement<const K: usize>: Ord + Sized {
+    /// Merges the runs like [`MultiMergingMethod::merge()`].
+    fn merge(slice: &mut [Self], run_lengths: &[usize], buffer: &mut [std::mem::MaybeUninit<Self>]);
+}
+
+#[cfg(feature = "simd")]
+impl<T: Ord, const K: usize> SimdMinMergeElement<K> for T
+where
+    std::simd::LaneCount<K>: std::simd::SupportedLaneCount,
+{
+    default fn merge(
+        slice: &mut [T],
+        run_lengths: &[usize],
+        buffer: &mut [std::mem::MaybeUninit<T>],
+    ) {
+        <CopyAll as MultiMergingMethod<K>>::merge(slice, run_lengths, buffer);
+    }
+}
+
+/// Specializes [`SimdMinMergeElement`] for the given [`SimdOrd`] integers.
+#[cfg(feature = "simd")]
+macro_rules! impl_simd_min_merge_element {
+    ($($int:ty),*) => {
+        $(
+            impl<const K: usize> SimdMinMergeElement<K> for $int
+            where
+                std::simd::LaneCount<K>: std::simd::SupportedLaneCount,
+            {
+                fn merge(
+                    slice: &mut [Self],
+                    run_lengths: &[usize],
+                    buffer: &mut [std::mem::MaybeUninit<Self>],
+                ) {
+                    SimdMinMerge::merge_simd::<Self, K>(slice, run_lengths, buffer);
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "simd")]
+impl_simd_min_merge_element!(u32, u64);
+
+#[cfg(feature = "simd")]
+impl SimdMinMerge {
+    /// Merges the up to `K` sorted runs of `slice` like [`MultiMergingMethod::merge()`], selecting
+    /// each element with [`SimdOrd::first_min_lane()`].
+    fn merge_simd<T: SimdOrd, const K: usize>(
+        slice: &mut [T],
+        run_lengths: &[usize],
+        buffer: &mut [std::mem::MaybeUninit<T>],
+    ) where
+        std::simd::LaneCount<K>: std::simd::SupportedLaneCount,
+    {
+        if slice.is_empty() {
+            return;
+        }
+
+        #[cfg(feature = "counters")]
+        #[expect(
+            clippy::as_conversions,
+            reason = "slice.len() will realistically stay way below u64::MAX, so this is lossless"
+        )]
+        {
+            crate::GLOBAL_COUNTERS
+                .merge_slice
+                .increase(slice.len() as u64);
+            crate::GLOBAL_COUNTERS
+                .merge_buffer
+                .increase(slice.len() as u64);
+        }
+
+        assert!(
+            buffer.len() >= slice.len(),
+            "Buffer needs to have at least the size of slice"
+        );
+        assert!(
+            (run_lengths).iter().sum::<usize>() <= slice.len(),
+            "Run length sum must be smaller or equal to slice.len()"
+        );
+
+        // Copy entire slice into buffer, the elements are `Copy` so nothing has to be written
+        // back on panic
+        let buffer = &mut buffer[..slice.len()];
+        super::record_buffer_use(buffer.len());
+        for (buffered, element) in buffer.iter_mut().zip(slice.iter()) {
+            buffered.write(*element);
+        }
+        // SAFETY: Every element of `buffer` was initialized above
+        let input =
+            unsafe { std::slice::from_raw_parts(buffer.as_ptr().cast::<T>(), buffer.len()) };
+
+        // The remaining range of each run within `input`
+        let mut run_end = 0;
+        let mut runs: [_; K] = std::array::from_fn(|i| {
+            let run_start = run_end;
+            run_end = run_lengths
+                .get(i)
+                .map_or(input.len(), |len| run_start + len);
+
+            run_start..run_end
+        });
+        let mut fronts = runs
+            .clone()
+            .map(|run| input.get(run.start).filter(|_| !run.is_empty()).copied());
+
+        for output in slice.iter_mut() {
+            let lane = T::first_min_lane(
+                fronts.map(|front| front.unwrap_or(T::MAX)),
+                fronts.map(|front| front.is_some()),
+            );
+
+            *output = fronts[lane].expect("The minimal lane is active");
+            runs[lane].start += 1;
+            fronts[lane] = input[runs[lane].clone()].first().copied();
         }
     }
 }
@@ -352,16 +697,21 @@ impl<M: super::two_way::MergingMethod> MultiMergingMethod<2> for M {
         M::display()
     }
 
-    fn merge<T: Ord>(
+    fn is_stable() -> bool {
+        M::is_stable()
+    }
+
+    fn merge_by<T, F: super::Comparator<T>>(
         slice: &mut [T],
         run_lengths: &[usize],
         buffer: &mut [std::mem::MaybeUninit<T>],
+        compare: &mut F,
     ) {
         if run_lengths.is_empty() {
             return;
         }
 
-        M::merge(slice, run_lengths[0], buffer);
+        M::merge_by(slice, run_lengths[0], buffer, compare);
     }
 }
 
@@ -407,6 +757,34 @@ mod tests {
                     crate::test::merging::test_soundness_merge::<$method, K>();
                 });
             }
+
+            #[test]
+            fn test_reversed_merges() {
+                test_multi_methods!(@all_k [$($k),*] => K => {
+                    crate::test::merging::test_reversed_merge::<$method, K>();
+                });
+            }
+
+            #[test]
+            fn test_arbitrary_runs_merges() {
+                test_multi_methods!(@all_k [$($k),*] => K => {
+                    crate::test::merging::test_arbitrary_runs_merge::<$method, K>();
+                });
+            }
+
+            #[test]
+            fn test_empty_runs_merges() {
+                test_multi_methods!(@all_k [$($k),*] => K => {
+                    crate::test::merging::test_empty_runs_merge::<$method, K>();
+                });
+            }
+
+            #[test]
+            fn test_display_stability() {
+                test_multi_methods!(@all_k [$($k),*] => K => {
+                    crate::test::merging::test_display_stability::<$method, K>();
+                });
+            }
         };
         (@all_k [$($value:expr),*] => $k:ident => $code:block) => {
             $(
@@ -422,5 +800,135 @@ mod tests {
     test_multi_methods! {
         tournament_tree: TournamentTree [2, 3, 4, 5, 6, 7, 8],
         fourway: Fourway [4],
+        copy_all: CopyAll [2, 3, 4, 5, 6, 7, 8],
+    }
+
+    #[cfg(feature = "simd")]
+    test_multi_methods! {
+        simd_min: SimdMinMerge [2, 4, 8],
+    }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn test_simd_min_merge() {
+        use rand::Rng as _;
+
+        /// Merges random runs of `T` with `SimdMinMerge` and checks the result equals that of
+        /// `TournamentTree`.
+        fn check<T: SimdOrd + std::fmt::Debug + TryFrom<u64>, const K: usize>(
+            rng: &mut crate::test::Rng,
+        ) where
+            std::simd::LaneCount<K>: std::simd::SupportedLaneCount,
+            TournamentTree: MultiMergingMethod<K>,
+            <T as TryFrom<u64>>::Error: std::fmt::Debug,
+        {
+            const SIZE: usize = 1_000;
+
+            let mut buffer = Vec::with_capacity(SIZE);
+
+            for max in [2, 100, u64::MAX] {
+                let mut elements: Vec<T> = (0..SIZE)
+                    .map(|_| {
+                        // Include the maximum, which also fills the lanes of exhausted runs
+                        if rng.random_bool(0.05) {
+                            T::MAX
+                        } else {
+                            T::try_from(rng.random_range(0..max).min(u64::from(u32::MAX))).unwrap()
+                        }
+                    })
+                    .collect();
+
+                // Random run lengths, including empty runs
+                let mut splits: Vec<usize> =
+                    (0..K - 1).map(|_| rng.random_range(0..=SIZE)).collect();
+                splits.sort();
+                let mut run_lengths = Vec::with_capacity(K - 1);
+                let mut start = 0;
+                for split in splits {
+                    elements[start..split].sort();
+                    run_lengths.push(split - start);
+                    start = split;
+                }
+                elements[start..].sort();
+
+                let mut expected = elements.clone();
+                <TournamentTree as MultiMergingMethod<K>>::merge(
+                    &mut expected,
+                    &run_lengths,
+                    buffer.spare_capacity_mut(),
+                );
+                <SimdMinMerge as MultiMergingMethod<K>>::merge(
+                    &mut elements,
+                    &run_lengths,
+                    buffer.spare_capacity_mut(),
+                );
+
+                assert_eq!(elements, expected, "Merging {run_lengths:?} differed");
+            }
+        }
+
+        let mut rng = crate::test::test_rng();
+        for _ in 0..crate::test::DEFAULT_TEST_RUNS {
+            check::<u32, 4>(&mut rng);
+            check::<u32, 8>(&mut rng);
+            check::<u64, 4>(&mut rng);
+            check::<u64, 8>(&mut rng);
+        }
+    }
+
+    #[test]
+    fn test_tournament_tree_comparisons() {
+        /// Merges `K` interleaved runs, which all stay inhabited until the last `K` elements, and
+        /// checks that `TournamentTree` only replays the path of the taken element, while `CopyAll`
+        /// compares all run heads.
+        fn check<const K: usize>() {
+            const RUN_LENGTH: usize = 1_000;
+            let len = RUN_LENGTH * K;
+
+            let elements: Vec<_> = (0..K)
+                .flat_map(|run| (0..RUN_LENGTH).map(move |i| i * K + run))
+                .map(crate::test::CountingOrdered)
+                .collect();
+            let run_lengths = [RUN_LENGTH; K];
+            let mut buffer = Vec::with_capacity(len);
+
+            let mut tree_elements = elements.clone();
+            let tree_comparisons = crate::test::count_comparisons(|| {
+                <TournamentTree as MultiMergingMethod<K>>::merge(
+                    &mut tree_elements,
+                    &run_lengths[..K - 1],
+                    buffer.spare_capacity_mut(),
+                )
+            });
+
+            let mut copy_all_elements = elements;
+            let copy_all_comparisons = crate::test::count_comparisons(|| {
+                <CopyAll as MultiMergingMethod<K>>::merge(
+                    &mut copy_all_elements,
+                    &run_lengths[..K - 1],
+                    buffer.spare_capacity_mut(),
+                )
+            });
+
+            assert!(tree_elements.is_sorted() && copy_all_elements.is_sorted());
+
+            // Building the tree takes `K - 1` comparisons, each element at most `ceil(log2(K))`
+            let depth = u64::from(K.next_power_of_two().ilog2());
+            let len = u64::try_from(len).unwrap();
+            let k = u64::try_from(K).unwrap();
+            assert!(
+                tree_comparisons <= k - 1 + len * depth,
+                "{tree_comparisons} comparisons for merging {len} elements with K = {K}"
+            );
+            assert!(copy_all_comparisons >= (len - k) * (k - 1));
+        }
+
+        check::<2>();
+        check::<3>();
+        check::<4>();
+        check::<5>();
+        check::<7>();
+        check::<8>();
+        check::<16>();
     }
 }
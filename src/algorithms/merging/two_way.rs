@@ -8,11 +8,52 @@ pub trait MergingMethod {
     /// Returns the string representation of this merging method.
     fn display() -> String;
 
+    /// Returns [`Self::IS_STABLE`], convenient where the method is only known at runtime.
+    fn is_stable() -> bool {
+        Self::IS_STABLE
+    }
+
     /// Merges the two sorted runs `slice[0..run_length]` and `slice[run_length..slice.len()]`,
     /// potentially using `buffer`.
     ///
     /// `buffer.len()` should be greater or equal to `Self::required_capacity(slice.len())`.
-    fn merge<T: Ord>(slice: &mut [T], run_length: usize, buffer: &mut [std::mem::MaybeUninit<T>]);
+    fn merge<T: Ord>(slice: &mut [T], run_length: usize, buffer: &mut [std::mem::MaybeUninit<T>]) {
+        Self::merge_by(slice, run_length, buffer, &mut T::cmp);
+    }
+
+    /// Like [`Self::merge()`], but orders the elements by `compare` instead of [`Ord`].
+    ///
+    /// Both runs have to be sorted according to `compare`.
+    fn merge_by<T, F: super::Comparator<T>>(
+        slice: &mut [T],
+        run_length: usize,
+        buffer: &mut [std::mem::MaybeUninit<T>],
+        compare: &mut F,
+    );
+
+    /// Like [`Self::merge()`], but carries `state` over from the previous merges of the same sort.
+    ///
+    /// Methods without any state just [`merge()`](Self::merge()).
+    fn merge_with_state<T: Ord>(
+        slice: &mut [T],
+        run_length: usize,
+        buffer: &mut [std::mem::MaybeUninit<T>],
+        state: &mut MergeState,
+    ) {
+        Self::merge_with_state_by(slice, run_length, buffer, state, &mut T::cmp);
+    }
+
+    /// Like [`Self::merge_with_state()`], but orders the elements by `compare` instead of [`Ord`].
+    fn merge_with_state_by<T, F: super::Comparator<T>>(
+        slice: &mut [T],
+        run_length: usize,
+        buffer: &mut [std::mem::MaybeUninit<T>],
+        state: &mut MergeState,
+        compare: &mut F,
+    ) {
+        let _ = state;
+        Self::merge_by(slice, run_length, buffer, compare);
+    }
 
     /// The required capacity of the buffer, needed for merging slices with length less than
     /// or equal to `size`.
@@ -21,7 +62,18 @@ pub trait MergingMethod {
     }
 }
 
+/// The state of a [`MergingMethod`] carried over between the merges of one sort, see
+/// [`MergingMethod::merge_with_state()`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MergeState {
+    /// The `min_gallop` threshold [`Galloping`] adapted to in the previous merges, if any
+    pub min_gallop: Option<usize>,
+}
+
 /// A [`MergingMethod`] that copies all elements into `buffer` and does a simple merge back.
+///
+/// Each element is moved exactly twice, once into `buffer` and once merged into its final
+/// position in `slice`, there is no separate copy back.
 #[derive(Debug, Clone, Copy)]
 pub struct CopyBoth;
 
@@ -32,7 +84,12 @@ impl MergingMethod for CopyBoth {
         "copy-both".to_string()
     }
 
-    fn merge<T: Ord>(slice: &mut [T], run_length: usize, buffer: &mut [std::mem::MaybeUninit<T>]) {
+    fn merge_by<T, F: super::Comparator<T>>(
+        slice: &mut [T],
+        run_length: usize,
+        buffer: &mut [std::mem::MaybeUninit<T>],
+        compare: &mut F,
+    ) {
         if slice.is_empty() {
             return;
         }
@@ -56,33 +113,77 @@ impl MergingMethod for CopyBoth {
             "Buffer needs to have at least the size of slice"
         );
         assert!(
-            (0..slice.len()).contains(&run_length),
+            run_length <= slice.len(),
             "run_lengths needs to be less than or equal to slice.len()"
         );
 
         let buffer = &mut buffer[..slice.len()];
+        super::record_buffer_use(buffer.len());
 
         // SAFETY: We copy each element into buffer and back exactly once, such that slice ends up
-        // permuted. Since we have exclusive access to slice and buffer, the constructed pointer
-        // ranges are safe to read from and write to.
+        // permuted. Since we have exclusive access to slice and buffer, the constructed pointers
+        // are safe to read from and write to.
         unsafe {
             // Copy entire slice into buffer
             std::ptr::copy_nonoverlapping(slice.as_ptr(), buffer.as_mut_ptr().cast(), slice.len());
 
-            // Construct the runs.
-            // These are safe to assume init since we just copied over the elements.
-            let ptr_range = buffer.as_mut_ptr_range();
+            // Merge back into slice, buffer is initialized since we just copied over the elements
+            PingPongMerge::merge_into(
+                buffer.as_mut_ptr().cast::<T>(),
+                slice.len(),
+                run_length,
+                slice.as_mut_ptr(),
+                compare,
+            );
+        }
+    }
+}
+
+/// A building block for ping-pong merging, which merges two adjacent runs from one location into
+/// another, instead of back into the same location, see [`PingPongMerge::merge_into()`].
+#[derive(Debug, Clone, Copy)]
+pub struct PingPongMerge;
+
+impl PingPongMerge {
+    /// Merges the two runs `source[..run_length]` and `source[run_length..len]`, sorted according
+    /// to `compare`, into `destination[..len]`.
+    ///
+    /// If a comparison panics, all remaining elements are copied into `destination` as well, such
+    /// that it always ends up as a permutation of `source`.
+    ///
+    /// # Safety
+    ///
+    /// `source[..len]` must be initialized and valid for reads, `destination[..len]` must be valid
+    /// for writes and both must not overlap.
+    /// Afterwards both contain bitwise copies of the same elements, the caller is responsible to
+    /// only treat one of them as initialized.
+    pub unsafe fn merge_into<T, F: super::Comparator<T>>(
+        source: *mut T,
+        len: usize,
+        run_length: usize,
+        destination: *mut T,
+        compare: &mut F,
+    ) {
+        assert!(
+            run_length <= len,
+            "run_lengths needs to be less than or equal to len"
+        );
+
+        // SAFETY: see method doc, all constructed ranges lie within `source[..len]` and
+        // `destination[..len]` respectively.
+        unsafe {
+            // Construct the runs
             let runs = [
-                super::Run(ptr_range.start..ptr_range.start.add(run_length)).assume_init(),
-                super::Run(ptr_range.start.add(run_length)..ptr_range.end).assume_init(),
+                super::Run(source..source.add(run_length)),
+                super::Run(source.add(run_length)..source.add(len)),
             ];
 
             // Construct the `output` run
-            let output = super::Run(slice.as_mut_ptr_range());
+            let output = super::Run(destination..destination.add(len));
 
             // All runs and output are valid by construction.
-            // This makes sure each element in `buffer` gets copied back, even if a comparison
-            // panics.
+            // This makes sure each element in `source` gets copied to `destination`, even if a
+            // comparison panics.
             let mut guard = super::MergingDropGuard::new(runs, output);
 
             // Destructure bindings for easier access, these are only references and
@@ -90,16 +191,16 @@ impl MergingMethod for CopyBoth {
             let &mut [ref mut left, ref mut right] = &mut guard.runs;
             let output = &mut guard.output;
 
-            // Repeatedly copy the smaller element of both runs into the slice
+            // Repeatedly copy the smaller element of both runs into the output
             while !left.is_empty() && !right.is_empty() {
-                if *left.start() <= *right.start() {
+                if compare(&*left.start(), &*right.start()).is_le() {
                     left.copy_nonoverlapping_prefix_to(output, 1);
                 } else {
                     right.copy_nonoverlapping_prefix_to(output, 1);
                 }
             }
 
-            // Copy the rest of the remaining runs into the slice
+            // Copy the rest of the remaining runs into the output
             if !left.is_empty() {
                 left.copy_nonoverlapping_prefix_to(output, left.len());
             }
@@ -115,83 +216,199 @@ impl MergingMethod for CopyBoth {
     }
 }
 
+/// Returns the string representation of [`Galloping`] with the given `min_gallop` threshold, also
+/// usable when the threshold is only known at runtime.
+pub fn display_galloping(min_gallop: usize) -> String {
+    format!("galloping (MIN_GALLOP = {min_gallop})")
+}
+
 /// A [`MergingMethod`] that utilizes a galloping strategy taken from Timsort.
+///
+/// - `MIN_GALLOP` is the number of consecutive elements taken from the same run, after which the
+///   merge switches to galloping. Smaller thresholds gallop sooner.
 #[derive(Debug, Clone, Copy)]
 pub struct Galloping<const MIN_GALLOP: usize = 7>;
 
+/// Statistics about the elements merged by [`Galloping`] on the current thread, see
+/// [`gallop_stats()`].
+///
+/// The statistics are only recorded if the `counters` feature is active.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct GallopStats {
+    /// The number of elements merged
+    pub merged: u64,
+    /// The number of elements merged one at a time, instead of by galloping
+    pub single: u64,
+    /// The number of times `min_gallop` was decremented
+    pub min_gallop_decrements: u64,
+}
+
+impl GallopStats {
+    /// Returns the number of elements merged by galloping or bulk copying.
+    pub fn galloped(&self) -> u64 {
+        self.merged - self.single
+    }
+}
+
+thread_local! {
+    /// The statistics recorded by [`Galloping`] merges on this thread
+    static GALLOP_STATS: std::cell::Cell<GallopStats> = const {
+        std::cell::Cell::new(GallopStats {
+            merged: 0,
+            single: 0,
+            min_gallop_decrements: 0,
+        })
+    };
+}
+
+/// Returns the statistics recorded by [`Galloping`] merges on this thread since the last
+/// [`reset_gallop_stats()`].
+pub fn gallop_stats() -> GallopStats {
+    GALLOP_STATS.get()
+}
+
+/// Resets the statistics recorded by [`Galloping`] merges on this thread.
+pub fn reset_gallop_stats() {
+    GALLOP_STATS.set(GallopStats::default());
+}
+
+/// Updates the statistics recorded by [`Galloping`] merges on this thread.
+#[cfg(feature = "counters")]
+fn record_gallop_stats(update: impl FnOnce(&mut GallopStats)) {
+    let mut stats = GALLOP_STATS.get();
+    update(&mut stats);
+    GALLOP_STATS.set(stats);
+}
+
 impl<const MIN_GALLOP: usize> MergingMethod for Galloping<MIN_GALLOP> {
     const IS_STABLE: bool = true;
 
     fn display() -> String {
-        format!("galloping (MIN_GALLOP = {MIN_GALLOP})")
+        display_galloping(MIN_GALLOP)
     }
 
-    fn merge<T: Ord>(slice: &mut [T], run_length: usize, buffer: &mut [std::mem::MaybeUninit<T>]) {
-        if slice.len() < 2 || run_length == 0 {
+    fn merge_by<T, F: super::Comparator<T>>(
+        slice: &mut [T],
+        run_length: usize,
+        buffer: &mut [std::mem::MaybeUninit<T>],
+        compare: &mut F,
+    ) {
+        Self::merge_with_state_by(
+            slice,
+            run_length,
+            buffer,
+            &mut MergeState::default(),
+            compare,
+        );
+    }
+
+    fn merge_with_state_by<T, F: super::Comparator<T>>(
+        slice: &mut [T],
+        run_length: usize,
+        buffer: &mut [std::mem::MaybeUninit<T>],
+        state: &mut MergeState,
+        compare: &mut F,
+    ) {
+        if slice.len() < 2 || run_length == 0 || run_length == slice.len() {
             return;
         }
 
+        #[cfg(feature = "counters")]
+        #[expect(
+            clippy::as_conversions,
+            reason = "slice.len() will realistically stay way below u64::MAX, so this is lossless"
+        )]
+        record_gallop_stats(|stats| stats.merged += slice.len() as u64);
+
+        // The runs are already in order if their boundary is, in particular if the keys around it
+        // are equal, since equal left elements stay before the right ones. This makes merging a
+        // stretch of equal keys spanning both runs take a single comparison.
+        if compare(&slice[run_length - 1], &slice[run_length]).is_le() {
+            return;
+        }
+
+        // Checked once per merge instead of in every `gallop()`, which would make galloping linear
+        debug_assert!(crate::data::uncounted(|| {
+            slice[..run_length].is_sorted_by(|a, b| compare(a, b).is_le())
+                && slice[run_length..].is_sorted_by(|a, b| compare(a, b).is_le())
+        }));
+
         // Gallop right to exclude elements from the left run that are smaller than all from the
-        // right run.
-        let start = Self::gallop::<T, false>(&slice[run_length], &slice[..run_length], 0);
+        // right run. Some usually remain, since the runs are out of order at the boundary.
+        let start =
+            Self::gallop::<T, F, false>(&slice[run_length], &slice[..run_length], 0, compare);
+        // Only reachable for inconsistent orders
         if start == run_length {
             return;
         }
 
         // Gallop left to exclude elements from the right run that are larger than all from the
         // left run.
-        let end = Self::gallop::<T, true>(
+        let end = Self::gallop::<T, F, true>(
             &slice[run_length - 1],
             &slice[run_length..],
             slice.len() - run_length - 1,
+            compare,
         ) + run_length;
+        // Only reachable for inconsistent orders
         if end == run_length {
             return;
         }
 
-        let mut min_gallop = MIN_GALLOP;
+        let mut min_gallop = state.min_gallop.unwrap_or(MIN_GALLOP);
 
         // Merge depending on the smaller run
         if run_length - start <= end - run_length {
-            Self::merge_low(
+            Self::merge_from::<T, F, false>(
                 &mut slice[start..end],
                 run_length - start,
                 buffer,
                 &mut min_gallop,
+                compare,
             );
         } else {
-            Self::merge_high(
+            Self::merge_from::<T, F, true>(
                 &mut slice[start..end],
                 run_length - start,
                 buffer,
                 &mut min_gallop,
+                compare,
             );
         }
+
+        // Carry the adapted threshold over to the next merge
+        state.min_gallop = Some(min_gallop);
     }
 }
 
 impl<const MIN_GALLOP: usize> Galloping<MIN_GALLOP> {
     /// Returns the index `i` such that after inserting `key` between index at `i`, `slice` is
-    /// still sorted. Assumes `slice` is sorted.
+    /// still sorted. Assumes `slice` is sorted according to `compare`.
     ///
     /// The starting point `hint` indicates from where to start galloping.
     ///
     /// `BEFORE_EQUAL` determines if `i` is chosen before equal elements and otherwise after them.
-    fn gallop<T: Ord, const BEFORE_EQUAL: bool>(key: &T, slice: &[T], hint: usize) -> usize {
-        debug_assert!(slice.is_sorted());
-        assert!((0..slice.len()).contains(&hint));
+    fn gallop<T, F: super::Comparator<T>, const BEFORE_EQUAL: bool>(
+        key: &T,
+        slice: &[T],
+        hint: usize,
+        compare: &mut F,
+    ) -> usize {
+        // Only an invariant, the indexing below is bounds checked anyway
+        debug_assert!((0..slice.len()).contains(&hint));
 
         let mut previous_offset = 0;
         let mut offset = 1;
 
-        // Determine comparison functions depending on galloping direction
-        type Comparator<T> = fn(&T, &T) -> bool;
-        let (should_insert_past, should_not_insert_past): (Comparator<T>, Comparator<T>) =
+        // Determine the comparison depending on galloping direction, i.e. `key > x` before equal
+        // elements and `key >= x` after them
+        let mut should_insert_past = |key: &T, x: &T| {
             if BEFORE_EQUAL {
-                (T::gt, T::le)
+                compare(key, x).is_gt()
             } else {
-                (T::ge, T::lt)
-            };
+                compare(key, x).is_ge()
+            }
+        };
 
         // Check if we're searching `slice[..hint]` or `slice[hint..]`
         if should_insert_past(key, &slice[hint]) {
@@ -199,7 +416,7 @@ impl<const MIN_GALLOP: usize> Galloping<MIN_GALLOP> {
             let max_offset = slice.len() - hint;
             while offset < max_offset && should_insert_past(key, &slice[hint + offset]) {
                 previous_offset = offset;
-                offset = (offset << 1) + 1;
+                offset = Self::grow_offset(offset, max_offset);
             }
             // Invariants:
             // - insert after hint + previous_offset
@@ -213,9 +430,9 @@ impl<const MIN_GALLOP: usize> Galloping<MIN_GALLOP> {
         } else {
             // Use quadratic search to find the containing interval
             let max_offset = hint + 1;
-            while offset < max_offset && should_not_insert_past(key, &slice[hint - offset]) {
+            while offset < max_offset && !should_insert_past(key, &slice[hint - offset]) {
                 previous_offset = offset;
-                offset = (offset << 1) + 1;
+                offset = Self::grow_offset(offset, max_offset);
             }
             // Invariants:
             // - insert before or at hint - previous_offset
@@ -228,7 +445,8 @@ impl<const MIN_GALLOP: usize> Galloping<MIN_GALLOP> {
             previous_offset = hint + 1 - offset; // + 1 since we insert after hint - offset
             offset = hint - tmp; // No + 1 since we know we don't insert after hint - previous_offset
         }
-        assert!(previous_offset <= offset && offset <= slice.len());
+        // Only an invariant, the slicing below is bounds checked anyway
+        debug_assert!(previous_offset <= offset && offset <= slice.len());
 
         // Perform binary search in the found interval
         let result = slice[previous_offset..offset].partition_point(|x| should_insert_past(key, x))
@@ -236,23 +454,46 @@ impl<const MIN_GALLOP: usize> Galloping<MIN_GALLOP> {
 
         debug_assert_eq!(
             result,
-            slice.partition_point(|x| should_insert_past(key, x)),
+            crate::data::uncounted(|| slice.partition_point(|x| should_insert_past(key, x))),
         );
 
         result
     }
 
-    /// Sort the given `slice` assuming `slice[..run_length]` and `slice[run_length..]` are
-    /// already sorted and `run_length < slice.len() - run_length` and `slice[0] > slice[run_length]`.
-    fn merge_low<T: Ord>(
+    /// Returns the next galloping offset `2 * offset + 1`, clamped to `max_offset`.
+    ///
+    /// The clamping happens before the calculation could overflow, so galloping terminates even
+    /// for runs close to `usize::MAX` elements.
+    fn grow_offset(offset: usize, max_offset: usize) -> usize {
+        offset
+            .checked_mul(2)
+            .and_then(|offset| offset.checked_add(1))
+            .map_or(max_offset, |offset| std::cmp::min(offset, max_offset))
+    }
+
+    /// Sort the given `slice` according to `compare`, assuming `slice[..run_length]` and
+    /// `slice[run_length..]` are already sorted and `slice[0] > slice[run_length]` and
+    /// `slice[run_length - 1] > slice[slice.len() - 1]`.
+    ///
+    /// Merges from the low end by copying the left run into `buffer`, or from the high end by
+    /// copying the right run into `buffer` if `FROM_HIGH` is set. The other run stays in place and
+    /// overlaps the output, so it has to be copied with the overlapping methods in bulk.
+    fn merge_from<T, F: super::Comparator<T>, const FROM_HIGH: bool>(
         slice: &mut [T],
         run_length: usize,
         buffer: &mut [std::mem::MaybeUninit<T>],
         min_gallop: &mut usize,
+        compare: &mut F,
     ) {
+        let buffered_length = if FROM_HIGH {
+            slice.len() - run_length
+        } else {
+            run_length
+        };
+
         assert!(
-            buffer.len() >= run_length,
-            "We need at least run_length buffer size"
+            buffer.len() >= buffered_length,
+            "We need at least the buffered run length buffer size"
         );
         assert!(
             (1..slice.len()).contains(&run_length),
@@ -270,33 +511,37 @@ impl<const MIN_GALLOP: usize> Galloping<MIN_GALLOP> {
                 .increase(slice.len() as u64);
             crate::GLOBAL_COUNTERS
                 .merge_buffer
-                .increase(run_length as u64);
+                .increase(buffered_length as u64);
         }
 
         // Set buffer size
-        let buffer = &mut buffer[..run_length];
+        let buffer = &mut buffer[..buffered_length];
+        super::record_buffer_use(buffer.len());
 
         // SAFETY: all runs are valid by construction and we keep invariants about neither run
         // being empty before copying from them.
         unsafe {
-            // Copy `slice[..run_length]` into temporary buffer
+            let slice_ptrs = slice.as_mut_ptr_range();
+            let split = slice_ptrs.start.add(run_length);
+
+            // Copy the buffered run into temporary buffer
             std::ptr::copy_nonoverlapping(
-                slice.as_mut_ptr(),
+                if FROM_HIGH { split } else { slice_ptrs.start },
                 buffer.as_mut_ptr().cast(),
-                run_length,
+                buffered_length,
             );
 
-            // Construct runs
-            let slice_ptrs = slice.as_mut_ptr_range();
-            let runs = [
-                // Left run in buffer (we just initialized it)
-                super::Run(buffer.as_mut_ptr_range()).assume_init(),
-                // Right run at the end of slice
-                super::Run(slice_ptrs.start.add(run_length)..slice_ptrs.end),
-            ];
+            // Construct runs, the buffered run is in buffer (we just initialized it)
+            let buffered = super::Run(buffer.as_mut_ptr_range()).assume_init();
+            let runs = if FROM_HIGH {
+                [super::Run(slice_ptrs.start..split), buffered]
+            } else {
+                [buffered, super::Run(split..slice_ptrs.end)]
+            };
 
             // The output run
-            // NOTE: Since `output` and `right` overlap, make sure to use the right copying method
+            // NOTE: Since `output` and the run in place overlap, make sure to use the right
+            // copying method
             let output = super::Run(slice_ptrs);
 
             // This guard makes sure all elements get written back into `output` on panic
@@ -306,125 +551,139 @@ impl<const MIN_GALLOP: usize> Galloping<MIN_GALLOP> {
             let &mut [ref mut left, ref mut right] = &mut guard.runs;
             let output = &mut guard.output;
 
-            // Use a closure to allow early break out of block
-            (move || {
-                // Copy the first element from `right` into `output` since it's the smallest
-                right.copy_nonoverlapping_prefix_to(output, 1);
-
-                // Right side only had one element, only need to copy the left side
-                if right.is_empty() {
-                    left.copy_nonoverlapping_prefix_to(output, left.len());
-
-                    return;
-                }
-
-                // Left side only has one element, copy the rest of the right side and then the one
-                // element from the left side
-                if left.len() == 1 {
-                    right.copy_prefix_to(output, right.len());
-                    left.copy_nonoverlapping_prefix_to(output, 1);
-
-                    return;
+            // Merging stops once the buffered run has one element left or the run in place is empty
+            let left_buffered = !FROM_HIGH;
+            let exhausted = |run: &super::Run<T>, buffered: bool| {
+                if buffered {
+                    run.len() <= 1
+                } else {
+                    run.is_empty()
                 }
+            };
 
-                // Continuously copy elements until `left.len() == 1` or `right.is_empty()`
-                'outer: loop {
-                    let mut count1 = 0;
-                    let mut count2 = 0;
-
-                    // Merge one by one until threshold for bulk merging is reached
-                    while (count1 | count2) < *min_gallop {
-                        assert!(left.len() > 1);
-                        assert!(!right.is_empty());
-
-                        if *right.start() < *left.start() {
-                            // Advance the right side
-                            right.copy_nonoverlapping_prefix_to(output, 1);
-                            count2 += 1;
-                            count1 = 0;
-
-                            if right.is_empty() {
-                                break 'outer;
-                            }
-                        } else {
-                            // Advance the left side
-                            left.copy_nonoverlapping_prefix_to(output, 1);
-                            count1 += 1;
-                            count2 = 0;
-
-                            if left.len() == 1 {
-                                break 'outer;
-                            }
+            // Copy the first element from the run in place into `output`, since it's the smallest
+            // (or largest when merging from the high end)
+            let (_, in_place) = Self::roles::<T, FROM_HIGH>(left, right);
+            Self::take::<T, FROM_HIGH>(in_place, output, 1, false);
+
+            // Continuously copy elements until either run is exhausted
+            'outer: while !exhausted(left, left_buffered) && !exhausted(right, !left_buffered) {
+                let mut count1 = 0;
+                let mut count2 = 0;
+
+                // Merge one by one until threshold for bulk merging is reached
+                while (count1 | count2) < *min_gallop {
+                    // Every copy below is followed by a check breaking out of `'outer`
+                    debug_assert!(!exhausted(left, left_buffered));
+                    debug_assert!(!exhausted(right, !left_buffered));
+
+                    // Equal elements are taken from the left run first (last when merging from
+                    // the high end)
+                    if compare(
+                        &*Self::next::<T, FROM_HIGH>(right),
+                        &*Self::next::<T, FROM_HIGH>(left),
+                    )
+                    .is_lt()
+                        != FROM_HIGH
+                    {
+                        // Advance the right side
+                        Self::take::<T, FROM_HIGH>(right, output, 1, false);
+                        count2 += 1;
+                        #[cfg(feature = "counters")]
+                        record_gallop_stats(|stats| stats.single += 1);
+                        count1 = 0;
+
+                        if exhausted(right, !left_buffered) {
+                            break 'outer;
+                        }
+                    } else {
+                        // Advance the left side
+                        Self::take::<T, FROM_HIGH>(left, output, 1, false);
+                        count1 += 1;
+                        #[cfg(feature = "counters")]
+                        record_gallop_stats(|stats| stats.single += 1);
+                        count2 = 0;
+
+                        if exhausted(left, left_buffered) {
+                            break 'outer;
                         }
                     }
+                }
 
-                    // Gallop and merge multiple until it's no longer worth it
-                    loop {
-                        assert!(left.len() > 1);
-                        assert!(!right.is_empty());
-
-                        // Gallop right to find how many left elements are smaller than right
-                        count1 = Self::gallop::<T, false>(&*right.start(), left.as_slice(), 0);
-                        if count1 != 0 {
-                            // Copy the elements
-                            left.copy_nonoverlapping_prefix_to(output, count1);
-
-                            if left.len() <= 1 {
-                                break 'outer;
-                            }
-                        }
+                // Gallop and merge multiple until it's no longer worth it
+                loop {
+                    debug_assert!(!exhausted(left, left_buffered));
+                    debug_assert!(!exhausted(right, !left_buffered));
 
-                        // Right element must be lowest at this point and we know right is not empty
-                        right.copy_nonoverlapping_prefix_to(output, 1);
+                    // Gallop to find how many left elements come before the next right element
+                    let key = Self::next::<T, FROM_HIGH>(right);
+                    count1 = Self::gallop_count::<T, F, FROM_HIGH, false>(&*key, left, compare);
+                    if count1 != 0 {
+                        // Copy the elements
+                        Self::take::<T, FROM_HIGH>(left, output, count1, !left_buffered);
 
-                        if right.is_empty() {
+                        if exhausted(left, left_buffered) {
                             break 'outer;
                         }
+                    }
 
-                        // Gallop left to find how many right elements are smaller than right
-                        count2 = Self::gallop::<T, true>(&*left.start(), right.as_slice(), 0);
-                        if count2 != 0 {
-                            // Copy the elements
-                            right.copy_prefix_to(output, count2);
+                    // The right element comes next at this point and we know right is not empty
+                    Self::take::<T, FROM_HIGH>(right, output, 1, false);
 
-                            if right.is_empty() {
-                                break 'outer;
-                            }
-                        }
+                    if exhausted(right, !left_buffered) {
+                        break 'outer;
+                    }
 
-                        // Left element must be lowest at this point and we know left is not empty
-                        left.copy_nonoverlapping_prefix_to(output, 1);
+                    // Gallop to find how many right elements come before the next left element
+                    let key = Self::next::<T, FROM_HIGH>(left);
+                    count2 = Self::gallop_count::<T, F, FROM_HIGH, true>(&*key, right, compare);
+                    if count2 != 0 {
+                        // Copy the elements
+                        Self::take::<T, FROM_HIGH>(right, output, count2, left_buffered);
 
-                        if left.len() == 1 {
+                        if exhausted(right, !left_buffered) {
                             break 'outer;
                         }
+                    }
 
-                        // Lower threshold for starting bulk merging
-                        *min_gallop = min_gallop.saturating_sub(1);
+                    // The left element comes next at this point and we know left is not empty
+                    Self::take::<T, FROM_HIGH>(left, output, 1, false);
 
-                        if count1 < MIN_GALLOP && count2 < MIN_GALLOP {
-                            break;
-                        }
+                    if exhausted(left, left_buffered) {
+                        break 'outer;
                     }
 
-                    // Increase threshold for starting bulk merging
-                    *min_gallop += 2;
-                }
+                    // Lower threshold for starting bulk merging
+                    *min_gallop = min_gallop.saturating_sub(1);
+                    #[cfg(feature = "counters")]
+                    record_gallop_stats(|stats| stats.min_gallop_decrements += 1);
 
-                // Loop end is reach so either `left.len() == 1` or `right.is_empty()`
-                if left.len() == 1 {
-                    assert!(!right.is_empty());
-                    // Copy the remaining elements from right
-                    right.copy_prefix_to(output, right.len());
-                    // Copy the last element from left
-                    left.copy_nonoverlapping_prefix_to(output, 1);
-                } else {
-                    assert!(!left.is_empty());
-                    assert!(right.is_empty());
-                    // Right is empty so just copy over left
-                    left.copy_nonoverlapping_prefix_to(output, left.len());
+                    if count1 < MIN_GALLOP && count2 < MIN_GALLOP {
+                        break;
+                    }
                 }
-            })();
+
+                // Increase threshold for starting bulk merging
+                *min_gallop += 2;
+            }
+
+            // Loop end is reached so either `in_place.is_empty()` or `buffered.len() == 1`
+            // The checks below guard the unsafe copies, since galloping with an inconsistent
+            // ordering may take the last buffered element early
+            let (buffered, in_place) = Self::roles::<T, FROM_HIGH>(left, right);
+            if in_place.is_empty() {
+                assert!(!buffered.is_empty());
+                // The run in place is empty so just copy over the buffered run
+                let buffered_length = buffered.len();
+                Self::take::<T, FROM_HIGH>(buffered, output, buffered_length, false);
+            } else {
+                assert!(buffered.len() == 1);
+                // Copy the remaining elements from the run in place
+                let in_place_length = in_place.len();
+                Self::take::<T, FROM_HIGH>(in_place, output, in_place_length, true);
+                // Copy the last element from the buffered run
+                Self::take::<T, FROM_HIGH>(buffered, output, 1, false);
+            }
 
             // Guard should be empty at this point
             debug_assert!(guard.is_empty());
@@ -434,13 +693,251 @@ impl<const MIN_GALLOP: usize> Galloping<MIN_GALLOP> {
         }
     }
 
-    /// Sort the given `slice` assuming `slice[..run_length]` and `slice[run_length..]` are
-    /// already sorted and `slice.len() - run_length <= run_length` and `slice[run_length - 1] > slice[slice.len() - 1]`.
-    fn merge_high<T: Ord>(
+    /// Returns the `(buffered, in_place)` runs of [`Self::merge_from()`].
+    fn roles<'a, T, const FROM_HIGH: bool>(
+        left: &'a mut super::Run<T>,
+        right: &'a mut super::Run<T>,
+    ) -> (&'a mut super::Run<T>, &'a mut super::Run<T>) {
+        if FROM_HIGH {
+            (right, left)
+        } else {
+            (left, right)
+        }
+    }
+
+    /// Returns the next element [`Self::merge_from()`] merges from `run`, i.e. the first or the
+    /// last element if `FROM_HIGH` is set.
+    ///
+    /// # Safety
+    ///
+    /// `run` must not be empty.
+    unsafe fn next<T, const FROM_HIGH: bool>(run: &super::Run<T>) -> *mut T {
+        if FROM_HIGH {
+            // SAFETY: see method doc
+            unsafe { run.end().sub(1) }
+        } else {
+            run.start()
+        }
+    }
+
+    /// Copies the next `count` elements [`Self::merge_from()`] merges from `run` to `output`.
+    ///
+    /// # Safety
+    ///
+    /// All safety conditions of [`super::Run::copy_prefix_to()`] or
+    /// [`super::Run::copy_suffix_to()`] if `FROM_HIGH` is set must hold, or the ones of their
+    /// nonoverlapping variants if `overlapping` is unset.
+    unsafe fn take<T, const FROM_HIGH: bool>(
+        run: &mut super::Run<T>,
+        output: &mut super::Run<T>,
+        count: usize,
+        overlapping: bool,
+    ) {
+        // SAFETY: see method doc
+        unsafe {
+            match (FROM_HIGH, overlapping) {
+                (false, false) => run.copy_nonoverlapping_prefix_to(output, count),
+                (false, true) => run.copy_prefix_to(output, count),
+                (true, false) => run.copy_nonoverlapping_suffix_to(output, count),
+                (true, true) => run.copy_suffix_to(output, count),
+            }
+        }
+    }
+
+    /// Returns how many of the next elements [`Self::merge_from()`] merges from `run` come before
+    /// `key`, see [`Self::gallop()`].
+    ///
+    /// # Safety
+    ///
+    /// `run` must not be empty and all safety conditions of [`super::Run::as_slice()`] must hold.
+    unsafe fn gallop_count<
+        T,
+        F: super::Comparator<T>,
+        const FROM_HIGH: bool,
+        const BEFORE_EQUAL: bool,
+    >(
+        key: &T,
+        run: &mut super::Run<T>,
+        compare: &mut F,
+    ) -> usize {
+        // SAFETY: see method doc
+        let slice = unsafe { run.as_slice() };
+
+        if FROM_HIGH {
+            slice.len() - Self::gallop::<T, F, BEFORE_EQUAL>(key, slice, slice.len() - 1, compare)
+        } else {
+            Self::gallop::<T, F, BEFORE_EQUAL>(key, slice, 0, compare)
+        }
+    }
+}
+
+/// The default `BLOCK` of [`BlockMerge`].
+pub const DEFAULT_BLOCK: usize = 4096;
+
+/// Returns the string representation of [`BlockMerge`] with the given `block` size, also usable
+/// when the block size is only known at runtime.
+pub fn display_block_merge(block: usize) -> String {
+    format!("block-merge (BLOCK = {block})")
+}
+
+/// A [`MergingMethod`] that never uses more than `BLOCK` elements of buffer, such that the buffer
+/// stays cache resident for large inputs.
+///
+/// As long as both runs are longer than `BLOCK`, the merge is split similar to WikiSort: the
+/// middle element of the longer run is located in the other run by binary search and the two
+/// inner parts are swapped by a rotation, leaving two independent merges of shorter runs. Once
+/// either run fits into the buffer, it is moved there and merged back linearly.
+///
+/// - `BLOCK` is the maximum number of elements moved into the buffer at once, has to be positive.
+#[derive(Debug, Clone, Copy)]
+pub struct BlockMerge<const BLOCK: usize = DEFAULT_BLOCK>;
+
+impl<const BLOCK: usize> MergingMethod for BlockMerge<BLOCK> {
+    const IS_STABLE: bool = true;
+
+    fn display() -> String {
+        display_block_merge(BLOCK)
+    }
+
+    fn merge_by<T, F: super::Comparator<T>>(
         slice: &mut [T],
         run_length: usize,
         buffer: &mut [std::mem::MaybeUninit<T>],
-        min_gallop: &mut usize,
+        compare: &mut F,
+    ) {
+        const { assert!(BLOCK > 0, "BLOCK has to be positive") };
+
+        assert!(
+            run_length <= slice.len(),
+            "run_lengths needs to be less than or equal to slice.len()"
+        );
+
+        if run_length == 0 || run_length == slice.len() {
+            return;
+        }
+
+        let right_length = slice.len() - run_length;
+
+        // Merge directly if the smaller run fits into the buffer
+        if run_length.min(right_length) <= BLOCK {
+            if run_length <= right_length {
+                Self::merge_low(slice, run_length, buffer, compare);
+            } else {
+                Self::merge_high(slice, run_length, buffer, compare);
+            }
+
+            return;
+        }
+
+        // Split at the middle of the longer run. Equal elements of the right run stay behind those
+        // of the left run, since we search for the first greater (or equal) element respectively.
+        let (left_cut, right_cut) = if run_length >= right_length {
+            let left_cut = run_length / 2;
+            let right_cut = run_length
+                + slice[run_length..]
+                    .partition_point(|element| compare(element, &slice[left_cut]).is_lt());
+            (left_cut, right_cut)
+        } else {
+            let right_cut = run_length + right_length / 2;
+            let left_cut = slice[..run_length]
+                .partition_point(|element| compare(element, &slice[right_cut]).is_le());
+            (left_cut, right_cut)
+        };
+
+        // Swap `slice[left_cut..run_length]` and `slice[run_length..right_cut]`
+        slice[left_cut..right_cut].rotate_left(run_length - left_cut);
+        let middle = left_cut + (right_cut - run_length);
+
+        // Both halves contain at least one element of the longer run, so the recursion terminates
+        Self::merge_by(&mut slice[..middle], left_cut, buffer, compare);
+        Self::merge_by(&mut slice[middle..], right_cut - middle, buffer, compare);
+    }
+
+    fn required_capacity(size: usize) -> usize {
+        std::cmp::min(size / 2, BLOCK)
+    }
+}
+
+impl<const BLOCK: usize> BlockMerge<BLOCK> {
+    /// Sort the given `slice` according to `compare`, assuming `slice[..run_length]` and
+    /// `slice[run_length..]` are already sorted and `run_length` is at most `buffer.len()`, by
+    /// moving the left run into `buffer` and merging forwards.
+    pub(crate) fn merge_low<T, F: super::Comparator<T>>(
+        slice: &mut [T],
+        run_length: usize,
+        buffer: &mut [std::mem::MaybeUninit<T>],
+        compare: &mut F,
+    ) {
+        assert!(
+            buffer.len() >= run_length,
+            "We need at least run_length buffer size"
+        );
+        assert!(
+            (1..slice.len()).contains(&run_length),
+            "Split point has to be within slice bounds"
+        );
+
+        #[cfg(feature = "counters")]
+        #[expect(
+            clippy::as_conversions,
+            reason = "slice.len() will realistically stay way below u64::MAX, so this is lossless"
+        )]
+        {
+            crate::GLOBAL_COUNTERS
+                .merge_slice
+                .increase(slice.len() as u64);
+            crate::GLOBAL_COUNTERS
+                .merge_buffer
+                .increase(run_length as u64);
+        }
+
+        // Set buffer size
+        let buffer = &mut buffer[..run_length];
+        super::record_buffer_use(buffer.len());
+
+        // SAFETY: all runs are valid by construction and we only copy from non empty runs. The
+        // output never overtakes the right run, since the left run still has to be written in
+        // between, so copying single elements from the right run does not overlap.
+        unsafe {
+            // Copy `slice[..run_length]` into temporary buffer
+            std::ptr::copy_nonoverlapping(
+                slice.as_mut_ptr(),
+                buffer.as_mut_ptr().cast(),
+                run_length,
+            );
+
+            let slice_ptrs = slice.as_mut_ptr_range();
+            let runs = [
+                // Left run in buffer (we just initialized it)
+                super::Run(buffer.as_mut_ptr_range()).assume_init(),
+                // Right run at the end of slice
+                super::Run(slice_ptrs.start.add(run_length)..slice_ptrs.end),
+            ];
+            let output = super::Run(slice_ptrs);
+
+            // This guard writes the remaining elements back into `output`, also on panic
+            let mut guard = super::MergingDropGuard::new(runs, output);
+            let &mut [ref mut left, ref mut right] = &mut guard.runs;
+            let output = &mut guard.output;
+
+            while !left.is_empty() && !right.is_empty() {
+                if compare(&*right.start(), &*left.start()).is_lt() {
+                    right.copy_nonoverlapping_prefix_to(output, 1);
+                } else {
+                    left.copy_nonoverlapping_prefix_to(output, 1);
+                }
+            }
+        }
+    }
+
+    /// Sort the given `slice` according to `compare`, assuming `slice[..run_length]` and
+    /// `slice[run_length..]` are already sorted and `slice.len() - run_length` is at most
+    /// `buffer.len()`, by moving the right run into `buffer` and merging backwards.
+    pub(crate) fn merge_high<T, F: super::Comparator<T>>(
+        slice: &mut [T],
+        run_length: usize,
+        buffer: &mut [std::mem::MaybeUninit<T>],
+        compare: &mut F,
     ) {
         assert!(
             buffer.len() >= slice.len() - run_length,
@@ -467,18 +964,19 @@ impl<const MIN_GALLOP: usize> Galloping<MIN_GALLOP> {
 
         // Set buffer size
         let buffer = &mut buffer[..slice.len() - run_length];
+        super::record_buffer_use(buffer.len());
 
-        // SAFETY: all runs are valid by construction and we keep invariants about neither run
-        // being empty before copying from them.
+        // SAFETY: all runs are valid by construction and we only copy from non empty runs. The
+        // output never overtakes the left run from behind, since the right run still has to be
+        // written in between, so copying single elements from the left run does not overlap.
         unsafe {
-            // Copy suffix into temporary buffer
+            // Copy `slice[run_length..]` into temporary buffer
             std::ptr::copy_nonoverlapping(
                 slice.as_mut_ptr().add(run_length),
                 buffer.as_mut_ptr().cast(),
                 slice.len() - run_length,
             );
 
-            // Construct runs
             let slice_ptrs = slice.as_mut_ptr_range();
             let runs = [
                 // Left run at the start of the slice
@@ -486,152 +984,149 @@ impl<const MIN_GALLOP: usize> Galloping<MIN_GALLOP> {
                 // Right run in buffer (we just initialized it)
                 super::Run(buffer.as_mut_ptr_range()).assume_init(),
             ];
-            // Output run
-            // NOTE: This run overlaps with right run so be careful when copying elements
             let output = super::Run(slice_ptrs);
 
-            // This guard makes sure all elements get written back into `output` on panic
+            // This guard writes the remaining elements back into `output`, also on panic
             let mut guard = super::MergingDropGuard::new(runs, output);
-
-            // References for easier access, guard still owns the runs
             let &mut [ref mut left, ref mut right] = &mut guard.runs;
             let output = &mut guard.output;
 
             // NOTE: We are merging into slice backwards
-            // Use a closure to allow early break out of block
-            (|| {
-                // Copy the first element from `left` into `output` since it's the smallest
-                left.copy_nonoverlapping_suffix_to(output, 1);
-
-                // Left side only had one element, only need to copy the right side
-                if left.is_empty() {
-                    right.copy_nonoverlapping_suffix_to(output, right.len());
-
-                    return;
-                }
-
-                // right side only has one element, copy the rest of the left side and then the one
-                // element from the right side
-                if right.len() == 1 {
-                    left.copy_suffix_to(output, left.len());
+            while !left.is_empty() && !right.is_empty() {
+                if compare(&*right.end().sub(1), &*left.end().sub(1)).is_lt() {
+                    left.copy_nonoverlapping_suffix_to(output, 1);
+                } else {
                     right.copy_nonoverlapping_suffix_to(output, 1);
-
-                    return;
                 }
+            }
+        }
+    }
+}
 
-                // Loop until `right.len() == 1` or `left.is_empty()`
-                'outer: loop {
-                    let mut count1 = 0;
-                    let mut count2 = 0;
-
-                    // Merge one by one until threshold for bulk merging is reached
-                    while (count1 | count2) < *min_gallop {
-                        assert!(right.len() > 1);
-                        assert!(!left.is_empty());
-
-                        if *right.end().sub(1) < *left.end().sub(1) {
-                            // Advance the left side
-                            left.copy_nonoverlapping_suffix_to(output, 1);
-                            count1 += 1;
-                            count2 = 0;
-
-                            if left.is_empty() {
-                                break 'outer;
-                            }
-                        } else {
-                            // Advance the right side
-                            right.copy_nonoverlapping_suffix_to(output, 1);
-                            count1 = 0;
-                            count2 += 1;
-
-                            if right.len() == 1 {
-                                break 'outer;
-                            }
-                        }
-                    }
+/// A [`MergingMethod`] that copies all elements into `buffer` and merges back from both ends at
+/// once, the smallest remaining element to the front and the largest to the back.
+///
+/// Both ends are merged for `slice.len() / 2` steps, so the two cursors meet exactly in the
+/// middle. If afterwards not every element was taken exactly once (e.g. due to an inconsistent
+/// ordering), `slice` is restored from `buffer` and the merge panics.
+#[derive(Debug, Clone, Copy)]
+pub struct BidirectionalMerge;
 
-                    // Gallop and merge multiple until it's no longer worth it
-                    loop {
-                        assert!(right.len() > 1);
-                        assert!(!left.is_empty());
-
-                        // Gallop right to find how many left elements are larger than right
-                        let left_len = left.len();
-                        count1 = left.len()
-                            - Self::gallop::<T, false>(
-                                &*right.end().sub(1),
-                                left.as_slice(),
-                                left_len - 1,
-                            );
-                        if count1 != 0 {
-                            // Copy the elements
-                            left.copy_suffix_to(output, count1);
-
-                            if left.is_empty() {
-                                break 'outer;
-                            }
-                        }
+impl MergingMethod for BidirectionalMerge {
+    const IS_STABLE: bool = true;
 
-                        // Right now has the largest element and we know it's not empty
-                        right.copy_nonoverlapping_suffix_to(output, 1);
+    fn display() -> String {
+        "bidirectional".to_string()
+    }
 
-                        if right.len() == 1 {
-                            break 'outer;
-                        }
+    fn merge_by<T, F: super::Comparator<T>>(
+        slice: &mut [T],
+        run_length: usize,
+        buffer: &mut [std::mem::MaybeUninit<T>],
+        compare: &mut F,
+    ) {
+        if slice.len() < 2 || run_length == 0 || run_length == slice.len() {
+            return;
+        }
 
-                        // Gallop left to find how many right elements are larger than left
-                        let right_len = right.len();
-                        count2 = right.len()
-                            - Self::gallop::<T, true>(
-                                &*left.end().sub(1),
-                                right.as_slice(),
-                                right_len - 1,
-                            );
-                        if count2 != 0 {
-                            // Copy the elements
-                            right.copy_nonoverlapping_suffix_to(output, count2);
-
-                            if right.len() <= 1 {
-                                break 'outer;
-                            }
-                        }
+        #[cfg(feature = "counters")]
+        #[expect(
+            clippy::as_conversions,
+            reason = "slice.len() will realistically stay way below u64::MAX, so this is lossless"
+        )]
+        {
+            crate::GLOBAL_COUNTERS
+                .merge_slice
+                .increase(slice.len() as u64);
+            crate::GLOBAL_COUNTERS
+                .merge_buffer
+                .increase(slice.len() as u64);
+        }
 
-                        // Left now has the largest element and we know it's not empty
-                        left.copy_nonoverlapping_suffix_to(output, 1);
+        assert!(
+            buffer.len() >= slice.len(),
+            "Buffer needs to have at least the size of slice"
+        );
+        assert!(
+            run_length <= slice.len(),
+            "run_lengths needs to be less than or equal to slice.len()"
+        );
 
-                        if left.is_empty() {
-                            break 'outer;
-                        }
+        let len = slice.len();
+        let buffer = &mut buffer[..len];
+        super::record_buffer_use(buffer.len());
 
-                        // Lower threshold for starting bulk merging
-                        *min_gallop = min_gallop.saturating_sub(1);
+        // SAFETY: The merge only reads from `buffer`, which keeps every element exactly once and
+        // is copied back over `slice` by the guard in case of a panic. The cursors are bounded by
+        // their runs, so all reads and writes stay within `buffer` and `slice` respectively.
+        unsafe {
+            // Copy entire slice into buffer
+            std::ptr::copy_nonoverlapping(slice.as_ptr(), buffer.as_mut_ptr().cast(), len);
 
-                        if count1 < MIN_GALLOP && count2 < MIN_GALLOP {
-                            break;
-                        }
-                    }
+            // Derive `input` from the same pointers as the guard, so creating them does not
+            // invalidate it
+            let buffer_ptrs = buffer.as_mut_ptr_range();
+            let input = buffer_ptrs.start.cast::<T>().cast_const();
+            let slice_ptrs = slice.as_mut_ptr_range();
+            let output = slice_ptrs.start;
 
-                    // Increase threshold for starting bulk merging
-                    *min_gallop += 2;
-                }
+            // This guard restores `slice` from `buffer` on panic
+            let guard = super::MergingDropGuard::new(
+                [super::Run(buffer_ptrs).assume_init()],
+                super::Run(slice_ptrs),
+            );
 
-                // Loop end is reach so either `right.len() == 1` or `left.is_empty()`
-                if right.len() == 1 {
-                    assert!(!left.is_empty());
-                    // Copy the remaining elements from left
-                    left.copy_suffix_to(output, left.len());
-                    // Copy the last element from right
-                    right.copy_nonoverlapping_suffix_to(output, 1);
+            // The next indices to take from the front and the end indices to take before from the
+            // back of each run
+            let (mut left_front, mut right_front) = (0, run_length);
+            let (mut left_back, mut right_back) = (run_length, len);
+
+            for index in 0..len / 2 {
+                // Merge the smallest element to the front, equal elements are taken from the left
+                let take_left = right_front == len
+                    || (left_front < run_length
+                        && compare(&*input.add(right_front), &*input.add(left_front)).is_ge());
+                let source = if take_left {
+                    left_front += 1;
+                    left_front - 1
                 } else {
-                    assert!(!right.is_empty());
-                    assert!(left.is_empty());
-                    // Left is empty so just copy over right
-                    right.copy_nonoverlapping_suffix_to(output, right.len());
-                }
-            })();
+                    right_front += 1;
+                    right_front - 1
+                };
+                std::ptr::copy_nonoverlapping(input.add(source), output.add(index), 1);
+
+                // Merge the largest element to the back, equal elements are taken from the right
+                let take_right = left_back == 0
+                    || (right_back > run_length
+                        && compare(&*input.add(right_back - 1), &*input.add(left_back - 1))
+                            .is_ge());
+                let source = if take_right {
+                    right_back -= 1;
+                    right_back
+                } else {
+                    left_back -= 1;
+                    left_back
+                };
+                std::ptr::copy_nonoverlapping(input.add(source), output.add(len - 1 - index), 1);
+            }
 
-            // Guard should be empty at this point
-            debug_assert!(guard.is_empty());
+            // The middle element is left over for odd lengths
+            if len % 2 == 1 {
+                let source = if left_front < left_back {
+                    left_front += 1;
+                    left_front - 1
+                } else {
+                    right_front += 1;
+                    right_front - 1
+                };
+                std::ptr::copy_nonoverlapping(input.add(source), output.add(len / 2), 1);
+            }
+
+            // The cursors only meet if every element was taken exactly once
+            assert!(
+                left_front == left_back && right_front == right_back,
+                "The runs were not sorted according to a consistent ordering"
+            );
 
             // We are done merging so disarm the guard
             guard.disarm();
@@ -639,6 +1134,121 @@ impl<const MIN_GALLOP: usize> Galloping<MIN_GALLOP> {
     }
 }
 
+/// The default `RATIO` of [`AdaptiveMerge`].
+pub const DEFAULT_ADAPTIVE_RATIO: usize = 8;
+
+/// A [`MergingMethod`] choosing between [`Galloping`] and [`CopyBoth`] for each merge, depending
+/// on the lengths of the two runs.
+///
+/// - `RATIO` is the minimum ratio of the longer to the shorter run, from which on the runs are
+///   merged with [`Galloping`]. More balanced runs are merged with [`CopyBoth`].
+#[derive(Debug, Clone, Copy)]
+pub struct AdaptiveMerge<const RATIO: usize = DEFAULT_ADAPTIVE_RATIO>;
+
+/// The number of merges [`AdaptiveMerge`] delegated to each method on the current thread, see
+/// [`adaptive_choices()`].
+///
+/// The choices are only recorded if the `counters` feature is active or during tests.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AdaptiveChoices {
+    /// The number of merges delegated to [`Galloping`]
+    pub galloping: u64,
+    /// The number of merges delegated to [`CopyBoth`]
+    pub copy_both: u64,
+}
+
+thread_local! {
+    /// The choices made by [`AdaptiveMerge`] merges on this thread
+    static ADAPTIVE_CHOICES: std::cell::Cell<AdaptiveChoices> = const {
+        std::cell::Cell::new(AdaptiveChoices {
+            galloping: 0,
+            copy_both: 0,
+        })
+    };
+}
+
+/// Returns the choices made by [`AdaptiveMerge`] merges on this thread since the last
+/// [`reset_adaptive_choices()`].
+pub fn adaptive_choices() -> AdaptiveChoices {
+    ADAPTIVE_CHOICES.get()
+}
+
+/// Resets the choices recorded by [`AdaptiveMerge`] merges on this thread.
+pub fn reset_adaptive_choices() {
+    ADAPTIVE_CHOICES.set(AdaptiveChoices::default());
+}
+
+impl<const RATIO: usize> AdaptiveMerge<RATIO> {
+    /// Returns whether runs of lengths `run_length` and `len - run_length` are merged with
+    /// [`Galloping`].
+    pub fn gallops(len: usize, run_length: usize) -> bool {
+        const { assert!(RATIO >= 1, "RATIO needs to be at least 1") };
+
+        let shorter = run_length.min(len - run_length);
+        let longer = run_length.max(len - run_length);
+
+        longer >= shorter.saturating_mul(RATIO)
+    }
+}
+
+impl<const RATIO: usize> MergingMethod for AdaptiveMerge<RATIO> {
+    const IS_STABLE: bool = <Galloping>::IS_STABLE && CopyBoth::IS_STABLE;
+
+    fn display() -> String {
+        format!("adaptive (RATIO = {RATIO})")
+    }
+
+    fn merge_by<T, F: super::Comparator<T>>(
+        slice: &mut [T],
+        run_length: usize,
+        buffer: &mut [std::mem::MaybeUninit<T>],
+        compare: &mut F,
+    ) {
+        Self::merge_with_state_by(
+            slice,
+            run_length,
+            buffer,
+            &mut MergeState::default(),
+            compare,
+        );
+    }
+
+    fn merge_with_state_by<T, F: super::Comparator<T>>(
+        slice: &mut [T],
+        run_length: usize,
+        buffer: &mut [std::mem::MaybeUninit<T>],
+        state: &mut MergeState,
+        compare: &mut F,
+    ) {
+        assert!(
+            run_length <= slice.len(),
+            "run_lengths needs to be less than or equal to slice.len()"
+        );
+
+        let gallops = Self::gallops(slice.len(), run_length);
+
+        if cfg!(any(feature = "counters", test)) {
+            let mut choices = ADAPTIVE_CHOICES.get();
+            if gallops {
+                choices.galloping += 1;
+            } else {
+                choices.copy_both += 1;
+            }
+            ADAPTIVE_CHOICES.set(choices);
+        }
+
+        if gallops {
+            <Galloping>::merge_with_state_by(slice, run_length, buffer, state, compare);
+        } else {
+            CopyBoth::merge_by(slice, run_length, buffer, compare);
+        }
+    }
+
+    fn required_capacity(size: usize) -> usize {
+        <Galloping as MergingMethod>::required_capacity(size).max(CopyBoth::required_capacity(size))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -666,6 +1276,26 @@ mod tests {
             fn test_soundness_merges() {
                 crate::test::merging::test_soundness_merge::<$method, 2>();
             }
+
+            #[test]
+            fn test_reversed_merges() {
+                crate::test::merging::test_reversed_merge::<$method, 2>();
+            }
+
+            #[test]
+            fn test_arbitrary_runs_merges() {
+                crate::test::merging::test_arbitrary_runs_merge::<$method, 2>();
+            }
+
+            #[test]
+            fn test_empty_runs_merges() {
+                crate::test::merging::test_empty_runs_merge::<$method, 2>();
+            }
+
+            #[test]
+            fn test_display_stability() {
+                crate::test::merging::test_display_stability::<$method, 2>();
+            }
         };
     }
 
@@ -676,4 +1306,461 @@ mod tests {
     mod galloping {
         test_methods!(super::Galloping);
     }
+
+    mod block_merge {
+        test_methods!(super::BlockMerge);
+    }
+
+    mod block_merge_small {
+        test_methods!(super::BlockMerge<4>);
+    }
+
+    mod bidirectional_merge {
+        test_methods!(super::BidirectionalMerge);
+    }
+
+    mod adaptive_merge {
+        test_methods!(super::AdaptiveMerge);
+    }
+
+    mod adaptive_merge_always_galloping {
+        test_methods!(super::AdaptiveMerge<1>);
+    }
+
+    /// [`CopyBoth`] preferring the right run on ties, i.e. comparing with `<` instead of `<=`,
+    /// deliberately breaking stability to check the stability tests catch it.
+    ///
+    /// - `CLAIM_STABLE` is reported as [`MergingMethod::IS_STABLE`], so the tests expect a stable
+    ///   merge if it is `true`.
+    #[derive(Debug, Clone, Copy)]
+    struct UnstableCopyBoth<const CLAIM_STABLE: bool = false>;
+
+    impl<const CLAIM_STABLE: bool> MergingMethod for UnstableCopyBoth<CLAIM_STABLE> {
+        const IS_STABLE: bool = CLAIM_STABLE;
+
+        fn display() -> String {
+            "unstable-copy-both".to_string()
+        }
+
+        fn merge_by<T, F: super::super::Comparator<T>>(
+            slice: &mut [T],
+            run_length: usize,
+            buffer: &mut [std::mem::MaybeUninit<T>],
+            compare: &mut F,
+        ) {
+            // Equal elements compare as greater, so the right one is taken first
+            CopyBoth::merge_by(slice, run_length, buffer, &mut |a: &T, b: &T| {
+                compare(a, b).then(std::cmp::Ordering::Greater)
+            });
+        }
+    }
+
+    mod unstable_copy_both {
+        test_methods!(super::UnstableCopyBoth);
+    }
+
+    #[test]
+    #[should_panic(expected = "were not sorted stable by unstable-copy-both")]
+    fn test_stability_tests_catch_unstable_merge() {
+        crate::test::merging::test_correct_stable_merge::<UnstableCopyBoth<true>, 2>();
+    }
+
+    #[test]
+    fn test_unstable_merge_detected() {
+        use crate::test::IndexedOrdered;
+
+        // Two runs of the same single value
+        let mut elements: Vec<_> = IndexedOrdered::map_iter(std::iter::repeat_n(0, 4)).collect();
+        let mut buffer = Vec::with_capacity(elements.len());
+
+        CopyBoth::merge(&mut elements, 2, buffer.spare_capacity_mut());
+        assert_eq!(IndexedOrdered::is_stable_sorted(elements.iter()), Ok(true));
+        assert_eq!(IndexedOrdered::first_unstable_index(&elements), None);
+
+        <UnstableCopyBoth>::merge(&mut elements, 2, buffer.spare_capacity_mut());
+        assert_eq!(IndexedOrdered::is_stable_sorted(elements.iter()), Ok(false));
+        assert_eq!(
+            elements
+                .iter()
+                .map(IndexedOrdered::index)
+                .collect::<Vec<_>>(),
+            [2, 3, 0, 1]
+        );
+        assert_eq!(IndexedOrdered::first_unstable_index(&elements), Some(2));
+    }
+
+    #[test]
+    fn test_gallop_offset_growth() {
+        for max_offset in [
+            1,
+            2,
+            1_000,
+            usize::MAX / 2,
+            usize::MAX / 2 + 2,
+            usize::MAX - 1,
+            usize::MAX,
+        ] {
+            let mut offset = 1;
+            let mut steps = 0;
+            while offset < max_offset {
+                let next = Galloping::<7>::grow_offset(offset, max_offset);
+                assert!(offset < next && next <= max_offset);
+
+                offset = next;
+                steps += 1;
+            }
+
+            assert_eq!(offset, max_offset);
+            assert!(steps <= usize::BITS);
+        }
+    }
+
+    #[test]
+    fn test_gallop_insertion_index() {
+        let slice: Vec<usize> = (0..1_000).map(|i| i / 3).collect();
+
+        // Gallop from every hint in both directions, the intervals are clamped to the slice bounds
+        for hint in [0, 1, 2, 100, 500, 998, 999] {
+            for key in [0, 1, 50, 166, 332, 333, 1_000] {
+                assert_eq!(
+                    Galloping::<7>::gallop::<_, _, true>(&key, &slice, hint, &mut usize::cmp),
+                    slice.partition_point(|x| *x < key),
+                );
+                assert_eq!(
+                    Galloping::<7>::gallop::<_, _, false>(&key, &slice, hint, &mut usize::cmp),
+                    slice.partition_point(|x| *x <= key),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_galloping_inconsistent_order_soundness() {
+        use rand::{Rng as _, RngCore as _};
+
+        /// A randomly ordered element owning a heap allocation, so lost or duplicated elements
+        /// show up as leaks or double frees, e.g. under MIRI.
+        type Element = (crate::test::RandomOrdered, Box<usize>);
+
+        // Keep the test feasible under MIRI
+        const SIZE: usize = if cfg!(miri) { 50 } else { 1_000 };
+        const RUNS: usize = if cfg!(miri) { 20 } else { 200 };
+
+        fn check<M: MergingMethod>(rng: &mut crate::test::Rng) {
+            let mut buffer = Vec::with_capacity(SIZE);
+
+            for run in 0..RUNS {
+                let mut elements: Vec<Element> =
+                    crate::test::RandomOrdered::new_iter(rng.next_u64())
+                        .zip((0..SIZE).map(Box::new))
+                        .collect();
+                let run_length = rng.random_range(1..SIZE);
+
+                let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                    M::merge(&mut elements, run_length, buffer.spare_capacity_mut());
+                }));
+
+                let mut values: Vec<usize> =
+                    elements.into_iter().map(|(_, value)| *value).collect();
+                values.sort();
+                assert!(
+                    values.into_iter().eq(0..SIZE),
+                    "Run {run} lost or duplicated elements"
+                );
+            }
+        }
+
+        let mut rng = crate::test::test_rng();
+        check::<Galloping<1>>(&mut rng);
+        check::<Galloping<7>>(&mut rng);
+    }
+
+    #[test]
+    fn test_galloping_merge_directions() {
+        use rand::Rng as _;
+
+        const SIZE: usize = 10_000;
+
+        fn check<const MIN_GALLOP: usize>(elements: &[crate::test::IndexedOrdered<usize>]) {
+            let mut buffer = Vec::with_capacity(elements.len());
+
+            // Short left runs are merged from the low end, short right runs from the high end
+            for run_length in [
+                1,
+                2,
+                10,
+                SIZE / 10,
+                SIZE / 2,
+                SIZE - SIZE / 10,
+                SIZE - 2,
+                SIZE - 1,
+            ] {
+                let mut elements = elements.to_vec();
+                elements[..run_length].sort();
+                elements[run_length..].sort();
+
+                Galloping::<MIN_GALLOP>::merge(
+                    &mut elements,
+                    run_length,
+                    buffer.spare_capacity_mut(),
+                );
+
+                assert_eq!(
+                    crate::test::IndexedOrdered::is_stable_sorted(elements.iter()),
+                    Ok(true),
+                    "Elements were not sorted stable with run_length {run_length}"
+                );
+            }
+        }
+
+        let mut rng = crate::test::test_rng();
+
+        // Many equal elements merged one by one, as well as long clusters which are galloped over
+        for values in [
+            (0..SIZE)
+                .map(|_| rng.random_range(0..10))
+                .collect::<Vec<_>>(),
+            (0..SIZE).map(|_| rng.random_range(0..SIZE)).collect(),
+            (0..SIZE).map(|i| (i / 100) % 7).collect(),
+        ] {
+            let elements: Vec<_> =
+                crate::test::IndexedOrdered::map_iter(values.into_iter()).collect();
+
+            check::<1>(&elements);
+            check::<7>(&elements);
+        }
+    }
+
+    #[test]
+    fn test_equal_boundary_merge_comparisons() {
+        const SIZE: usize = 1_000;
+
+        let mut buffer = Vec::with_capacity(SIZE);
+
+        // All elements equal, as well as equal keys only around the boundary
+        for values in [
+            vec![7; SIZE],
+            std::iter::repeat_n(0, 10)
+                .chain(std::iter::repeat_n(5, SIZE - 20))
+                .chain(std::iter::repeat_n(9, 10))
+                .collect(),
+        ] {
+            for run_length in [1, SIZE / 3, SIZE / 2, SIZE - 1] {
+                let mut elements: Vec<_> = crate::test::IndexedOrdered::map_iter(
+                    values
+                        .iter()
+                        .map(|&value| crate::test::CountingOrdered(value)),
+                )
+                .collect();
+
+                let comparisons = crate::test::count_comparisons(|| {
+                    <Galloping>::merge(&mut elements, run_length, buffer.spare_capacity_mut());
+                });
+
+                assert_eq!(
+                    crate::test::IndexedOrdered::is_stable_sorted(elements.iter()),
+                    Ok(true),
+                    "Elements were not sorted stable with run_length {run_length}"
+                );
+                assert!(
+                    comparisons <= u64::from(SIZE.ilog2()),
+                    "Merging equal keys at {run_length} took {comparisons} comparisons"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_copy_both_single_pass() {
+        use rand::Rng as _;
+
+        const SIZE: usize = 1_000;
+
+        let mut rng = crate::test::test_rng();
+        let mut buffer = Vec::with_capacity(SIZE);
+
+        for run_length in [0, 1, SIZE / 3, SIZE / 2, SIZE] {
+            let mut slice: Vec<u32> = (0..SIZE).map(|_| rng.random_range(0..100)).collect();
+            slice[..run_length].sort();
+            slice[run_length..].sort();
+            let unmerged = slice.clone();
+
+            CopyBoth::merge(&mut slice, run_length, buffer.spare_capacity_mut());
+            assert!(slice.is_sorted());
+
+            // The buffer still holds the runs as copied, so the merge wrote directly into `slice`
+            // instead of merging into `buffer` and copying back
+            // SAFETY: `CopyBoth` initialized the first `SIZE` elements of the buffer
+            let copied: Vec<u32> = buffer.spare_capacity_mut()[..SIZE]
+                .iter()
+                .map(|element| unsafe { element.assume_init() })
+                .collect();
+            assert_eq!(
+                copied, unmerged,
+                "Unexpected buffer for run_length {run_length}"
+            );
+        }
+    }
+
+    #[cfg(feature = "counters")]
+    #[test]
+    fn test_clustered_merges_gallop() {
+        // The left run is entirely smaller than the right run
+        let mut slice: Vec<usize> = (0..1_000).collect();
+        let mut buffer = Vec::with_capacity(slice.len());
+
+        reset_gallop_stats();
+        Galloping::<7>::merge(&mut slice, 300, buffer.spare_capacity_mut());
+
+        assert!(slice.is_sorted());
+        assert_eq!(gallop_stats().galloped(), 1_000);
+        assert_eq!(gallop_stats().single, 0);
+
+        // The left tail is entirely larger than the right run
+        let mut slice: Vec<usize> = (0..100).chain(400..500).chain(100..400).collect();
+
+        reset_gallop_stats();
+        Galloping::<7>::merge(&mut slice, 200, buffer.spare_capacity_mut());
+
+        assert!(slice.is_sorted());
+        assert!(gallop_stats().single <= 7);
+        assert!(gallop_stats().galloped() >= 500 - 7);
+    }
+
+    #[test]
+    fn test_buffer_prefix() {
+        use rand::Rng as _;
+
+        const SIZE: usize = 10_000;
+        const SHORT: usize = 10;
+
+        let mut rng = crate::test::test_rng();
+        let values: Vec<u32> = (0..SIZE).map(|_| rng.random_range(0..1_000)).collect();
+        let allocated = <Galloping as MergingMethod>::required_capacity(SIZE);
+        let mut buffer = Vec::with_capacity(allocated);
+
+        // Galloping only buffers the shorter run of a heavily skewed merge
+        for run_length in [SHORT, SIZE - SHORT] {
+            let mut slice = values.clone();
+            slice[..run_length].sort();
+            slice[run_length..].sort();
+
+            super::super::reset_buffer_prefix();
+            <Galloping>::merge(&mut slice, run_length, buffer.spare_capacity_mut());
+
+            assert!(slice.is_sorted());
+            assert_eq!(super::super::buffer_prefix(), SHORT);
+            assert!(super::super::buffer_prefix() * 100 < allocated);
+        }
+
+        // Copying both runs uses the whole buffer
+        let mut slice = values.clone();
+        slice[..SHORT].sort();
+        slice[SHORT..].sort();
+
+        super::super::reset_buffer_prefix();
+        CopyBoth::merge(&mut slice, SHORT, buffer.spare_capacity_mut());
+
+        assert!(slice.is_sorted());
+        assert_eq!(super::super::buffer_prefix(), SIZE);
+    }
+
+    #[test]
+    fn test_large_block_merge() {
+        use rand::Rng as _;
+
+        const SIZE: usize = 1_000_000;
+
+        let mut rng = crate::test::test_rng();
+        let mut buffer = Vec::with_capacity(BlockMerge::<DEFAULT_BLOCK>::required_capacity(SIZE));
+
+        for run_length in [
+            0,
+            1,
+            DEFAULT_BLOCK,
+            SIZE / 3,
+            SIZE / 2,
+            SIZE - DEFAULT_BLOCK - 1,
+            SIZE,
+        ] {
+            let mut elements: Vec<_> = crate::test::IndexedOrdered::map_iter(
+                (0..SIZE).map(|_| rng.random_range(0..SIZE / 100)),
+            )
+            .collect();
+            elements[..run_length].sort();
+            elements[run_length..].sort();
+
+            BlockMerge::<DEFAULT_BLOCK>::merge(
+                &mut elements,
+                run_length,
+                buffer.spare_capacity_mut(),
+            );
+
+            assert_eq!(
+                crate::test::IndexedOrdered::is_stable_sorted(elements.iter()),
+                Ok(true),
+                "Elements were not sorted stable with run_length {run_length}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_adaptive_merge_choices() {
+        use rand::Rng as _;
+
+        const SIZE: usize = 10_000;
+
+        let mut rng = crate::test::test_rng();
+        let values: Vec<_> = (0..SIZE).map(|_| rng.random_range(0..SIZE / 10)).collect();
+        let mut buffer = Vec::with_capacity(SIZE);
+
+        for (run_length, expect_galloping) in [
+            (SIZE / 2, false),
+            (SIZE / 3, false),
+            (SIZE - SIZE / 4, false),
+            (1, true),
+            (10, true),
+            (SIZE / 9, true),
+            (SIZE - SIZE / 100, true),
+            (SIZE - 1, true),
+        ] {
+            let mut elements: Vec<_> =
+                crate::test::IndexedOrdered::map_iter(values.iter().copied()).collect();
+            elements[..run_length].sort();
+            elements[run_length..].sort();
+
+            reset_adaptive_choices();
+            <AdaptiveMerge>::merge(&mut elements, run_length, buffer.spare_capacity_mut());
+
+            let expected = if expect_galloping {
+                AdaptiveChoices {
+                    galloping: 1,
+                    copy_both: 0,
+                }
+            } else {
+                AdaptiveChoices {
+                    galloping: 0,
+                    copy_both: 1,
+                }
+            };
+            assert_eq!(
+                adaptive_choices(),
+                expected,
+                "Unexpected method chosen with run_length {run_length}"
+            );
+            assert_eq!(
+                crate::test::IndexedOrdered::is_stable_sorted(elements.iter()),
+                Ok(true),
+                "Elements were not sorted stable with run_length {run_length}"
+            );
+        }
+
+        // The ratio is inclusive and applies in both directions
+        assert!(AdaptiveMerge::<8>::gallops(9, 1));
+        assert!(AdaptiveMerge::<8>::gallops(9, 8));
+        assert!(!AdaptiveMerge::<8>::gallops(10, 2));
+        assert!(!AdaptiveMerge::<8>::gallops(10, 8));
+        assert!(AdaptiveMerge::<8>::gallops(10, 0));
+        assert!(AdaptiveMerge::<1>::gallops(10, 5));
+    }
 }
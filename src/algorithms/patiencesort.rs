@@ -0,0 +1,205 @@
+//! The Patience sort implementation.
+
+use crate::algorithms::merging::BufGuard as _;
+
+/// The default [`super::merging::MultiMergingMethod`] to use.
+pub type DefaultMultiMergingMethod = super::merging::multi_way::TournamentTree;
+
+/// The default [`super::BufGuardFactory`] to use.
+pub type DefaultBufGuardFactory = super::DefaultBufGuardFactory;
+
+/// The default `MERGE_K_RUNS` to use.
+pub const DEFAULT_MERGE_K_RUNS: usize = 4;
+
+/// The Patience sort [`super::Sort`].
+///
+/// Distributes the elements onto weakly increasing piles, appending each element to the leftmost
+/// pile it fits on, and merges the piles afterwards. Existing weakly increasing runs therefore end
+/// up on a single pile.
+///
+/// - `M` is the [`super::merging::MultiMergingMethod`] used to merge piles.
+/// - `B` is the [`super::BufGuardFactory`] used to create the buffer for merging.
+/// - `MERGE_K_RUNS` determines how many piles are merged together.
+pub struct PatienceSort<
+    M: super::merging::MultiMergingMethod<MERGE_K_RUNS> = DefaultMultiMergingMethod,
+    B: super::BufGuardFactory = DefaultBufGuardFactory,
+    const MERGE_K_RUNS: usize = DEFAULT_MERGE_K_RUNS,
+>(std::marker::PhantomData<M>, std::marker::PhantomData<B>);
+
+impl<
+    M: super::merging::MultiMergingMethod<MERGE_K_RUNS>,
+    B: super::BufGuardFactory,
+    const MERGE_K_RUNS: usize,
+> super::Sort for PatienceSort<M, B, MERGE_K_RUNS>
+{
+    // Equal elements always end up on piles in their initial relative order, see `form_piles()`
+    const IS_STABLE: bool = M::IS_STABLE;
+
+    const BASE_NAME: &str = "patiencesort";
+
+    fn parameters() -> impl Iterator<Item = (&'static str, String)> {
+        vec![("K", MERGE_K_RUNS.to_string()), ("merging", M::display())].into_iter()
+    }
+
+    fn merging() -> Option<String> {
+        Some(crate::cli::display_merging::<M, MERGE_K_RUNS>())
+    }
+
+    fn buffer_bytes<T>(len: usize) -> usize {
+        if len < 2 {
+            return 0;
+        }
+
+        M::required_capacity(len) * std::mem::size_of::<T>()
+    }
+
+    fn sort<T: Ord>(slice: &mut [T]) {
+        if slice.len() < 2 {
+            return;
+        }
+
+        let (mut piles, pile_lengths) = form_piles(slice);
+        if pile_lengths.len() == 1 {
+            return;
+        }
+
+        // Move the piles next to each other, turning `piles` into the destination of each element
+        let mut pile_starts: Vec<usize> = pile_lengths
+            .iter()
+            .scan(0, |start, len| {
+                let pile_start = *start;
+                *start += len;
+                Some(pile_start)
+            })
+            .collect();
+        for pile in piles.iter_mut() {
+            let destination = pile_starts[*pile];
+            pile_starts[*pile] += 1;
+            *pile = destination;
+        }
+        apply_permutation(slice, &mut piles);
+
+        // Conservatively initiate a buffer big enough to merge the complete array
+        let mut buffer = <B::Guard<T>>::with_capacity(M::required_capacity(slice.len()));
+
+        Self::merge_piles(slice, pile_lengths, buffer.as_uninit_slice_mut());
+    }
+}
+
+impl<
+    M: super::merging::MultiMergingMethod<MERGE_K_RUNS>,
+    B: super::BufGuardFactory,
+    const MERGE_K_RUNS: usize,
+> PatienceSort<M, B, MERGE_K_RUNS>
+{
+    /// Repeatedly merges `MERGE_K_RUNS` adjacent piles of the given lengths, until a single pile
+    /// is left.
+    fn merge_piles<T: Ord>(
+        slice: &mut [T],
+        mut pile_lengths: Vec<usize>,
+        buffer: &mut [std::mem::MaybeUninit<T>],
+    ) {
+        while pile_lengths.len() > 1 {
+            let mut start = 0;
+            let mut merged_lengths = Vec::with_capacity(pile_lengths.len().div_ceil(MERGE_K_RUNS));
+
+            for group in pile_lengths.chunks(MERGE_K_RUNS) {
+                let len: usize = group.iter().sum();
+
+                // The last length is implied by the slice
+                if group.len() > 1 {
+                    super::merging::record_merge();
+                    M::merge(
+                        &mut slice[start..start + len],
+                        &group[..group.len() - 1],
+                        buffer,
+                    );
+                }
+
+                merged_lengths.push(len);
+                start += len;
+            }
+
+            pile_lengths = merged_lengths;
+        }
+    }
+}
+
+/// Distributes the elements of `slice` onto weakly increasing piles, returning the pile of each
+/// element and the length of each pile.
+///
+/// Each element is appended to the leftmost pile whose last element is less than or equal to it,
+/// or to a new pile on the right. The last elements of the piles are therefore strictly decreasing
+/// from left to right, so the pile can be found with a binary search. Since the last element of a
+/// pile never decreases, equal elements end up on piles from left to right in their initial
+/// relative order.
+fn form_piles<T: Ord>(slice: &[T]) -> (Vec<usize>, Vec<usize>) {
+    let mut piles = Vec::with_capacity(slice.len());
+    let mut pile_lengths = Vec::new();
+    // The index of the last element of each pile
+    let mut pile_ends: Vec<usize> = Vec::new();
+
+    for (index, element) in slice.iter().enumerate() {
+        let pile = pile_ends.partition_point(|&end| slice[end] > *element);
+
+        if pile == pile_ends.len() {
+            pile_ends.push(index);
+            pile_lengths.push(1);
+        } else {
+            pile_ends[pile] = index;
+            pile_lengths[pile] += 1;
+        }
+
+        piles.push(pile);
+    }
+
+    (piles, pile_lengths)
+}
+
+/// Moves each element `slice[i]` to `slice[destinations[i]]`, cycle by cycle.
+///
+/// `destinations` has to be a permutation of `0..slice.len()` and is left as the identity.
+fn apply_permutation<T>(slice: &mut [T], destinations: &mut [usize]) {
+    for start in 0..slice.len() {
+        while destinations[start] != start {
+            let destination = destinations[start];
+            slice.swap(start, destination);
+            destinations.swap(start, destination);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generate_test_suite;
+
+    use super::*;
+
+    generate_test_suite! {
+        TEST_SIZE: crate::test::DEFAULT_TEST_SIZE;
+        TEST_RUNS: crate::test::DEFAULT_TEST_RUNS;
+
+        PatienceSort,
+        PatienceSort<super::super::merging::two_way::CopyBoth, DefaultBufGuardFactory, 2>,
+        PatienceSort<DefaultMultiMergingMethod, DefaultBufGuardFactory, 3>,
+        PatienceSort<super::super::merging::multi_way::Fourway>,
+    }
+
+    #[test]
+    fn test_sorted_single_pile() {
+        let values: Vec<usize> = (0..crate::test::DEFAULT_TEST_SIZE).map(|i| i / 4).collect();
+
+        let (piles, pile_lengths) = form_piles(&values);
+        assert!(piles.iter().all(|&pile| pile == 0));
+        assert_eq!(pile_lengths, [values.len()]);
+    }
+
+    #[test]
+    fn test_reverse_sorted_piles() {
+        let values: Vec<usize> = (0..crate::test::DEFAULT_TEST_SIZE).rev().collect();
+
+        let (piles, pile_lengths) = form_piles(&values);
+        assert!(piles.iter().copied().eq(0..values.len()));
+        assert!(pile_lengths.iter().all(|&len| len == 1));
+    }
+}
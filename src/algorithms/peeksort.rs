@@ -15,7 +15,16 @@ pub type DefaultBufGuardFactory = super::DefaultBufGuardFactory;
 pub const DEFAULT_INSERTION_THRESHOLD: usize = 24;
 
 /// The default `ONLY_INCREASING_RUNS` to use.
-pub const DEFAULT_ONLY_INCREASING_RUNS: bool = true;
+pub const DEFAULT_ONLY_INCREASING_RUNS: bool = false;
+
+/// The default `ITERATIVE` to use.
+pub const DEFAULT_ITERATIVE: bool = false;
+
+/// The default [`super::merging::MultiMergingMethod`] to use.
+pub type DefaultMultiMergingMethod = super::merging::multi_way::TournamentTree;
+
+/// The default `MERGE_K_RUNS` to use.
+pub const DEFAULT_MERGE_K_RUNS: usize = 4;
 
 /// The Peeksort [`super::Sort`].
 ///
@@ -23,13 +32,17 @@ pub const DEFAULT_ONLY_INCREASING_RUNS: bool = true;
 /// - `M` is the [`super::merging::MergingMethod`] used to merge the runs.
 /// - `B` is the [`super::BufGuardFactory`] used to create the buffer for merging.
 /// - `INSERTION_THRESHOLD` determines the maximum length for sub slices sorted with insertion sort.
-/// - `ONLY_INCREASING_RUNS` indicates whether only increasing existing runs are used.
+/// - `ONLY_INCREASING_RUNS` indicates whether only preexisting weakly increasing runs are used,
+///   i.e. strictly decreasing runs are never detected and reversed.
+/// - `ITERATIVE` indicates whether to keep the pending sub slices on an explicit stack instead of
+///   recursing, see [`PeekStep`]. Both produce the same result.
 pub struct PeekSort<
     I: super::Sort = DefaultInsertionSort,
     M: super::merging::MergingMethod = DefaultMergingMethod,
     B: super::BufGuardFactory = DefaultBufGuardFactory,
     const INSERTION_THRESHOLD: usize = DEFAULT_INSERTION_THRESHOLD,
     const ONLY_INCREASING_RUNS: bool = DEFAULT_ONLY_INCREASING_RUNS,
+    const ITERATIVE: bool = DEFAULT_ITERATIVE,
 >(
     std::marker::PhantomData<I>,
     std::marker::PhantomData<M>,
@@ -42,7 +55,8 @@ impl<
     B: super::BufGuardFactory,
     const INSERTION_THRESHOLD: usize,
     const ONLY_INCREASING_RUNS: bool,
-> super::Sort for PeekSort<I, M, B, INSERTION_THRESHOLD, ONLY_INCREASING_RUNS>
+    const ITERATIVE: bool,
+> super::Sort for PeekSort<I, M, B, INSERTION_THRESHOLD, ONLY_INCREASING_RUNS, ITERATIVE>
 {
     const IS_STABLE: bool = I::IS_STABLE && M::IS_STABLE;
 
@@ -54,10 +68,23 @@ impl<
             ("merging", M::display()),
             ("i-threshold", INSERTION_THRESHOLD.to_string()),
             ("only-increasing", ONLY_INCREASING_RUNS.to_string()),
+            ("iterative", ITERATIVE.to_string()),
         ]
         .into_iter()
     }
 
+    fn merging() -> Option<String> {
+        Some(crate::cli::display_merging::<M, 2>())
+    }
+
+    fn buffer_bytes<T>(len: usize) -> usize {
+        if len < 2 {
+            return 0;
+        }
+
+        M::required_capacity(len) * std::mem::size_of::<T>()
+    }
+
     fn sort<T: Ord>(slice: &mut [T]) {
         <Self as super::PostfixSort>::sort_with_sorted_prefix(slice, 1);
     }
@@ -69,7 +96,8 @@ impl<
     B: super::BufGuardFactory,
     const INSERTION_THRESHOLD: usize,
     const ONLY_INCREASING_RUNS: bool,
-> super::PostfixSort for PeekSort<I, M, B, INSERTION_THRESHOLD, ONLY_INCREASING_RUNS>
+    const ITERATIVE: bool,
+> super::PostfixSort for PeekSort<I, M, B, INSERTION_THRESHOLD, ONLY_INCREASING_RUNS, ITERATIVE>
 {
     fn sort_with_sorted_prefix<T: Ord>(slice: &mut [T], split_point: usize) {
         if slice.len() < 2 {
@@ -80,12 +108,21 @@ impl<
         let mut buffer = <B::Guard<T>>::with_capacity(M::required_capacity(slice.len()));
 
         // Delegate to helper function
-        Self::peeksort(
-            slice,
-            split_point,
-            slice.len() - 1,
-            buffer.as_uninit_slice_mut(),
-        );
+        if ITERATIVE {
+            Self::peeksort_iterative(
+                slice,
+                split_point,
+                slice.len() - 1,
+                buffer.as_uninit_slice_mut(),
+            );
+        } else {
+            Self::peeksort(
+                slice,
+                split_point,
+                slice.len() - 1,
+                buffer.as_uninit_slice_mut(),
+            );
+        }
     }
 }
 
@@ -95,7 +132,8 @@ impl<
     B: super::BufGuardFactory,
     const INSERTION_THRESHOLD: usize,
     const ONLY_INCREASING_RUNS: bool,
-> PeekSort<I, M, B, INSERTION_THRESHOLD, ONLY_INCREASING_RUNS>
+    const ITERATIVE: bool,
+> PeekSort<I, M, B, INSERTION_THRESHOLD, ONLY_INCREASING_RUNS, ITERATIVE>
 {
     /// The actual peek sort implementation.
     ///
@@ -135,6 +173,7 @@ impl<
                 right_run_begin - left_run_end, // Shift index since we cut right_run_begin elements
                 buffer,
             );
+            super::merging::record_merge();
             M::merge(slice, left_run_end, buffer);
         } else if middle >= right_run_begin {
             // right run extends further than middle => sort beginning and merge
@@ -144,46 +183,16 @@ impl<
                 right_run_begin - 1, // Right run is always at least one element long
                 buffer,
             );
+            super::merging::record_merge();
             M::merge(slice, right_run_begin, buffer);
         } else {
             // Find the longest run containing `middle - 1`
-            let (middle_run_start, middle_run_end);
-
-            #[allow(
-                clippy::collapsible_else_if,
-                reason = "Clearer distinction between compile time and runtime checks"
-            )]
-            if ONLY_INCREASING_RUNS {
-                middle_run_start = left_run_end
-                    + crate::algorithms::merging::util::weakly_increasing_suffix_index(
-                        &slice[left_run_end..middle],
-                    );
-                middle_run_end = middle - 1
-                    + crate::algorithms::merging::util::weakly_increasing_prefix_index(
-                        &slice[middle - 1..right_run_begin],
-                    );
-            } else {
-                if slice[middle - 1] <= slice[middle] {
-                    middle_run_start = left_run_end
-                        + crate::algorithms::merging::util::weakly_increasing_suffix_index(
-                            &slice[left_run_end..middle],
-                        );
-                    middle_run_end = middle - 1
-                        + crate::algorithms::merging::util::weakly_increasing_prefix_index(
-                            &slice[middle - 1..right_run_begin],
-                        );
-                } else {
-                    middle_run_start = left_run_end
-                        + crate::algorithms::merging::util::strictly_decreasing_suffix_index(
-                            &slice[left_run_end..middle],
-                        );
-                    middle_run_end = middle - 1
-                        + crate::algorithms::merging::util::strictly_decreasing_prefix_index(
-                            &slice[middle - 1..right_run_begin],
-                        );
-                    slice[middle_run_start..middle_run_end].reverse();
-                }
-            }
+            let (middle_run_start, middle_run_end) = find_run_around::<_, ONLY_INCREASING_RUNS>(
+                slice,
+                left_run_end,
+                middle,
+                right_run_begin,
+            );
 
             // Recurse mostly halfway, eating up the run in the middle with one half
             if middle - middle_run_start < middle_run_end - middle {
@@ -200,6 +209,7 @@ impl<
                     right_run_begin - middle_run_start, // End run stays the same
                     buffer,
                 );
+                super::merging::record_merge();
                 M::merge(slice, middle_run_start, buffer);
             } else {
                 // Middle run extends mostly into the left half
@@ -215,10 +225,398 @@ impl<
                     right_run_begin - middle_run_end, // Right run stays the same
                     buffer,
                 );
+                super::merging::record_merge();
                 M::merge(slice, middle_run_end, buffer);
             }
         }
     }
+
+    /// Like [`Self::peeksort()`], but keeps the pending sub slices and merges on an explicit
+    /// stack, performing the same steps in the same order without recursion.
+    fn peeksort_iterative<T: Ord>(
+        slice: &mut [T],
+        left_run_end: usize,
+        right_run_begin: usize,
+        buffer: &mut [std::mem::MaybeUninit<T>],
+    ) {
+        let mut steps = vec![PeekStep::Sort {
+            range: 0..slice.len(),
+            left_run_end,
+            right_run_begin,
+        }];
+
+        while let Some(step) = steps.pop() {
+            let (range, left_run_end, right_run_begin) = match step {
+                PeekStep::Sort {
+                    range,
+                    left_run_end,
+                    right_run_begin,
+                } => (range, left_run_end, right_run_begin),
+                PeekStep::Merge { range, run_length } => {
+                    super::merging::record_merge();
+                    M::merge(&mut slice[range], run_length, buffer);
+                    continue;
+                }
+            };
+
+            let start = range.start;
+            let sub_slice = &mut slice[range.clone()];
+
+            // Assert invariant in debug build
+            debug_assert!(
+                sub_slice[..left_run_end].is_sorted() && sub_slice[right_run_begin..].is_sorted()
+            );
+
+            // Assert minimum and maximum run lengths
+            assert!((1..=sub_slice.len()).contains(&left_run_end));
+            assert!((0..sub_slice.len()).contains(&right_run_begin));
+
+            // Sub slice is already sorted, nothing to do
+            if left_run_end > right_run_begin {
+                continue;
+            }
+
+            // Use insertion sort for small sub slices
+            if sub_slice.len() < INSERTION_THRESHOLD {
+                I::sort(sub_slice);
+                continue;
+            }
+
+            let middle = sub_slice.len() / 2;
+
+            // The steps are popped in reverse order, so the merge is pushed before the sub slices
+            // it merges and the right sub slice before the left one
+            if middle <= left_run_end {
+                // left run extends further than middle => sort rest and merge
+                steps.push(PeekStep::Merge {
+                    range: range.clone(),
+                    run_length: left_run_end,
+                });
+                steps.push(PeekStep::Sort {
+                    range: start + left_run_end..range.end,
+                    left_run_end: 1,
+                    right_run_begin: right_run_begin - left_run_end,
+                });
+            } else if middle >= right_run_begin {
+                // right run extends further than middle => sort beginning and merge
+                steps.push(PeekStep::Merge {
+                    range,
+                    run_length: right_run_begin,
+                });
+                steps.push(PeekStep::Sort {
+                    range: start..start + right_run_begin,
+                    left_run_end,
+                    right_run_begin: right_run_begin - 1,
+                });
+            } else {
+                // Find the longest run containing `middle - 1`
+                let (middle_run_start, middle_run_end) = find_run_around::<_, ONLY_INCREASING_RUNS>(
+                    sub_slice,
+                    left_run_end,
+                    middle,
+                    right_run_begin,
+                );
+
+                // Split mostly halfway, eating up the run in the middle with one half
+                let (split, left, right) = if middle - middle_run_start < middle_run_end - middle {
+                    // Middle run extends mostly into the right half
+                    (
+                        middle_run_start,
+                        (left_run_end, middle_run_start - 1),
+                        (
+                            middle_run_end - middle_run_start,
+                            right_run_begin - middle_run_start,
+                        ),
+                    )
+                } else {
+                    // Middle run extends mostly into the left half
+                    (
+                        middle_run_end,
+                        (left_run_end, middle_run_start),
+                        (1, right_run_begin - middle_run_end),
+                    )
+                };
+
+                steps.push(PeekStep::Merge {
+                    range: range.clone(),
+                    run_length: split,
+                });
+                steps.push(PeekStep::Sort {
+                    range: start + split..range.end,
+                    left_run_end: right.0,
+                    right_run_begin: right.1,
+                });
+                steps.push(PeekStep::Sort {
+                    range: start..start + split,
+                    left_run_end: left.0,
+                    right_run_begin: left.1,
+                });
+            }
+        }
+    }
+}
+
+/// A pending step of [`PeekSort`] with `ITERATIVE`, on the sub slice `slice[range]`.
+///
+/// Each [`PeekStep::Sort`] corresponds to a recursive call of the recursive variant, so its
+/// fields are the invariants that call would assert.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum PeekStep {
+    /// Sort the sub slice, of which `[..left_run_end]` and `[right_run_begin..]` are sorted
+    Sort {
+        range: std::ops::Range<usize>,
+        left_run_end: usize,
+        right_run_begin: usize,
+    },
+    /// Merge the sorted runs `[..run_length]` and `[run_length..]` of the sub slice
+    Merge {
+        range: std::ops::Range<usize>,
+        run_length: usize,
+    },
+}
+
+/// Finds the longest run `slice[start..end]` containing `slice[middle - 1]`, such that
+/// `lower <= start < middle <= end <= upper`.
+///
+/// If `ONLY_INCREASING_RUNS` is `false` and `slice[middle - 1] > slice[middle]`, the strictly
+/// decreasing run is found and reversed.
+fn find_run_around<T: Ord, const ONLY_INCREASING_RUNS: bool>(
+    slice: &mut [T],
+    lower: usize,
+    middle: usize,
+    upper: usize,
+) -> (usize, usize) {
+    assert!(lower < middle && middle < upper);
+
+    if ONLY_INCREASING_RUNS || slice[middle - 1] <= slice[middle] {
+        let start = lower
+            + crate::algorithms::merging::util::weakly_increasing_suffix_index(
+                &slice[lower..middle],
+            );
+        let end = middle - 1
+            + crate::algorithms::merging::util::weakly_increasing_prefix_index(
+                &slice[middle - 1..upper],
+            );
+
+        (start, end)
+    } else {
+        let start = lower
+            + crate::algorithms::merging::util::strictly_decreasing_suffix_index(
+                &slice[lower..middle],
+            );
+        let end = middle - 1
+            + crate::algorithms::merging::util::strictly_decreasing_prefix_index(
+                &slice[middle - 1..upper],
+            );
+        slice[start..end].reverse();
+
+        (start, end)
+    }
+}
+
+/// The Multiway Peeksort [`super::Sort`], splitting slices into `MERGE_K_RUNS` parts.
+///
+/// Like [`PeekSort`], each split point is moved to the nearer end of the run containing it.
+///
+/// - `I` is the insertion sort used for small slices.
+/// - `M` is the [`super::merging::MultiMergingMethod`] used to merge the parts.
+/// - `B` is the [`super::BufGuardFactory`] used to create the buffer for merging.
+/// - `MERGE_K_RUNS` determines how many parts a slice is split into.
+/// - `INSERTION_THRESHOLD` determines the maximum length for sub slices sorted with insertion sort.
+/// - `ONLY_INCREASING_RUNS` indicates whether only preexisting weakly increasing runs are used,
+///   i.e. strictly decreasing runs are never detected and reversed.
+pub struct MultiwayPeekSort<
+    I: super::Sort = DefaultInsertionSort,
+    M: super::merging::MultiMergingMethod<MERGE_K_RUNS> = DefaultMultiMergingMethod,
+    B: super::BufGuardFactory = DefaultBufGuardFactory,
+    const MERGE_K_RUNS: usize = DEFAULT_MERGE_K_RUNS,
+    const INSERTION_THRESHOLD: usize = DEFAULT_INSERTION_THRESHOLD,
+    const ONLY_INCREASING_RUNS: bool = DEFAULT_ONLY_INCREASING_RUNS,
+>(
+    std::marker::PhantomData<I>,
+    std::marker::PhantomData<M>,
+    std::marker::PhantomData<B>,
+);
+
+impl<
+    I: super::Sort,
+    M: super::merging::MultiMergingMethod<MERGE_K_RUNS>,
+    B: super::BufGuardFactory,
+    const MERGE_K_RUNS: usize,
+    const INSERTION_THRESHOLD: usize,
+    const ONLY_INCREASING_RUNS: bool,
+> super::Sort
+    for MultiwayPeekSort<I, M, B, MERGE_K_RUNS, INSERTION_THRESHOLD, ONLY_INCREASING_RUNS>
+{
+    const IS_STABLE: bool = I::IS_STABLE && M::IS_STABLE;
+
+    const BASE_NAME: &str = "multiway-peeksort";
+
+    fn parameters() -> impl Iterator<Item = (&'static str, String)> {
+        vec![
+            ("i-sort", crate::cli::display_inline::<I>()),
+            ("merging", M::display()),
+            ("k", MERGE_K_RUNS.to_string()),
+            ("i-threshold", INSERTION_THRESHOLD.to_string()),
+            ("only-increasing", ONLY_INCREASING_RUNS.to_string()),
+        ]
+        .into_iter()
+    }
+
+    fn merging() -> Option<String> {
+        Some(crate::cli::display_merging::<M, MERGE_K_RUNS>())
+    }
+
+    fn buffer_bytes<T>(len: usize) -> usize {
+        if len < 2 {
+            return 0;
+        }
+
+        M::required_capacity(len) * std::mem::size_of::<T>()
+    }
+
+    fn sort<T: Ord>(slice: &mut [T]) {
+        <Self as super::PostfixSort>::sort_with_sorted_prefix(slice, 1);
+    }
+}
+
+impl<
+    I: super::Sort,
+    M: super::merging::MultiMergingMethod<MERGE_K_RUNS>,
+    B: super::BufGuardFactory,
+    const MERGE_K_RUNS: usize,
+    const INSERTION_THRESHOLD: usize,
+    const ONLY_INCREASING_RUNS: bool,
+> super::PostfixSort
+    for MultiwayPeekSort<I, M, B, MERGE_K_RUNS, INSERTION_THRESHOLD, ONLY_INCREASING_RUNS>
+{
+    fn sort_with_sorted_prefix<T: Ord>(slice: &mut [T], split_point: usize) {
+        if slice.len() < 2 {
+            return;
+        }
+
+        // Conservatively initiate a buffer big enough to merge the complete array
+        let mut buffer = <B::Guard<T>>::with_capacity(M::required_capacity(slice.len()));
+
+        // Delegate to helper function
+        Self::multiway_peeksort(
+            slice,
+            split_point,
+            slice.len() - 1,
+            buffer.as_uninit_slice_mut(),
+        );
+    }
+}
+
+impl<
+    I: super::Sort,
+    M: super::merging::MultiMergingMethod<MERGE_K_RUNS>,
+    B: super::BufGuardFactory,
+    const MERGE_K_RUNS: usize,
+    const INSERTION_THRESHOLD: usize,
+    const ONLY_INCREASING_RUNS: bool,
+> MultiwayPeekSort<I, M, B, MERGE_K_RUNS, INSERTION_THRESHOLD, ONLY_INCREASING_RUNS>
+{
+    /// The actual multiway peek sort implementation.
+    ///
+    /// Sorts `slice` under the assumption, that `slice[..left_run_end]` and
+    /// `slice[right_run_begin..]` are already sorted.
+    fn multiway_peeksort<T: Ord>(
+        slice: &mut [T],
+        left_run_end: usize,
+        right_run_begin: usize,
+        buffer: &mut [std::mem::MaybeUninit<T>],
+    ) {
+        const { assert!(MERGE_K_RUNS >= 2, "Need to split into at least two parts") };
+
+        // Assert invariant in debug build
+        debug_assert!(slice[..left_run_end].is_sorted() && slice[right_run_begin..].is_sorted());
+
+        // Assert minimum and maximum run lengths
+        assert!((1..=slice.len()).contains(&left_run_end));
+        assert!((0..slice.len()).contains(&right_run_begin));
+
+        // Slice is already sorted, nothing to do
+        if left_run_end > right_run_begin {
+            return;
+        }
+
+        // Use insertion sort for small slices
+        if slice.len() < INSERTION_THRESHOLD {
+            I::sort(slice);
+            return;
+        }
+
+        // The parts as `(end, left_run_end, right_run_begin)`, each starting at the previous end
+        let mut parts = [(0, 0, 0); MERGE_K_RUNS];
+        let mut count = 0;
+
+        // The end of the run known to start the current part
+        let mut known_run_end = left_run_end;
+
+        for i in 1..MERGE_K_RUNS {
+            // The rest of the slice is already sorted
+            if known_run_end > right_run_begin {
+                break;
+            }
+
+            let boundary = i * slice.len() / MERGE_K_RUNS;
+
+            if boundary <= known_run_end {
+                // Known run extends further than the boundary => it becomes its own part
+                parts[count] = (known_run_end, known_run_end, known_run_end - 1);
+                known_run_end += 1;
+            } else if boundary >= right_run_begin {
+                // Right run extends further than the boundary => it becomes the last part
+                parts[count] = (right_run_begin, known_run_end, right_run_begin - 1);
+                known_run_end = slice.len();
+            } else {
+                let (run_start, run_end) = find_run_around::<_, ONLY_INCREASING_RUNS>(
+                    slice,
+                    known_run_end,
+                    boundary,
+                    right_run_begin,
+                );
+
+                if boundary - run_start < run_end - boundary {
+                    // Run extends mostly into the next part => it starts the next part
+                    parts[count] = (run_start, known_run_end, run_start - 1);
+                    known_run_end = run_end;
+                } else {
+                    // Run extends mostly into the current part => it ends the current part
+                    parts[count] = (run_end, known_run_end, run_start);
+                    known_run_end = run_end + 1;
+                }
+            }
+
+            count += 1;
+        }
+
+        // The last part ends with the right run
+        parts[count] = (slice.len(), known_run_end, right_run_begin);
+        count += 1;
+
+        // Sort the parts
+        let mut run_lengths = [0; MERGE_K_RUNS];
+        let mut start = 0;
+        for (&(end, left_run_end, right_run_begin), length) in
+            parts[..count].iter().zip(&mut run_lengths)
+        {
+            Self::multiway_peeksort(
+                &mut slice[start..end],
+                left_run_end - start,
+                right_run_begin - start,
+                buffer,
+            );
+
+            *length = end - start;
+            start = end;
+        }
+
+        // Merge the parts, the last length is implied by the slice
+        super::merging::record_merge();
+        M::merge(slice, &run_lengths[..count - 1], buffer);
+    }
 }
 
 #[cfg(test)]
@@ -227,12 +625,21 @@ mod tests {
 
     use super::*;
 
-    type PeekSortDecreasing = PeekSort<
+    type PeekSortIncreasing = PeekSort<
+        DefaultInsertionSort,
+        DefaultMergingMethod,
+        DefaultBufGuardFactory,
+        DEFAULT_INSERTION_THRESHOLD,
+        true,
+    >;
+
+    type PeekSortIterative = PeekSort<
         DefaultInsertionSort,
         DefaultMergingMethod,
         DefaultBufGuardFactory,
         DEFAULT_INSERTION_THRESHOLD,
-        false,
+        DEFAULT_ONLY_INCREASING_RUNS,
+        true,
     >;
 
     generate_test_suite! {
@@ -240,6 +647,165 @@ mod tests {
         TEST_RUNS: crate::test::DEFAULT_TEST_RUNS;
 
         PeekSort,
-        PeekSortDecreasing,
+        PeekSortIncreasing,
+        PeekSortIterative,
+        MultiwayPeekSort,
+        MultiwayPeekSort<DefaultInsertionSort, DefaultMultiMergingMethod, DefaultBufGuardFactory, 2>,
+        MultiwayPeekSort<DefaultInsertionSort, DefaultMultiMergingMethod, DefaultBufGuardFactory, 3>,
+        MultiwayPeekSort<DefaultInsertionSort, super::super::merging::multi_way::Fourway>,
+        MultiwayPeekSort<
+            DefaultInsertionSort,
+            DefaultMultiMergingMethod,
+            DefaultBufGuardFactory,
+            DEFAULT_MERGE_K_RUNS,
+            DEFAULT_INSERTION_THRESHOLD,
+            true,
+        >,
+    }
+
+    #[test]
+    fn test_multiway_soundness() {
+        crate::test::test_soundness::<TEST_RUNS, 1_000, MultiwayPeekSort>();
+    }
+
+    #[test]
+    fn test_two_way_matches_peeksort() {
+        use crate::algorithms::Sort as _;
+        use rand::seq::SliceRandom as _;
+
+        let mut rng = crate::test::test_rng();
+        let mut values: Box<[_]> = (0..crate::test::DEFAULT_TEST_SIZE)
+            .map(crate::test::CountingOrdered)
+            .collect();
+
+        for _ in 0..crate::test::DEFAULT_TEST_RUNS {
+            values.shuffle(&mut rng);
+            let mut multiway_values = values.clone();
+
+            let comparisons = crate::test::count_comparisons(|| <PeekSort>::sort(&mut values));
+            let multiway_comparisons = crate::test::count_comparisons(|| {
+                MultiwayPeekSort::<
+                    DefaultInsertionSort,
+                    DefaultMergingMethod,
+                    DefaultBufGuardFactory,
+                    2,
+                >::sort(&mut multiway_values)
+            });
+
+            assert!(values.is_sorted() && multiway_values.is_sorted());
+            assert_eq!(comparisons, multiway_comparisons);
+        }
+    }
+
+    #[test]
+    fn test_iterative_matches_recursive() {
+        use crate::algorithms::Sort as _;
+        use rand::Rng as _;
+
+        const SIZE: usize = crate::test::DEFAULT_TEST_SIZE;
+
+        let mut rng = crate::test::test_rng();
+
+        let mut inputs: Vec<Vec<usize>> = vec![
+            (0..SIZE).collect(),
+            (0..SIZE).rev().collect(),
+            // Organ pipe
+            (0..SIZE / 2).chain((0..SIZE / 2).rev()).collect(),
+            // Sawtooth of alternating ascending and descending runs
+            (0..SIZE)
+                .map(|i| {
+                    if (i / 100) % 2 == 0 {
+                        i % 100
+                    } else {
+                        99 - i % 100
+                    }
+                })
+                .collect(),
+            // Runs just around the insertion threshold
+            (0..SIZE)
+                .map(|i| i % (DEFAULT_INSERTION_THRESHOLD + 1))
+                .collect(),
+            vec![0; SIZE],
+        ];
+        for _ in 0..crate::test::DEFAULT_TEST_RUNS {
+            inputs.push((0..SIZE).map(|_| rng.random_range(0..SIZE / 10)).collect());
+        }
+
+        for values in inputs {
+            let mut recursive: Vec<_> = crate::test::IndexedOrdered::map_iter(
+                values.iter().copied().map(crate::test::CountingOrdered),
+            )
+            .collect();
+            let mut iterative = recursive.clone();
+
+            crate::algorithms::merging::reset_merge_count();
+            let recursive_comparisons =
+                crate::test::count_comparisons(|| <PeekSort>::sort(&mut recursive));
+            let recursive_merges = crate::algorithms::merging::merge_count();
+
+            crate::algorithms::merging::reset_merge_count();
+            let iterative_comparisons =
+                crate::test::count_comparisons(|| PeekSortIterative::sort(&mut iterative));
+            let iterative_merges = crate::algorithms::merging::merge_count();
+
+            assert_eq!(
+                crate::test::IndexedOrdered::is_stable_sorted(iterative.iter()),
+                Ok(true)
+            );
+            assert!(
+                recursive
+                    .iter()
+                    .map(crate::test::IndexedOrdered::index)
+                    .eq(iterative.iter().map(crate::test::IndexedOrdered::index))
+            );
+            assert_eq!(recursive_comparisons, iterative_comparisons);
+            assert_eq!(recursive_merges, iterative_merges);
+        }
+    }
+
+    #[test]
+    fn test_iterative_small_stack() {
+        use crate::algorithms::Sort as _;
+        use rand::Rng as _;
+
+        const SIZE: usize = 1 << 20;
+
+        let mut rng = crate::test::test_rng();
+        let mut values: Vec<u32> = (0..SIZE).map(|_| rng.random()).collect();
+
+        // The steps are kept on the heap, so even a tiny thread stack suffices
+        std::thread::Builder::new()
+            .stack_size(64 * 1024)
+            .spawn(move || {
+                PeekSortIterative::sort(&mut values);
+                assert!(values.is_sorted());
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn test_reverse_sorted_comparisons() {
+        use crate::algorithms::Sort as _;
+
+        let reversed: Box<[_]> = (0..crate::test::DEFAULT_TEST_SIZE)
+            .rev()
+            .map(crate::test::CountingOrdered)
+            .collect();
+
+        let mut values = reversed.clone();
+        let comparisons = crate::test::count_comparisons(|| <PeekSort>::sort(&mut values));
+        assert!(values.is_sorted());
+
+        let mut increasing_values = reversed.clone();
+        let increasing_comparisons =
+            crate::test::count_comparisons(|| PeekSortIncreasing::sort(&mut increasing_values));
+        assert!(increasing_values.is_sorted());
+
+        // Reversing finds a single run spanning all but the outermost elements
+        let len = u64::try_from(reversed.len()).expect("Slice lengths should fit into u64");
+        assert!(comparisons < 3 * len);
+        assert!(comparisons < increasing_comparisons);
     }
 }
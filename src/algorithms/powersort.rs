@@ -3,7 +3,8 @@
 use crate::algorithms::merging::BufGuard as _;
 
 /// The default [`node_power::NodePowerMethod`] to use.
-pub type DefaultNodePowerMethod = node_power::MostSignificantSetBit;
+pub type DefaultNodePowerMethod =
+    node_power::CheckedOrFallback<node_power::MostSignificantSetBit, node_power::WideDivisionLoop>;
 
 /// The default insertion sort to use.
 pub type DefaultInsertionSort = super::insertionsort::InsertionSort;
@@ -23,21 +24,45 @@ pub const DEFAULT_MERGE_K_RUNS: usize = 4;
 /// The default `MIN_RUN_LENGTH` to use.
 pub const DEFAULT_MIN_RUN_LENGTH: usize = 24;
 
+/// The largest supported `MIN_RUN_LENGTH`.
+///
+/// Extending a short run inserts up to `MIN_RUN_LENGTH` elements into it, so adversarial input,
+/// e.g. strictly decreasing blocks with `ONLY_INCREASING_RUNS`, forces O(n * MIN_RUN_LENGTH)
+/// comparisons and moves before the first merge. A binary insertion sort reduces the comparisons
+/// to O(n * log(MIN_RUN_LENGTH)), but not the moves.
+pub const MAX_MIN_RUN_LENGTH: usize = 1024;
+
 /// The default `ONLY_INCREASING_RUNS` to use.
 pub const DEFAULT_ONLY_INCREASING_RUNS: bool = false;
 
 /// The default `USE_POWER_INDEXED_STACK` to use.
 pub const DEFAULT_USE_POWER_INDEXED_STACK: bool = false;
 
+/// The default `ADAPTIVE_MIN_RUN` to use.
+pub const DEFAULT_ADAPTIVE_MIN_RUN: bool = false;
+
+/// The default `DETECT_FULLY_SORTED` to use.
+pub const DEFAULT_DETECT_FULLY_SORTED: bool = false;
+
+/// The default `KWAY_THRESHOLD` to use, i.e. always merging `MERGE_K_RUNS` runs at once.
+pub const DEFAULT_KWAY_THRESHOLD: usize = 0;
+
 /// The Powersort [`super::Sort`].
 ///
-/// - `N` is the [`noder_power::NodePowerMethod`] used to calculate the node power of runs.
+/// - `N` is the [`noder_power::NodePowerMethod`] used to calculate the node power of runs, see
+///   [`node_power::calculate()`] for slices longer than its `MAX_N`.
 /// - `I` is the insertion sort used to extend small runs.
 /// - `M` is the [`super::merging::MergingMethod`] used to merge runs.
 /// - `B` is the [`super::BufGuardFactory`] used to create the buffer for merging.
-/// - `MIN_RUN_LENGTH` determines the minimum length up to which runs will be manually extended.
-/// - `ONLY_INCREASING_RUNS` indicates whether only to use preexisting weakly increasing runs.
+/// - `MIN_RUN_LENGTH` determines the minimum length up to which runs will be manually extended, at
+///   most [`MAX_MIN_RUN_LENGTH`].
+/// - `ONLY_INCREASING_RUNS` indicates whether only preexisting weakly increasing runs are used,
+///   i.e. strictly decreasing runs are never detected and reversed.
 /// - `USE_POWER_INDEXED_STACK` indicates whether to use a power indexed stack.
+/// - `ADAPTIVE_MIN_RUN` indicates whether to adapt `MIN_RUN_LENGTH` to the slice length, see
+///   [`super::timsort::min_run_length()`].
+/// - `DETECT_FULLY_SORTED` indicates whether to return before allocating the buffer if the first
+///   run already spans the whole slice.
 pub struct PowerSort<
     N: node_power::NodePowerMethod<2> = DefaultNodePowerMethod,
     I: super::PostfixSort = DefaultInsertionSort,
@@ -46,6 +71,8 @@ pub struct PowerSort<
     const MIN_RUN_LENGTH: usize = DEFAULT_MIN_RUN_LENGTH,
     const ONLY_INCREASING_RUNS: bool = DEFAULT_ONLY_INCREASING_RUNS,
     const USE_POWER_INDEXED_STACK: bool = DEFAULT_USE_POWER_INDEXED_STACK,
+    const ADAPTIVE_MIN_RUN: bool = DEFAULT_ADAPTIVE_MIN_RUN,
+    const DETECT_FULLY_SORTED: bool = DEFAULT_DETECT_FULLY_SORTED,
 >(
     std::marker::PhantomData<N>,
     std::marker::PhantomData<I>,
@@ -61,8 +88,20 @@ impl<
     const MIN_RUN_LENGTH: usize,
     const ONLY_INCREASING_RUNS: bool,
     const USE_POWER_INDEXED_STACK: bool,
+    const ADAPTIVE_MIN_RUN: bool,
+    const DETECT_FULLY_SORTED: bool,
 > super::Sort
-    for PowerSort<N, I, M, B, MIN_RUN_LENGTH, ONLY_INCREASING_RUNS, USE_POWER_INDEXED_STACK>
+    for PowerSort<
+        N,
+        I,
+        M,
+        B,
+        MIN_RUN_LENGTH,
+        ONLY_INCREASING_RUNS,
+        USE_POWER_INDEXED_STACK,
+        ADAPTIVE_MIN_RUN,
+        DETECT_FULLY_SORTED,
+    >
 {
     const IS_STABLE: bool = I::IS_STABLE && M::IS_STABLE;
 
@@ -76,24 +115,31 @@ impl<
             ("min-run-len", MIN_RUN_LENGTH.to_string()),
             ("only-increasing", ONLY_INCREASING_RUNS.to_string()),
             ("power-indexed", USE_POWER_INDEXED_STACK.to_string()),
+            ("adaptive-min-run", ADAPTIVE_MIN_RUN.to_string()),
+            ("detect-sorted", DETECT_FULLY_SORTED.to_string()),
         ]
         .into_iter()
     }
 
-    fn sort<T: Ord>(slice: &mut [T]) {
-        if slice.len() < 2 {
-            return;
+    fn merging() -> Option<String> {
+        Some(crate::cli::display_merging::<M, 2>())
+    }
+
+    fn buffer_bytes<T>(len: usize) -> usize {
+        if len < 2 {
+            return 0;
         }
 
-        // Conservatively initiate a buffer big enough to merge the complete array
-        let mut buffer = <B::Guard<T>>::with_capacity(M::required_capacity(slice.len()));
+        M::required_capacity(len) * std::mem::size_of::<T>()
+    }
 
-        // Delegate to helper function
-        if USE_POWER_INDEXED_STACK {
-            Self::powersort::<T, PowerIndexedStack>(slice, buffer.as_uninit_slice_mut());
-        } else {
-            Self::powersort::<T, Stack>(slice, buffer.as_uninit_slice_mut());
-        }
+    fn sort<T: Ord>(slice: &mut [T]) {
+        Self::sort_with_scratch(slice, &mut StackScratch::default());
+    }
+
+    fn sort_descending<T: Ord>(slice: &mut [T]) {
+        // Sorting with the reversed ordering keeps equal elements in their initial order
+        Self::sort(super::Descending::from_mut_slice(slice));
     }
 }
 
@@ -108,35 +154,126 @@ impl<
     const MIN_RUN_LENGTH: usize,
     const ONLY_INCREASING_RUNS: bool,
     const USE_POWER_INDEXED_STACK: bool,
-> PowerSort<N, I, M, B, MIN_RUN_LENGTH, ONLY_INCREASING_RUNS, USE_POWER_INDEXED_STACK>
+    const ADAPTIVE_MIN_RUN: bool,
+    const DETECT_FULLY_SORTED: bool,
+>
+    PowerSort<
+        N,
+        I,
+        M,
+        B,
+        MIN_RUN_LENGTH,
+        ONLY_INCREASING_RUNS,
+        USE_POWER_INDEXED_STACK,
+        ADAPTIVE_MIN_RUN,
+        DETECT_FULLY_SORTED,
+    >
 {
-    /// The actual Powersort implementation.
-    fn powersort<T: Ord, S: RunStack>(slice: &mut [T], buffer: &mut [std::mem::MaybeUninit<T>]) {
-        // Create the run stack
+    /// Sorts the given slice like [`super::Sort::sort()`], reusing the run stack allocation of
+    /// `scratch` instead of allocating a new one.
+    #[allow(dead_code, reason = "Currently not used for experiments")]
+    pub fn sort_with_scratch<T: Ord>(slice: &mut [T], scratch: &mut StackScratch) {
+        const {
+            assert!(
+                MIN_RUN_LENGTH <= MAX_MIN_RUN_LENGTH,
+                "MIN_RUN_LENGTH must be at most MAX_MIN_RUN_LENGTH"
+            )
+        };
+
+        if slice.len() < 2 {
+            return;
+        }
+
+        let min_run_length = min_run_length::<MIN_RUN_LENGTH, ADAPTIVE_MIN_RUN>(slice.len());
+
+        // Find the first run
+        let first_run = next_run::<_, I, ONLY_INCREASING_RUNS>(slice, 0, min_run_length);
+
+        // The slice is already sorted, if the first run spans all of it
+        if DETECT_FULLY_SORTED && first_run.end == slice.len() {
+            return;
+        }
+
+        // Conservatively initiate a buffer big enough to merge the complete array
+        let mut buffer = <B::Guard<T>>::with_capacity(M::required_capacity(slice.len()));
+
+        // Delegate to helper function
+        if USE_POWER_INDEXED_STACK {
+            Self::powersort_from_run::<T, PowerIndexedStack, _>(
+                slice,
+                first_run,
+                &mut buffer,
+                scratch,
+            );
+        } else {
+            Self::powersort_from_run::<T, Stack, _>(slice, first_run, &mut buffer, scratch);
+        }
+    }
+
+    /// The actual Powersort implementation, finding the first run and continuing with
+    /// [`Self::powersort_from_run()`].
+    #[allow(dead_code, reason = "Currently only used in tests")]
+    fn powersort<T: Ord, S: RunStack, G: super::merging::BufGuard<T>>(
+        slice: &mut [T],
+        buffer: &mut G,
+        scratch: &mut StackScratch,
+    ) {
+        // A single run is already sorted
+        if slice.len() < 2 {
+            return;
+        }
+
+        let min_run_length = min_run_length::<MIN_RUN_LENGTH, ADAPTIVE_MIN_RUN>(slice.len());
+
+        // Find the first run
+        let first_run = next_run::<_, I, ONLY_INCREASING_RUNS>(slice, 0, min_run_length);
+
+        Self::powersort_from_run::<T, S, G>(slice, first_run, buffer, scratch);
+    }
+
+    /// Continues Powersort after the given `first_run` of `slice` has been found.
+    ///
+    /// `slice` has to contain at least two elements. The memory of `buffer` is only accessed when
+    /// merging, so a [`super::merging::LazyBuffer`] is never allocated for a single run.
+    fn powersort_from_run<T: Ord, S: RunStack, G: super::merging::BufGuard<T>>(
+        slice: &mut [T],
+        first_run: Run,
+        buffer: &mut G,
+        scratch: &mut StackScratch,
+    ) {
+        // Create the run stack, the slice length keeps `ilog2()` from panicking
         let max_stack_height =
             usize::try_from(slice.len().ilog2()).expect("This can not panic") + 2;
-        let mut stack = S::new(max_stack_height);
+        let mut stack = S::from_scratch(scratch, max_stack_height);
 
-        // Find current run
-        let mut current_run = next_run::<_, I, MIN_RUN_LENGTH, ONLY_INCREASING_RUNS>(slice, 0);
+        let min_run_length = min_run_length::<MIN_RUN_LENGTH, ADAPTIVE_MIN_RUN>(slice.len());
+
+        // Start with the first run
+        let mut current_run = first_run;
 
         // Iterate until we reach the end
         while current_run.end != slice.len() {
             // Find next run
             let next_run =
-                next_run::<_, I, MIN_RUN_LENGTH, ONLY_INCREASING_RUNS>(slice, current_run.end);
+                next_run::<_, I, ONLY_INCREASING_RUNS>(slice, current_run.end, min_run_length);
 
             // Calculate the node power of the current run
-            assert!(current_run.end == next_run.start);
+            validate_assert!(current_run.end == next_run.start);
             let current_node_power =
-                N::node_power(slice.len(), current_run.clone(), next_run.clone());
-            assert!(current_node_power != stack.top_power());
+                node_power::calculate::<N, 2>(slice.len(), current_run.clone(), next_run.clone());
+            validate_assert!(current_node_power != stack.top_power());
+            record_node_power(current_node_power);
 
             // Pop and merge runs with higher power from the stack with the current run.
             for (_, run) in stack.pop_runs_with_greater_power(current_node_power) {
                 current_run.start = run.start;
 
-                M::merge(&mut slice[current_run.clone()], run.len(), buffer);
+                super::merging::record_merge();
+                M::merge(
+                    &mut slice[current_run.clone()],
+                    run.len(),
+                    buffer.as_uninit_slice_mut(),
+                );
             }
 
             // Push current run onto the stack
@@ -146,32 +283,52 @@ impl<
 
         // Merge all remaining runs with the rest of the slice
         for (_, run) in stack.pop_all() {
-            M::merge(&mut slice[run.start..], run.len(), buffer);
+            super::merging::record_merge();
+            M::merge(
+                &mut slice[run.start..],
+                run.len(),
+                buffer.as_uninit_slice_mut(),
+            );
         }
+
+        stack.into_scratch(scratch);
     }
 }
 
 /// The Multiway Powersort [`super::Sort`].
 ///
-/// - `N` is the [`noder_power::NodePowerMethod`] used to calculate the node power of runs.
+/// - `N` is the [`noder_power::NodePowerMethod`] used to calculate the node power of runs, see
+///   [`node_power::calculate()`] for slices longer than its `MAX_N`.
 /// - `I` is the insertion sort used to extend small runs.
 /// - `M` is the [`super::merging::MultiMergingMethod`] used to merge runs.
+/// - `F` is the [`super::merging::MergingMethod`] used to merge runs of less than `KWAY_THRESHOLD`
+///   elements in total, one run after another.
 /// - `B` is the [`super::BufGuardFactory`] used to create the buffer for merging.
 /// - `MERGE_K_RUNS` determines how many runs are merged together.
-/// - `MIN_RUN_LENGTH` determines the minimum length up to which runs will be manually extended.
-/// - `ONLY_INCREASING_RUNS` indicates whether only to use preexisting weakly increasing runs.
+/// - `MIN_RUN_LENGTH` determines the minimum length up to which runs will be manually extended, at
+///   most [`MAX_MIN_RUN_LENGTH`].
+/// - `ONLY_INCREASING_RUNS` indicates whether only preexisting weakly increasing runs are used,
+///   i.e. strictly decreasing runs are never detected and reversed.
+/// - `ADAPTIVE_MIN_RUN` indicates whether to adapt `MIN_RUN_LENGTH` to the slice length, see
+///   [`super::timsort::min_run_length()`].
+/// - `KWAY_THRESHOLD` determines the total length below which merges use `F` instead of `M`,
+///   avoiding the overhead of `K` way merging for short runs.
 pub struct MultiwayPowerSort<
     N: node_power::NodePowerMethod<MERGE_K_RUNS> = DefaultNodePowerMethod,
     I: super::PostfixSort = DefaultInsertionSort,
     M: super::merging::MultiMergingMethod<MERGE_K_RUNS> = DefaultMultiMergingMethod,
+    F: super::merging::MergingMethod = DefaultMergingMethod,
     B: super::BufGuardFactory = DefaultBufGuardFactory,
     const MERGE_K_RUNS: usize = DEFAULT_MERGE_K_RUNS,
     const MIN_RUN_LENGTH: usize = DEFAULT_MIN_RUN_LENGTH,
     const ONLY_INCREASING_RUNS: bool = DEFAULT_ONLY_INCREASING_RUNS,
+    const ADAPTIVE_MIN_RUN: bool = DEFAULT_ADAPTIVE_MIN_RUN,
+    const KWAY_THRESHOLD: usize = DEFAULT_KWAY_THRESHOLD,
 >(
     std::marker::PhantomData<N>,
     std::marker::PhantomData<I>,
     std::marker::PhantomData<M>,
+    std::marker::PhantomData<F>,
     std::marker::PhantomData<B>,
 );
 
@@ -179,14 +336,28 @@ impl<
     N: node_power::NodePowerMethod<MERGE_K_RUNS>,
     I: super::PostfixSort,
     M: super::merging::MultiMergingMethod<MERGE_K_RUNS>,
+    F: super::merging::MergingMethod,
     B: super::BufGuardFactory,
     const MERGE_K_RUNS: usize,
     const MIN_RUN_LENGTH: usize,
     const ONLY_INCREASING_RUNS: bool,
+    const ADAPTIVE_MIN_RUN: bool,
+    const KWAY_THRESHOLD: usize,
 > super::Sort
-    for MultiwayPowerSort<N, I, M, B, MERGE_K_RUNS, MIN_RUN_LENGTH, ONLY_INCREASING_RUNS>
+    for MultiwayPowerSort<
+        N,
+        I,
+        M,
+        F,
+        B,
+        MERGE_K_RUNS,
+        MIN_RUN_LENGTH,
+        ONLY_INCREASING_RUNS,
+        ADAPTIVE_MIN_RUN,
+        KWAY_THRESHOLD,
+    >
 {
-    const IS_STABLE: bool = I::IS_STABLE && M::IS_STABLE;
+    const IS_STABLE: bool = I::IS_STABLE && M::IS_STABLE && (KWAY_THRESHOLD == 0 || F::IS_STABLE);
 
     const BASE_NAME: &str = "multiway-powersort";
 
@@ -198,20 +369,27 @@ impl<
             ("merging", M::display()),
             ("min-run-len", MIN_RUN_LENGTH.to_string()),
             ("only-increasing", ONLY_INCREASING_RUNS.to_string()),
+            ("adaptive-min-run", ADAPTIVE_MIN_RUN.to_string()),
+            ("kway-threshold", KWAY_THRESHOLD.to_string()),
+            ("fallback-merging", F::display()),
         ]
         .into_iter()
     }
 
-    fn sort<T: Ord>(slice: &mut [T]) {
-        if slice.len() < 2 {
-            return;
+    fn merging() -> Option<String> {
+        Some(crate::cli::display_merging::<M, MERGE_K_RUNS>())
+    }
+
+    fn buffer_bytes<T>(len: usize) -> usize {
+        if len < 2 {
+            return 0;
         }
 
-        // Conservatively initiate a buffer big enough to merge the complete array
-        let mut buffer = <B::Guard<T>>::with_capacity(M::required_capacity(slice.len()));
+        Self::required_capacity(len) * std::mem::size_of::<T>()
+    }
 
-        // Delegate to helper function
-        Self::multiway_powersort(slice, buffer.as_uninit_slice_mut());
+    fn sort<T: Ord>(slice: &mut [T]) {
+        Self::sort_with_scratch(slice, &mut StackScratch::default());
     }
 }
 
@@ -219,18 +397,113 @@ impl<
     N: node_power::NodePowerMethod<MERGE_K_RUNS>,
     I: super::PostfixSort,
     M: super::merging::MultiMergingMethod<MERGE_K_RUNS>,
+    F: super::merging::MergingMethod,
     B: super::BufGuardFactory,
     const MERGE_K_RUNS: usize,
     const MIN_RUN_LENGTH: usize,
     const ONLY_INCREASING_RUNS: bool,
-> MultiwayPowerSort<N, I, M, B, MERGE_K_RUNS, MIN_RUN_LENGTH, ONLY_INCREASING_RUNS>
+    const ADAPTIVE_MIN_RUN: bool,
+    const KWAY_THRESHOLD: usize,
+>
+    MultiwayPowerSort<
+        N,
+        I,
+        M,
+        F,
+        B,
+        MERGE_K_RUNS,
+        MIN_RUN_LENGTH,
+        ONLY_INCREASING_RUNS,
+        ADAPTIVE_MIN_RUN,
+        KWAY_THRESHOLD,
+    >
 {
+    /// Sorts the given slice like [`super::Sort::sort()`], reusing the run stack allocation of
+    /// `scratch` instead of allocating a new one.
+    #[allow(dead_code, reason = "Currently not used for experiments")]
+    pub fn sort_with_scratch<T: Ord>(slice: &mut [T], scratch: &mut StackScratch) {
+        const {
+            assert!(
+                MIN_RUN_LENGTH <= MAX_MIN_RUN_LENGTH,
+                "MIN_RUN_LENGTH must be at most MAX_MIN_RUN_LENGTH"
+            )
+        };
+
+        if slice.len() < 2 {
+            return;
+        }
+
+        // Conservatively initiate a buffer big enough to merge the complete array
+        let mut buffer = <B::Guard<T>>::with_capacity(Self::required_capacity(slice.len()));
+
+        // Delegate to helper function
+        Self::multiway_powersort::<T, Stack, _>(slice, &mut buffer, scratch);
+    }
+
+    /// The buffer capacity needed to merge slices of up to `len` elements with `M` or `F`.
+    fn required_capacity(len: usize) -> usize {
+        std::cmp::max(
+            M::required_capacity(len),
+            F::required_capacity(std::cmp::min(len, KWAY_THRESHOLD)),
+        )
+    }
+
+    /// Merges the runs of the given `run_lengths` in `slice` with `M`, see
+    /// [`super::merging::MultiMergingMethod::merge()`].
+    ///
+    /// If `slice` is shorter than `KWAY_THRESHOLD`, the runs are instead merged with `F` from left
+    /// to right.
+    fn merge<T: Ord>(
+        slice: &mut [T],
+        run_lengths: &[usize],
+        buffer: &mut [std::mem::MaybeUninit<T>],
+    ) {
+        if slice.len() >= KWAY_THRESHOLD {
+            M::merge(slice, run_lengths, buffer);
+            return;
+        }
+
+        let Some((&first, run_lengths)) = run_lengths.split_first() else {
+            return;
+        };
+
+        let mut merged = first;
+        for &run_length in run_lengths {
+            F::merge(&mut slice[..merged + run_length], merged, buffer);
+            merged += run_length;
+        }
+
+        // The last run is implied by the slice
+        F::merge(slice, merged, buffer);
+    }
+
     // The actual Multiway Powersort implementation.
-    fn multiway_powersort<T: Ord>(slice: &mut [T], buffer: &mut [std::mem::MaybeUninit<T>]) {
+    //
+    // The memory of `buffer` is only accessed when merging, so a `LazyBuffer` is never allocated
+    // for a single run.
+    fn multiway_powersort<T: Ord, S: RunStack, G: super::merging::BufGuard<T>>(
+        slice: &mut [T],
+        buffer: &mut G,
+        scratch: &mut StackScratch,
+    ) {
+        const {
+            assert!(
+                MERGE_K_RUNS >= 2,
+                "At least two runs have to be merged at once"
+            )
+        };
+
+        // A single run is already sorted, this also keeps `ilog()` from panicking. Slices with
+        // fewer than `MERGE_K_RUNS` runs need no special handling, since all merges take an
+        // arbitrary number of at most `MERGE_K_RUNS` runs.
+        if slice.len() < 2 {
+            return;
+        }
+
         // Create run stack
         let max_stack_height = (MERGE_K_RUNS - 1)
             * (usize::try_from(slice.len().ilog(MERGE_K_RUNS)).expect("This can not fail") + 2);
-        let mut stack = Stack::new(max_stack_height);
+        let mut stack = S::from_scratch(scratch, max_stack_height);
 
         // NOTE: We technically only need `MERGE_K_RUNS - 1` but that is unstable (const generics)
         // `run_lengths[run_lengths_index..]` forms the stack of merging split points use by `M`.
@@ -238,17 +511,24 @@ impl<
         let mut run_lengths = [0; MERGE_K_RUNS];
         let mut run_lengths_index = MERGE_K_RUNS;
 
+        let min_run_length = min_run_length::<MIN_RUN_LENGTH, ADAPTIVE_MIN_RUN>(slice.len());
+
         // Find current run
-        let mut current_run = next_run::<_, I, MIN_RUN_LENGTH, ONLY_INCREASING_RUNS>(slice, 0);
+        let mut current_run = next_run::<_, I, ONLY_INCREASING_RUNS>(slice, 0, min_run_length);
 
         // Iterate until we reach the end
         while current_run.end != slice.len() {
             // Find next run
             let next_run =
-                next_run::<_, I, MIN_RUN_LENGTH, ONLY_INCREASING_RUNS>(slice, current_run.end);
+                next_run::<_, I, ONLY_INCREASING_RUNS>(slice, current_run.end, min_run_length);
 
             // Calculate the node power of the current run
-            let node_power = N::node_power(slice.len(), current_run.clone(), next_run.clone());
+            let node_power = node_power::calculate::<N, MERGE_K_RUNS>(
+                slice.len(),
+                current_run.clone(),
+                next_run.clone(),
+            );
+            record_node_power(node_power);
 
             // Pop runs from the stack until the current node power is the highest
             let mut top_power = stack.top_power();
@@ -257,10 +537,11 @@ impl<
                 for (power, run) in stack.pop_runs_with_greater_power(node_power) {
                     // Run power drops, merge all previously collected runs
                     if top_power != power {
-                        M::merge(
+                        super::merging::record_merge();
+                        Self::merge(
                             &mut slice[current_run.clone()],
                             &run_lengths[run_lengths_index..],
-                            buffer,
+                            buffer.as_uninit_slice_mut(),
                         );
 
                         // Empty `run_lengths` stack and update last power
@@ -277,11 +558,12 @@ impl<
                 }
 
                 // There will be at least one run left to merge at this point
-                assert!(run_lengths_index < MERGE_K_RUNS);
-                M::merge(
+                validate_assert!(run_lengths_index < MERGE_K_RUNS);
+                super::merging::record_merge();
+                Self::merge(
                     &mut slice[current_run.clone()],
                     &run_lengths[run_lengths_index..],
-                    buffer,
+                    buffer.as_uninit_slice_mut(),
                 );
 
                 // Empty `run_lengths` stack
@@ -307,10 +589,11 @@ impl<
                 current_run.start = run.start;
             }
 
-            M::merge(
+            super::merging::record_merge();
+            Self::merge(
                 &mut slice[current_run.clone()],
                 &run_lengths[run_lengths_index..],
-                buffer,
+                buffer.as_uninit_slice_mut(),
             );
         }
 
@@ -323,8 +606,16 @@ impl<
                 current_run.start = run.start;
             }
 
-            M::merge(&mut slice[current_run.clone()], &run_lengths[1..], buffer);
+            super::merging::record_merge();
+            Self::merge(
+                &mut slice[current_run.clone()],
+                &run_lengths[1..],
+                buffer.as_uninit_slice_mut(),
+            );
         }
+
+        drop(remaining_runs);
+        stack.into_scratch(scratch);
     }
 }
 
@@ -346,36 +637,71 @@ fn find_run<T: Ord, const ONLY_INCREASING_RUNS: bool>(slice: &mut [T]) -> usize
     }
 }
 
+/// Returns the minimum run length to use for slices of length `n`.
+///
+/// If `ADAPTIVE_MIN_RUN` is `true`, this is [`super::timsort::min_run_length()`] with
+/// `MIN_RUN_LENGTH` as threshold, otherwise it is just `MIN_RUN_LENGTH`.
+fn min_run_length<const MIN_RUN_LENGTH: usize, const ADAPTIVE_MIN_RUN: bool>(n: usize) -> usize {
+    if ADAPTIVE_MIN_RUN {
+        super::timsort::min_run_length(n, MIN_RUN_LENGTH)
+    } else {
+        MIN_RUN_LENGTH
+    }
+}
+
 /// Creates the next run, by finding the longest existing run and potentially extending it using
-/// `I` such that it is at least `MIN_RUN_LENGTH` elements long.
-fn next_run<
-    T: Ord,
-    I: super::PostfixSort,
-    const MIN_RUN_LENGTH: usize,
-    const ONLY_INCREASING_RUNS: bool,
->(
+/// `I` such that it is at least `min_run_length` elements long.
+///
+/// In the worst case the extension takes O(`min_run_length`^2) comparisons with a linear and
+/// O(`min_run_length` * log(`min_run_length`)) with a binary insertion sort, see
+/// [`MAX_MIN_RUN_LENGTH`].
+fn next_run<T: Ord, I: super::PostfixSort, const ONLY_INCREASING_RUNS: bool>(
     slice: &mut [T],
     start: usize,
+    min_run_length: usize,
 ) -> Run {
     // Find longest existing run
     let run = start..start + find_run::<_, ONLY_INCREASING_RUNS>(&mut slice[start..]);
 
+    #[cfg(feature = "instrument")]
+    crate::instrument::on_run(run.start, run.len());
+
     // Extend run if too short
-    if run.len() < MIN_RUN_LENGTH {
-        let end = std::cmp::min(slice.len(), start + MIN_RUN_LENGTH);
+    let run = if run.len() < min_run_length {
+        let end = std::cmp::min(slice.len(), start + min_run_length);
 
         I::sort_with_sorted_prefix(&mut slice[start..end], run.len());
 
         start..end
     } else {
         run
-    }
+    };
+
+    debug_assert!(
+        crate::data::uncounted(|| slice[run.clone()].is_sorted()),
+        "Detected run is not sorted"
+    );
+
+    run
+}
+
+/// Reusable allocations of the run stacks used by [`PowerSort`] and [`MultiwayPowerSort`], see
+/// e.g. [`PowerSort::sort_with_scratch()`].
+#[derive(Debug, Default)]
+pub struct StackScratch {
+    /// The allocation of a [`Stack`]
+    runs: Vec<(usize, Run)>,
+    /// The allocation of a [`PowerIndexedStack`]
+    slots: Vec<Option<Run>>,
 }
 
 /// Unifies behavior of run stack implementations.
 trait RunStack {
-    /// Creates a new stack with the given capacity.
-    fn new(capacity: usize) -> Self;
+    /// Creates a new empty stack with the given capacity, reusing the allocation of `scratch`.
+    fn from_scratch(scratch: &mut StackScratch, capacity: usize) -> Self;
+
+    /// Returns the allocation of this stack to `scratch`.
+    fn into_scratch(self, scratch: &mut StackScratch);
 
     /// Returns a power greater or equal to the highest power of a run in the stack.
     fn top_power(&self) -> usize;
@@ -400,20 +726,74 @@ trait RunStack {
     ) -> impl Iterator<Item = (usize, Run)> + 'this;
 
     /// Pops all remaining runs from this stack.
-    fn pop_all(self) -> impl Iterator<Item = (usize, Run)>;
+    ///
+    /// # Note
+    ///
+    /// If the returned iterator is not fully consumed, the resulting state of the stack is left
+    /// unspecified.
+    fn pop_all<'this>(&'this mut self) -> impl Iterator<Item = (usize, Run)> + 'this;
 
     /// Returns the number of runs left on the stack.
     fn len(&self) -> usize;
 }
 
+/// Records `height` in [`crate::GLOBAL_COUNTERS`], if it exceeds the maximum stack height so far.
+#[cfg(feature = "counters")]
+fn record_stack_height(height: usize) {
+    crate::GLOBAL_COUNTERS
+        .max_stack_height
+        .increase_to(u64::try_from(height).expect("Stack height should fit into u64"));
+}
+
+thread_local! {
+    /// The number of node powers computed on this thread for each power, see [`node_powers()`]
+    static NODE_POWERS: std::cell::RefCell<Vec<u64>> = const { std::cell::RefCell::new(Vec::new()) };
+}
+
+/// Returns how many node powers [`PowerSort`] and [`MultiwayPowerSort`] computed on this thread
+/// for each power since the last [`reset_node_powers()`], indexed by the power.
+///
+/// Every computed node power corresponds to an inner node of the merge tree, so this describes
+/// its shape. The node powers are only recorded if the `counters` feature is active or during
+/// tests.
+#[allow(dead_code, reason = "Unused when feature 'counters' is inactive")]
+pub fn node_powers() -> Vec<u64> {
+    NODE_POWERS.with_borrow(Clone::clone)
+}
+
+/// Resets the node powers recorded on this thread.
+pub fn reset_node_powers() {
+    NODE_POWERS.with_borrow_mut(Vec::clear);
+}
+
+/// Records a computed node power `power`, see [`node_powers()`].
+fn record_node_power(power: usize) {
+    if cfg!(any(feature = "counters", test)) {
+        NODE_POWERS.with_borrow_mut(|powers| {
+            if powers.len() <= power {
+                powers.resize(power + 1, 0);
+            }
+            powers[power] += 1;
+        });
+    }
+}
+
 /// A power indexed stack, cannot be used for [`MultiwayPowerSort`] since it can only store one run
 /// of each power.
 #[derive(Debug)]
-struct PowerIndexedStack(Box<[Option<Run>]>, usize);
+struct PowerIndexedStack(Vec<Option<Run>>, usize);
 
 impl RunStack for PowerIndexedStack {
-    fn new(capacity: usize) -> Self {
-        Self(std::iter::repeat_n(None, capacity).collect(), 0)
+    fn from_scratch(scratch: &mut StackScratch, capacity: usize) -> Self {
+        let mut slots = std::mem::take(&mut scratch.slots);
+        slots.clear();
+        slots.resize(capacity, None);
+
+        Self(slots, 0)
+    }
+
+    fn into_scratch(self, scratch: &mut StackScratch) {
+        scratch.slots = self.0;
     }
 
     fn top_power(&self) -> usize {
@@ -421,12 +801,15 @@ impl RunStack for PowerIndexedStack {
     }
 
     fn push(&mut self, run: Run, power: usize) {
-        assert!(power >= self.1);
-        assert!(power < self.0.len());
-        assert!(self.0[power].is_none(), "Power slot is already occupied");
+        validate_assert!(power >= self.1);
+        validate_assert!(power < self.0.len());
+        validate_assert!(self.0[power].is_none(), "Power slot is already occupied");
 
         self.0[power] = Some(run);
         self.1 = power;
+
+        #[cfg(feature = "counters")]
+        record_stack_height(self.len());
     }
 
     fn pop_runs_with_greater_power<'this>(
@@ -440,8 +823,10 @@ impl RunStack for PowerIndexedStack {
             .filter_map(|i| self.0[i].take().map(|run| (i, run)))
     }
 
-    fn pop_all(mut self) -> impl Iterator<Item = (usize, Run)> {
-        (0..=self.top_power())
+    fn pop_all<'this>(&'this mut self) -> impl Iterator<Item = (usize, Run)> + 'this {
+        let top_power = self.top_power();
+        self.1 = 0;
+        (0..=top_power)
             .rev()
             .filter_map(move |i| self.0[i].take().map(|run| (i, run)))
     }
@@ -459,8 +844,16 @@ impl RunStack for PowerIndexedStack {
 struct Stack(Vec<(usize, Run)>);
 
 impl RunStack for Stack {
-    fn new(capacity: usize) -> Self {
-        Self(Vec::with_capacity(capacity))
+    fn from_scratch(scratch: &mut StackScratch, capacity: usize) -> Self {
+        let mut runs = std::mem::take(&mut scratch.runs);
+        runs.clear();
+        runs.reserve(capacity);
+
+        Self(runs)
+    }
+
+    fn into_scratch(self, scratch: &mut StackScratch) {
+        scratch.runs = self.0;
     }
 
     fn top_power(&self) -> usize {
@@ -468,13 +861,16 @@ impl RunStack for Stack {
     }
 
     fn push(&mut self, run: Run, power: usize) {
-        assert!(power >= self.top_power());
-        assert!(
+        validate_assert!(power >= self.top_power());
+        validate_assert!(
             !self.0.spare_capacity_mut().is_empty(),
             "We should not exceed the initial capacity"
         );
 
         self.0.push((power, run));
+
+        #[cfg(feature = "counters")]
+        record_stack_height(self.len());
     }
 
     fn pop_runs_with_greater_power<'this>(
@@ -490,8 +886,8 @@ impl RunStack for Stack {
         })
     }
 
-    fn pop_all(self) -> impl Iterator<Item = (usize, Run)> {
-        self.0.into_iter().rev()
+    fn pop_all<'this>(&'this mut self) -> impl Iterator<Item = (usize, Run)> + 'this {
+        self.0.drain(..).rev()
     }
 
     fn len(&self) -> usize {
@@ -503,6 +899,12 @@ impl RunStack for Stack {
 pub mod node_power {
     /// Defines a node power calculation method, for `K` way merges.
     pub trait NodePowerMethod<const K: usize> {
+        /// Whether this method works for `K` way merges at all.
+        ///
+        /// Methods only working for some `K` define a `MAX_N` of 0 for the others, so
+        /// [`calculate`] always falls back to [`WideDivisionLoop`] for them.
+        const SUPPORTS_K: bool = K > 1;
+
         /// The max `n` up to which this method words correctly
         const MAX_N: usize;
 
@@ -513,6 +915,56 @@ pub mod node_power {
         fn node_power(n: usize, run_a: super::Run, run_b: super::Run) -> usize;
     }
 
+    /// Calculate the node power of `run_a` using `N`, falling back to [`WideDivisionLoop`] if `n`
+    /// exceeds `N::MAX_N`.
+    pub fn calculate<N: NodePowerMethod<K>, const K: usize>(
+        n: usize,
+        run_a: super::Run,
+        run_b: super::Run,
+    ) -> usize {
+        if n <= N::MAX_N {
+            N::node_power(n, run_a, run_b)
+        } else {
+            <WideDivisionLoop as NodePowerMethod<K>>::node_power(n, run_a, run_b)
+        }
+    }
+
+    /// A [`NodePowerMethod`] using `Fast` for slices up to its `MAX_N` and `Slow` for longer ones,
+    /// working for slices up to `Slow::MAX_N`.
+    ///
+    /// `n` is the same for every node power calculated during a sort, so the choice between both
+    /// methods is the same for the whole sort and the branch is easily predicted.
+    #[derive(Debug, Clone, Copy)]
+    pub struct CheckedOrFallback<Fast, Slow>(std::marker::PhantomData<(Fast, Slow)>);
+
+    impl<const K: usize, Fast: NodePowerMethod<K>, Slow: NodePowerMethod<K>> NodePowerMethod<K>
+        for CheckedOrFallback<Fast, Slow>
+    {
+        const SUPPORTS_K: bool = Fast::SUPPORTS_K || Slow::SUPPORTS_K;
+
+        const MAX_N: usize = if Fast::MAX_N > Slow::MAX_N {
+            Fast::MAX_N
+        } else {
+            Slow::MAX_N
+        };
+
+        fn display() -> String {
+            format!(
+                "{fast} (fallback = {slow})",
+                fast = Fast::display(),
+                slow = Slow::display()
+            )
+        }
+
+        fn node_power(n: usize, run_a: super::Run, run_b: super::Run) -> usize {
+            if n <= Fast::MAX_N {
+                Fast::node_power(n, run_a, run_b)
+            } else {
+                Slow::node_power(n, run_a, run_b)
+            }
+        }
+    }
+
     /// Trivial [`NodePowerMethod`] using floating point calculations.
     #[allow(dead_code, reason = "Currently not used for experiments")]
     #[derive(Debug, Clone, Copy)]
@@ -558,8 +1010,13 @@ pub mod node_power {
     pub struct DivisionLoop;
 
     impl<const K: usize> NodePowerMethod<K> for DivisionLoop {
-        // NOTE: is this correct?
-        const MAX_N: usize = usize::MAX.isqrt();
+        // The positions are never reduced, while their digits agree the runs are closer than
+        // `n` after scaling by `K^power`, so they stay below `2 * n * n * K`
+        const MAX_N: usize = if <Self as NodePowerMethod<K>>::SUPPORTS_K {
+            (usize::MAX / (2 * K)).isqrt()
+        } else {
+            0
+        };
 
         fn display() -> String {
             "division-loop".to_string()
@@ -573,7 +1030,6 @@ pub mod node_power {
             let mut b = 2 * run_b.start + run_b.len();
             let mut power = 0;
 
-            // TODO: Investigate with regards to overflows?
             while b - a <= n2 && a / n2 == b / n2 {
                 power += 1;
                 a *= K;
@@ -584,6 +1040,42 @@ pub mod node_power {
         }
     }
 
+    /// A [`NodePowerMethod`] using a division loop on `u128` values, working for every `n`.
+    ///
+    /// Unlike [`DivisionLoop`], the common digit is subtracted in every step, so the positions
+    /// stay below `2 * n * K`.
+    #[derive(Debug, Clone, Copy)]
+    pub struct WideDivisionLoop;
+
+    impl<const K: usize> NodePowerMethod<K> for WideDivisionLoop {
+        const MAX_N: usize = usize::MAX;
+
+        fn display() -> String {
+            "wide-division-loop".to_string()
+        }
+
+        fn node_power(n: usize, run_a: super::Run, run_b: super::Run) -> usize {
+            let wide = |value: usize| u128::try_from(value).unwrap();
+
+            let n2 = 2 * wide(n);
+            let factor = wide(K);
+            let mut a = wide(run_a.start) + wide(run_a.end);
+            let mut b = wide(run_b.start) + wide(run_b.end);
+            let mut power = 0;
+
+            loop {
+                let (digit_a, digit_b) = (a / n2, b / n2);
+                if digit_a != digit_b {
+                    break power;
+                }
+
+                power += 1;
+                a = (a - digit_a * n2) * factor;
+                b = (b - digit_b * n2) * factor;
+            }
+        }
+    }
+
     /// A [`NodePowerMethod`] using a loop with bitwise operations.
     ///
     /// # Note
@@ -594,11 +1086,12 @@ pub mod node_power {
     pub struct BitwiseLoop;
 
     impl<const K: usize> NodePowerMethod<K> for BitwiseLoop {
-        const MAX_N: usize = {
-            assert!(K > 1);
-            assert!(K.count_ones() == 1, "K has to be a power of 2");
+        const SUPPORTS_K: bool = K > 1 && K.is_power_of_two();
 
+        const MAX_N: usize = if <Self as NodePowerMethod<K>>::SUPPORTS_K {
             1 << (usize::BITS - 1)
+        } else {
+            0
         };
 
         fn display() -> String {
@@ -606,6 +1099,10 @@ pub mod node_power {
         }
 
         fn node_power(n: usize, run_a: super::Run, run_b: super::Run) -> usize {
+            assert!(
+                <Self as NodePowerMethod<K>>::SUPPORTS_K,
+                "K has to be a power of 2"
+            );
             assert!(n <= <Self as NodePowerMethod<K>>::MAX_N);
 
             let factor: usize = K.trailing_zeros().try_into().unwrap();
@@ -643,11 +1140,12 @@ pub mod node_power {
     pub struct MostSignificantSetBit;
 
     impl<const K: usize> NodePowerMethod<K> for MostSignificantSetBit {
-        const MAX_N: usize = {
-            assert!(K > 1);
-            assert!(K.count_ones() == 1, "K has to be a power of 2");
+        const SUPPORTS_K: bool = K > 1 && K.is_power_of_two();
 
+        const MAX_N: usize = if <Self as NodePowerMethod<K>>::SUPPORTS_K {
             1 << (usize::BITS / 2 - 1)
+        } else {
+            0
         };
 
         fn display() -> String {
@@ -655,6 +1153,10 @@ pub mod node_power {
         }
 
         fn node_power(n: usize, run_a: super::Run, run_b: super::Run) -> usize {
+            assert!(
+                <Self as NodePowerMethod<K>>::SUPPORTS_K,
+                "K has to be a power of 2"
+            );
             assert!(n <= <Self as NodePowerMethod<K>>::MAX_N);
 
             const HALF_MASK: usize = usize::MAX >> (usize::BITS / 2);
@@ -704,10 +1206,34 @@ mod tests {
         true,
     >;
 
+    type PowerSortTrivialAdaptive = PowerSort<
+        node_power::Trivial,
+        DefaultInsertionSort,
+        DefaultMergingMethod,
+        DefaultBufGuardFactory,
+        DEFAULT_MIN_RUN_LENGTH,
+        DEFAULT_ONLY_INCREASING_RUNS,
+        DEFAULT_USE_POWER_INDEXED_STACK,
+        true,
+    >;
+
+    type PowerSortTrivialDetectSorted = PowerSort<
+        node_power::Trivial,
+        DefaultInsertionSort,
+        DefaultMergingMethod,
+        DefaultBufGuardFactory,
+        DEFAULT_MIN_RUN_LENGTH,
+        DEFAULT_ONLY_INCREASING_RUNS,
+        DEFAULT_USE_POWER_INDEXED_STACK,
+        DEFAULT_ADAPTIVE_MIN_RUN,
+        true,
+    >;
+
     type PowerSortTrivialMulti4 = MultiwayPowerSort<
         node_power::Trivial,
         DefaultInsertionSort,
         DefaultMultiMergingMethod,
+        DefaultMergingMethod,
         DefaultBufGuardFactory,
         4,
         DEFAULT_MIN_RUN_LENGTH,
@@ -718,12 +1244,38 @@ mod tests {
         node_power::Trivial,
         DefaultInsertionSort,
         DefaultMultiMergingMethod,
+        DefaultMergingMethod,
         DefaultBufGuardFactory,
         8,
         DEFAULT_MIN_RUN_LENGTH,
         DEFAULT_ONLY_INCREASING_RUNS,
     >;
 
+    type PowerSortTrivialMulti4Adaptive = MultiwayPowerSort<
+        node_power::Trivial,
+        DefaultInsertionSort,
+        DefaultMultiMergingMethod,
+        DefaultMergingMethod,
+        DefaultBufGuardFactory,
+        4,
+        DEFAULT_MIN_RUN_LENGTH,
+        DEFAULT_ONLY_INCREASING_RUNS,
+        true,
+    >;
+
+    type PowerSortTrivialMulti4KwayThreshold<const THRESHOLD: usize> = MultiwayPowerSort<
+        node_power::Trivial,
+        DefaultInsertionSort,
+        DefaultMultiMergingMethod,
+        super::super::merging::two_way::Galloping,
+        DefaultBufGuardFactory,
+        4,
+        DEFAULT_MIN_RUN_LENGTH,
+        DEFAULT_ONLY_INCREASING_RUNS,
+        DEFAULT_ADAPTIVE_MIN_RUN,
+        THRESHOLD,
+    >;
+
     mod two_way {
         use crate::generate_test_suite;
 
@@ -733,6 +1285,8 @@ mod tests {
 
             super::PowerSortTrivial,
             super::PowerSortTrivialPowerIndexedStack,
+            super::PowerSortTrivialAdaptive,
+            super::PowerSortTrivialDetectSorted,
         }
     }
 
@@ -745,9 +1299,455 @@ mod tests {
 
             super::PowerSortTrivialMulti4,
             super::PowerSortTrivialMulti8,
+            super::PowerSortTrivialMulti4Adaptive,
+            super::PowerSortTrivialMulti4KwayThreshold<64>,
+            super::PowerSortTrivialMulti4KwayThreshold<{ super::TEST_SIZE }>,
         }
     }
 
+    std::thread_local! {
+        /// The maximum height reached by a [`MaxHeightStack`] on this thread
+        static MAX_HEIGHT: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+    }
+
+    /// A [`RunStack`] wrapper, recording the maximum stack height in [`MAX_HEIGHT`].
+    struct MaxHeightStack<S: RunStack>(S);
+
+    impl<S: RunStack> RunStack for MaxHeightStack<S> {
+        fn from_scratch(scratch: &mut StackScratch, capacity: usize) -> Self {
+            Self(S::from_scratch(scratch, capacity))
+        }
+
+        fn into_scratch(self, scratch: &mut StackScratch) {
+            self.0.into_scratch(scratch);
+        }
+
+        fn top_power(&self) -> usize {
+            self.0.top_power()
+        }
+
+        fn push(&mut self, run: Run, power: usize) {
+            self.0.push(run, power);
+            MAX_HEIGHT.set(MAX_HEIGHT.get().max(self.0.len()));
+        }
+
+        fn pop_runs_with_greater_power<'this>(
+            &'this mut self,
+            power: usize,
+        ) -> impl Iterator<Item = (usize, Run)> + 'this {
+            self.0.pop_runs_with_greater_power(power)
+        }
+
+        fn pop_all<'this>(&'this mut self) -> impl Iterator<Item = (usize, Run)> + 'this {
+            self.0.pop_all()
+        }
+
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+    }
+
+    /// Sorts random permutations with `sorter` and checks that the maximum stack height stays at
+    /// or below `(log_K(n) + 1) * (K - 1)`.
+    ///
+    /// Each of the at most `log_K(n) + 1` node powers holds at most `K - 1` runs. Note that the
+    /// tighter `log_K(n) * (K - 1) + 2` only holds for `K = 2`.
+    fn test_max_stack_height<const K: usize>(
+        sorter: fn(&mut [usize], &mut Vec<usize>, &mut StackScratch),
+    ) {
+        use rand::seq::SliceRandom as _;
+
+        let mut rng = crate::test::test_rng();
+
+        for _ in 0..RUNS {
+            let n = rng.random_range(2..TEST_SIZE);
+            let mut values: Vec<usize> = (0..n).collect();
+            values.shuffle(&mut rng);
+            let mut buffer = Vec::with_capacity(n);
+
+            MAX_HEIGHT.set(0);
+            sorter(&mut values, &mut buffer, &mut StackScratch::default());
+
+            let bound = (usize::try_from(n.ilog(K)).unwrap() + 1) * (K - 1);
+            assert!(values.is_sorted());
+            assert!(
+                MAX_HEIGHT.get() <= bound,
+                "Stack height {height} exceeded {bound} for n = {n}",
+                height = MAX_HEIGHT.get(),
+            );
+        }
+    }
+
+    #[test]
+    fn test_name() {
+        use crate::algorithms::Sort as _;
+
+        let name = <PowerSort>::name();
+        assert!(name.starts_with("powersort("), "{name}");
+        assert!(
+            name.contains(&format!("min-run-len={DEFAULT_MIN_RUN_LENGTH}")),
+            "{name}"
+        );
+
+        let name = PowerSort::<
+            DefaultNodePowerMethod,
+            DefaultInsertionSort,
+            DefaultMergingMethod,
+            DefaultBufGuardFactory,
+            7,
+        >::name();
+        assert!(name.contains("min-run-len=7, "), "{name}");
+
+        let name = PowerSortTrivialMulti8::name();
+        assert!(name.starts_with("multiway-powersort(K=8, "), "{name}");
+        assert_ne!(name, PowerSortTrivialMulti4::name());
+    }
+
+    #[test]
+    fn test_detect_fully_sorted() {
+        use crate::algorithms::Sort as _;
+
+        let n = TEST_SIZE;
+        let mut values: Vec<_> = (0..n).map(crate::test::CountingOrdered).collect();
+
+        let comparisons =
+            crate::test::count_comparisons(|| PowerSortTrivialDetectSorted::sort(&mut values));
+        assert!(values.is_sorted());
+        assert_eq!(comparisons, u64::try_from(n - 1).unwrap());
+
+        // Strictly decreasing slices are a single run as well
+        values.reverse();
+        let comparisons =
+            crate::test::count_comparisons(|| PowerSortTrivialDetectSorted::sort(&mut values));
+        assert!(values.is_sorted());
+        assert_eq!(comparisons, u64::try_from(n - 1).unwrap());
+    }
+
+    /// Sorts random slices with `sort_with_scratch`, reusing a single [`StackScratch`] (initially
+    /// containing stale runs), and checks the results match `sort` and the used stack allocation
+    /// is left empty.
+    fn test_scratch_reuse(
+        sort: fn(&mut [crate::test::IndexedOrdered<usize>]),
+        sort_with_scratch: fn(&mut [crate::test::IndexedOrdered<usize>], &mut StackScratch),
+        power_indexed: bool,
+    ) {
+        use rand::seq::SliceRandom as _;
+
+        let mut rng = crate::test::test_rng();
+        let mut scratch = StackScratch {
+            runs: vec![(usize::MAX, 0..TEST_SIZE); 4],
+            slots: vec![Some(0..TEST_SIZE); 4],
+        };
+
+        for _ in 0..RUNS {
+            let n = rng.random_range(0..TEST_SIZE);
+            let mut values: Vec<usize> = (0..n).map(|i| i / 4).collect();
+            values.shuffle(&mut rng);
+
+            let mut expected: Vec<_> =
+                crate::test::IndexedOrdered::map_iter(values.iter().copied()).collect();
+            let mut actual = expected.clone();
+            sort(&mut expected);
+            sort_with_scratch(&mut actual, &mut scratch);
+
+            assert!(actual.is_sorted());
+            assert!(
+                actual
+                    .iter()
+                    .map(crate::test::IndexedOrdered::index)
+                    .eq(expected.iter().map(crate::test::IndexedOrdered::index))
+            );
+            if n < 2 {
+                continue;
+            }
+            if power_indexed {
+                assert!(
+                    scratch.slots.iter().all(Option::is_none),
+                    "Stale runs left on the power indexed stack"
+                );
+            } else {
+                assert!(scratch.runs.is_empty(), "Stale runs left on the stack");
+            }
+        }
+    }
+
+    #[test]
+    fn test_sort_with_scratch() {
+        use crate::algorithms::Sort as _;
+
+        test_scratch_reuse(
+            PowerSortTrivial::sort,
+            PowerSortTrivial::sort_with_scratch,
+            false,
+        );
+        test_scratch_reuse(
+            PowerSortTrivialPowerIndexedStack::sort,
+            PowerSortTrivialPowerIndexedStack::sort_with_scratch,
+            true,
+        );
+        test_scratch_reuse(
+            PowerSortTrivialMulti4::sort,
+            PowerSortTrivialMulti4::sort_with_scratch,
+            false,
+        );
+    }
+
+    #[test]
+    fn max_stack_height_two_way() {
+        type MinRun1 = PowerSort<
+            DefaultNodePowerMethod,
+            DefaultInsertionSort,
+            DefaultMergingMethod,
+            DefaultBufGuardFactory,
+            1,
+        >;
+
+        test_max_stack_height::<2>(PowerSortTrivial::powersort::<_, MaxHeightStack<Stack>, _>);
+        test_max_stack_height::<2>(MinRun1::powersort::<_, MaxHeightStack<Stack>, _>);
+        test_max_stack_height::<2>(
+            PowerSortTrivialPowerIndexedStack::powersort::<_, MaxHeightStack<PowerIndexedStack>, _>,
+        );
+    }
+
+    #[test]
+    fn test_kway_threshold() {
+        use crate::algorithms::Sort as _;
+        use rand::Rng as _;
+
+        const THRESHOLD: usize = 256;
+
+        type Threshold = PowerSortTrivialMulti4KwayThreshold<THRESHOLD>;
+
+        let mut rng = crate::test::test_rng();
+        for size in (THRESHOLD - 40..THRESHOLD + 40).chain([TEST_SIZE]) {
+            for distinct in [size, 10] {
+                let values: Vec<_> = crate::test::IndexedOrdered::map_iter(
+                    (0..size).map(|_| rng.random_range(0..distinct)),
+                )
+                .collect();
+
+                let mut kway = values.clone();
+                PowerSortTrivialMulti4::sort(&mut kway);
+                let mut threshold = values;
+                Threshold::sort(&mut threshold);
+
+                // Both sorts are stable, so the results have to match including the indices
+                assert!(threshold.is_sorted());
+                assert_eq!(
+                    crate::test::IndexedOrdered::first_unstable_index(&threshold),
+                    None
+                );
+                assert!(
+                    threshold
+                        .iter()
+                        .map(crate::test::IndexedOrdered::index)
+                        .eq(kway.iter().map(crate::test::IndexedOrdered::index)),
+                    "Results differ for size {size}"
+                );
+            }
+        }
+    }
+
+    type Indexed = crate::test::IndexedOrdered<usize>;
+
+    /// Sorts tiny slices of `0`, `1`, `2`, `K - 1`, `K`, `K + 1` and `MIN_RUN_LENGTH - 1` elements
+    /// with `S`, also directly with the helper `sorter`, checking that they end up stable sorted.
+    ///
+    /// The inputs form between a single and up to `size` runs, in particular fewer than `K`.
+    fn test_tiny_sizes<S: crate::algorithms::Sort, const K: usize, const MIN_RUN_LENGTH: usize>(
+        sorter: fn(&mut [Indexed], &mut Vec<Indexed>, &mut StackScratch),
+    ) {
+        use rand::seq::SliceRandom as _;
+
+        let mut rng = crate::test::test_rng();
+
+        for size in [0, 1, 2, K - 1, K, K + 1, MIN_RUN_LENGTH.saturating_sub(1)] {
+            let mut inputs: Vec<Vec<usize>> = vec![
+                (0..size).collect(),
+                (0..size).rev().collect(),
+                // Alternating pairs, forming `size / 2` runs without extension
+                (0..size).map(|i| i ^ 1).collect(),
+                (0..size).map(|i| i % 2).collect(),
+            ];
+            for _ in 0..10 {
+                let mut values: Vec<usize> = (0..size).map(|i| i / 2).collect();
+                values.shuffle(&mut rng);
+                inputs.push(values);
+            }
+
+            for values in inputs {
+                let mut expected = values.clone();
+                expected.sort();
+
+                let mut elements: Vec<_> =
+                    crate::test::IndexedOrdered::map_iter(values.iter().copied()).collect();
+                S::sort(&mut elements);
+
+                let mut helper_elements: Vec<_> =
+                    crate::test::IndexedOrdered::map_iter(values.iter().copied()).collect();
+                let mut buffer = Vec::with_capacity(size);
+                sorter(
+                    &mut helper_elements,
+                    &mut buffer,
+                    &mut StackScratch::default(),
+                );
+
+                for elements in [elements, helper_elements] {
+                    assert_eq!(
+                        crate::test::IndexedOrdered::is_stable_sorted(elements.iter()),
+                        Ok(true),
+                        "{} did not sort {values:?} stable",
+                        S::name(),
+                    );
+                    assert!(
+                        elements
+                            .iter()
+                            .map(|element| values[element.index()])
+                            .eq(expected.iter().copied())
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_tiny_sizes_multi_way() {
+        type MinRun1<const K: usize> = MultiwayPowerSort<
+            DefaultNodePowerMethod,
+            DefaultInsertionSort,
+            DefaultMultiMergingMethod,
+            DefaultMergingMethod,
+            DefaultBufGuardFactory,
+            K,
+            1,
+        >;
+        type DefaultMinRun<const K: usize> = MultiwayPowerSort<
+            DefaultNodePowerMethod,
+            DefaultInsertionSort,
+            DefaultMultiMergingMethod,
+            DefaultMergingMethod,
+            DefaultBufGuardFactory,
+            K,
+        >;
+
+        macro_rules! test_k {
+            ($($k:literal),*) => {
+                $(
+                    test_tiny_sizes::<MinRun1<$k>, $k, 1>(
+                        MinRun1::<$k>::multiway_powersort::<_, Stack, _>,
+                    );
+                    test_tiny_sizes::<DefaultMinRun<$k>, $k, DEFAULT_MIN_RUN_LENGTH>(
+                        DefaultMinRun::<$k>::multiway_powersort::<_, Stack, _>,
+                    );
+                )*
+            };
+        }
+
+        test_k!(2, 4, 8, 16);
+    }
+
+    #[test]
+    fn test_tiny_sizes_two_way() {
+        type MinRun1 = PowerSort<
+            DefaultNodePowerMethod,
+            DefaultInsertionSort,
+            DefaultMergingMethod,
+            DefaultBufGuardFactory,
+            1,
+        >;
+
+        test_tiny_sizes::<MinRun1, 2, 1>(MinRun1::powersort::<_, Stack, _>);
+        test_tiny_sizes::<PowerSort, 2, DEFAULT_MIN_RUN_LENGTH>(
+            <PowerSort>::powersort::<_, Stack, _>,
+        );
+    }
+
+    #[test]
+    fn max_stack_height_multi_way() {
+        type MinRun1<const K: usize> = MultiwayPowerSort<
+            DefaultNodePowerMethod,
+            DefaultInsertionSort,
+            DefaultMultiMergingMethod,
+            DefaultMergingMethod,
+            DefaultBufGuardFactory,
+            K,
+            1,
+        >;
+
+        test_max_stack_height::<4>(
+            PowerSortTrivialMulti4::multiway_powersort::<_, MaxHeightStack<Stack>, _>,
+        );
+        test_max_stack_height::<8>(
+            PowerSortTrivialMulti8::multiway_powersort::<_, MaxHeightStack<Stack>, _>,
+        );
+        test_max_stack_height::<4>(MinRun1::<4>::multiway_powersort::<_, MaxHeightStack<Stack>, _>);
+        test_max_stack_height::<8>(MinRun1::<8>::multiway_powersort::<_, MaxHeightStack<Stack>, _>);
+    }
+
+    /// Sorts random permutations with the `K` way `sorter` and checks the recorded node powers,
+    /// assuming runs of about two elements, see [`node_powers()`].
+    fn test_node_powers<const K: u32>(sorter: fn(&mut [usize])) {
+        use rand::seq::SliceRandom as _;
+
+        let mut rng = crate::test::test_rng();
+
+        for size in [1_000, 100_000, 1 << 17] {
+            let mut values: Vec<usize> = (0..size).collect();
+            values.shuffle(&mut rng);
+
+            reset_node_powers();
+            sorter(&mut values);
+            let powers = node_powers();
+            assert!(values.is_sorted());
+
+            // The node powers of a merge tree over (nearly) `size` runs reach about `log_K(n)`
+            let max_power = powers.iter().rposition(|&count| count > 0).unwrap();
+            let log = f64::from(u32::try_from(size).unwrap()).log(f64::from(K));
+            let distance = (f64::from(u32::try_from(max_power).unwrap()) - log).abs();
+            assert!(
+                distance <= 1.0,
+                "Max power {max_power} is too far from {log} for K = {K} and size {size}"
+            );
+
+            // Node powers are positive and the top levels of the tree are complete
+            assert_eq!(powers[0], 0);
+            for (power, &count) in powers.iter().enumerate().take(max_power - 1).skip(1) {
+                let complete =
+                    u64::from(K - 1) * u64::from(K).pow(u32::try_from(power - 1).unwrap());
+                assert_eq!(
+                    count, complete,
+                    "Incomplete level {power} for K = {K} and size {size}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_node_power_histogram() {
+        use crate::algorithms::Sort as _;
+
+        type MinRun1 = PowerSort<
+            DefaultNodePowerMethod,
+            DefaultInsertionSort,
+            DefaultMergingMethod,
+            DefaultBufGuardFactory,
+            1,
+        >;
+        type MultiMinRun1<const K: usize> = MultiwayPowerSort<
+            DefaultNodePowerMethod,
+            DefaultInsertionSort,
+            DefaultMultiMergingMethod,
+            DefaultMergingMethod,
+            DefaultBufGuardFactory,
+            K,
+            1,
+        >;
+
+        test_node_powers::<2>(MinRun1::sort);
+        test_node_powers::<4>(MultiMinRun1::<4>::sort);
+        test_node_powers::<8>(MultiMinRun1::<8>::sort);
+    }
+
     macro_rules! test_powers {
         ([$($power:expr),*]: $k:ident => $code:expr) => {
             $(
@@ -760,49 +1760,379 @@ mod tests {
         };
     }
 
+    /// Tests the node power method `$method` for every `K` from 1 to 16 with slices up to
+    /// `$max_n`, skipping the `K` it does not support.
+    macro_rules! test_node_power_method {
+        ($method:ty, $max_n:expr) => {
+            test_powers!(
+                [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]:
+                K => test_node_power_calculations::<$method, K>($max_n)
+            )
+        };
+    }
+
     #[test]
     fn node_power_division_loop() {
-        test_powers!(
-            [2, 3, 4, 5, 6, 7, 8]:
-            K => test_node_power_calculations::<node_power::DivisionLoop, K>()
-        );
+        test_node_power_method!(node_power::DivisionLoop, usize::MAX);
     }
 
     #[test]
     fn node_power_bitwise_loop() {
-        test_powers!(
-            [2, 4, 8, 16]:
-            K => test_node_power_calculations::<node_power::BitwiseLoop, K>()
-        );
+        test_node_power_method!(node_power::BitwiseLoop, usize::MAX);
     }
 
     #[test]
     fn node_power_most_significant_bit_set() {
-        test_powers!(
-            [2, 4, 8, 16]:
-            K => test_node_power_calculations::<node_power::MostSignificantSetBit, K>()
+        test_node_power_method!(node_power::MostSignificantSetBit, usize::MAX);
+    }
+
+    #[test]
+    fn node_power_wide_division_loop() {
+        test_node_power_method!(node_power::WideDivisionLoop, 1 << 40);
+    }
+
+    #[test]
+    fn node_power_default() {
+        test_node_power_method!(DefaultNodePowerMethod, 1 << 40);
+    }
+
+    #[test]
+    fn test_supports_k() {
+        use node_power::*;
+
+        const {
+            assert!(<DivisionLoop as NodePowerMethod<3>>::SUPPORTS_K);
+            assert!(!<DivisionLoop as NodePowerMethod<1>>::SUPPORTS_K);
+            assert!(<BitwiseLoop as NodePowerMethod<8>>::SUPPORTS_K);
+            assert!(!<BitwiseLoop as NodePowerMethod<6>>::SUPPORTS_K);
+            assert!(<DefaultNodePowerMethod as NodePowerMethod<3>>::SUPPORTS_K);
+        }
+        assert_eq!(<MostSignificantSetBit as NodePowerMethod<6>>::MAX_N, 0);
+
+        // Unsupported `K` always use the fallback
+        assert_eq!(
+            calculate::<MostSignificantSetBit, 3>(100, 10..20, 20..30),
+            <Trivial as NodePowerMethod<3>>::node_power(100, 10..20, 20..30)
+        );
+    }
+
+    #[test]
+    fn test_binary_insertion_extension() {
+        use super::super::Sort as _;
+        use super::super::insertionsort::InsertionSort;
+        use crate::test::{CountingOrdered, count_comparisons};
+
+        const MIN_RUN_LENGTH: usize = MAX_MIN_RUN_LENGTH;
+        const BLOCKS: usize = 8;
+
+        // Strictly decreasing blocks, each forming a run of length 1 when only using increasing
+        // runs, i.e. every extension inserts each element at the front of the run
+        let blocks: Vec<_> = (0..BLOCKS)
+            .flat_map(|block| {
+                (0..MIN_RUN_LENGTH)
+                    .rev()
+                    .map(move |i| block * MIN_RUN_LENGTH + i)
+            })
+            .map(CountingOrdered)
+            .collect();
+
+        fn extension_comparisons<I: super::super::PostfixSort>(
+            blocks: &[CountingOrdered<usize>],
+        ) -> u64 {
+            let mut slice = blocks.to_vec();
+            let mut start = 0;
+            let comparisons = count_comparisons(|| {
+                while start < slice.len() {
+                    let run = next_run::<_, I, true>(&mut slice, start, MIN_RUN_LENGTH);
+                    assert_eq!(run.len(), MIN_RUN_LENGTH);
+                    start = run.end;
+                }
+            });
+            assert!(slice.is_sorted());
+
+            comparisons
+        }
+
+        // Finding each run takes a single comparison, the binary search inserting the element at
+        // index `i` at most `ceil(log2(i)) + 1`
+        let binary_run_bound = 1
+            + (1..MIN_RUN_LENGTH)
+                .map(|i| u64::from(i.next_power_of_two().ilog2()) + 1)
+                .sum::<u64>();
+        let binary_bound = binary_run_bound * u64::try_from(BLOCKS).unwrap();
+        let binary = extension_comparisons::<InsertionSort<true>>(&blocks);
+        assert!(binary <= binary_bound, "{binary} > {binary_bound}");
+
+        // Linear insertion compares each element with the whole run
+        let linear_comparisons =
+            u64::try_from(BLOCKS * (1 + MIN_RUN_LENGTH * (MIN_RUN_LENGTH - 1) / 2)).unwrap();
+        assert_eq!(
+            extension_comparisons::<InsertionSort<false>>(&blocks),
+            linear_comparisons
+        );
+        assert!(binary * 10 < linear_comparisons);
+
+        // Sorting the worst case with binary insertion stays within the bound plus merging
+        let mut slice = blocks.clone();
+        let comparisons = count_comparisons(|| {
+            PowerSort::<
+                DefaultNodePowerMethod,
+                InsertionSort<true>,
+                DefaultMergingMethod,
+                DefaultBufGuardFactory,
+                MIN_RUN_LENGTH,
+                true,
+            >::sort(&mut slice)
+        });
+        assert!(slice.is_sorted());
+        assert!(comparisons <= binary_bound + u64::try_from(2 * blocks.len()).unwrap());
+    }
+
+    #[test]
+    fn test_next_run_boundaries() {
+        use super::super::insertionsort::InsertionSort;
+        use crate::test::IndexedOrdered;
+
+        /// Checks that the run found in `values` from `start` ends at `end`, is sorted stable and
+        /// that no element outside of it was moved.
+        fn check<const ONLY_INCREASING_RUNS: bool>(
+            values: &[u32],
+            start: usize,
+            min_run_length: usize,
+            end: usize,
+        ) {
+            let mut slice: Vec<_> = IndexedOrdered::map_iter(values.iter().copied()).collect();
+            let run = next_run::<_, InsertionSort, ONLY_INCREASING_RUNS>(
+                &mut slice,
+                start,
+                min_run_length,
+            );
+
+            assert_eq!(
+                run,
+                start..end,
+                "Wrong run in {values:?} from {start} (only increasing: {ONLY_INCREASING_RUNS})"
+            );
+            assert_eq!(
+                IndexedOrdered::is_stable_sorted(slice[run.clone()].iter()),
+                Ok(true)
+            );
+            assert!(
+                slice
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| !run.contains(index))
+                    .all(|(index, element)| element.index() == index)
+            );
+        }
+
+        // Equal elements form a single weakly increasing run, which is never reversed
+        check::<true>(&[5; 10], 0, 1, 10);
+        check::<false>(&[5; 10], 0, 1, 10);
+
+        // Strictly decreasing runs are only detected if not only using increasing runs
+        let decreasing: Vec<u32> = (0..10).rev().collect();
+        check::<true>(&decreasing, 0, 1, 1);
+        check::<false>(&decreasing, 0, 1, 10);
+
+        // Decreasing runs stop before equal elements, so reversing them stays stable
+        check::<true>(&[4, 3, 3, 2], 0, 1, 1);
+        check::<false>(&[4, 3, 3, 2], 0, 1, 2);
+        check::<false>(&[4, 3, 3, 2], 1, 1, 3);
+
+        // Increasing runs include equal elements and stop at the first descent
+        check::<true>(&[1, 2, 2, 3, 0], 0, 1, 4);
+        check::<false>(&[1, 2, 2, 3, 0], 0, 1, 4);
+
+        // Runs starting after the beginning and ending exactly at the end of the slice
+        check::<false>(&[0, 3, 2, 1], 1, 1, 4);
+        check::<true>(&[0, 3, 2, 1], 3, 1, 4);
+        check::<false>(&[0, 1, 2, 2], 3, 1, 4);
+
+        // Extensions are cut off at the end of the slice
+        check::<true>(&decreasing, 0, 4, 4);
+        check::<true>(&decreasing, 7, 8, 10);
+        check::<false>(&[1, 0, 1, 0, 1], 1, 3, 4);
+        check::<false>(&[1, 0, 1, 0, 1], 2, 20, 5);
+    }
+
+    /// A [`node_power::NodePowerMethod`] only supporting tiny slices, panicking otherwise.
+    struct TinyNodePower;
+
+    impl<const K: usize> node_power::NodePowerMethod<K> for TinyNodePower {
+        const MAX_N: usize = 64;
+
+        fn display() -> String {
+            "tiny".to_string()
+        }
+
+        fn node_power(n: usize, run_a: Run, run_b: Run) -> usize {
+            assert!(n <= <Self as node_power::NodePowerMethod<K>>::MAX_N);
+
+            <node_power::Trivial as node_power::NodePowerMethod<K>>::node_power(n, run_a, run_b)
+        }
+    }
+
+    #[test]
+    fn test_node_power_fallback() {
+        use super::super::Sort as _;
+        use node_power::*;
+
+        // Slices beyond `MAX_N` are handled by the fallback
+        let (run_a, run_b) = (3..10, 10..12);
+        assert_eq!(
+            calculate::<TinyNodePower, 2>(1000, run_a.clone(), run_b.clone()),
+            <Trivial as NodePowerMethod<2>>::node_power(1000, run_a.clone(), run_b.clone())
+        );
+        let n = 1 << (usize::BITS / 2 + 4);
+        assert_eq!(
+            calculate::<MostSignificantSetBit, 4>(n, run_a.clone(), run_b.clone()),
+            <Trivial as NodePowerMethod<4>>::node_power(n, run_a, run_b)
+        );
+
+        // The fallback creates the same merge tree, so the comparison counts match
+        let mut rng = crate::test::test_rng();
+        let values: Vec<_> = (0..TEST_SIZE)
+            .map(|_| crate::test::CountingOrdered(rng.random::<u64>()))
+            .collect();
+        let count = |sort: fn(&mut [crate::test::CountingOrdered<u64>])| {
+            let mut values = values.clone();
+            let comparisons = crate::test::count_comparisons(|| sort(&mut values));
+            assert!(values.is_sorted());
+            comparisons
+        };
+
+        assert_eq!(
+            count(PowerSort::<TinyNodePower>::sort),
+            count(PowerSort::<Trivial>::sort)
+        );
+        assert_eq!(
+            count(MultiwayPowerSort::<TinyNodePower>::sort),
+            count(MultiwayPowerSort::<Trivial>::sort)
+        );
+    }
+
+    #[test]
+    fn test_checked_or_fallback() {
+        use super::super::Sort as _;
+        use node_power::*;
+
+        type TinyOrTrivial = CheckedOrFallback<TinyNodePower, Trivial>;
+
+        // `TinyNodePower` panics beyond its `MAX_N`, so longer slices have to use the fallback
+        assert_eq!(<TinyOrTrivial as NodePowerMethod<2>>::MAX_N, usize::MAX);
+        for n in [2, 10, 64, 65, 1000, 1 << 40] {
+            for (run_a, run_b) in [(0..1, 1..2), (0..n / 2, n / 2..n), (n - 2..n - 1, n - 1..n)] {
+                assert_eq!(
+                    <TinyOrTrivial as NodePowerMethod<4>>::node_power(
+                        n,
+                        run_a.clone(),
+                        run_b.clone()
+                    ),
+                    <Trivial as NodePowerMethod<4>>::node_power(n, run_a, run_b),
+                    "Node powers differ for n = {n}"
+                );
+            }
+        }
+
+        // The default falls back beyond the `MAX_N` of its fast method
+        let n = 1 << (usize::BITS / 2 + 4);
+        assert!(n > <MostSignificantSetBit as NodePowerMethod<2>>::MAX_N);
+        assert_eq!(
+            <DefaultNodePowerMethod as NodePowerMethod<2>>::node_power(n, 3..10, 10..12),
+            <WideDivisionLoop as NodePowerMethod<2>>::node_power(n, 3..10, 10..12)
         );
+
+        // Sorting with the fallback creates the same merge tree, with the same stable results
+        let mut rng = crate::test::test_rng();
+        for size in [0, 1, 63, 64, 65, 100, TEST_SIZE] {
+            let values: Vec<_> = crate::test::IndexedOrdered::map_iter(
+                (0..size).map(|_| rng.random_range(0..size / 4 + 1)),
+            )
+            .collect();
+            let sort = |sort: fn(&mut [crate::test::IndexedOrdered<usize>])| {
+                let mut values = values.clone();
+                sort(&mut values);
+                assert!(values.is_sorted());
+                values
+                    .iter()
+                    .map(crate::test::IndexedOrdered::index)
+                    .collect::<Vec<_>>()
+            };
+
+            assert_eq!(
+                sort(PowerSort::<TinyOrTrivial>::sort),
+                sort(PowerSort::<Trivial>::sort)
+            );
+            assert_eq!(
+                sort(MultiwayPowerSort::<TinyOrTrivial>::sort),
+                sort(MultiwayPowerSort::<Trivial>::sort)
+            );
+        }
     }
 
-    fn test_node_power_calculations<N: node_power::NodePowerMethod<K>, const K: usize>() {
+    /// Checks that `N` calculates the same node powers as [`node_power::Trivial`] for slices up
+    /// to `max_n` (or `N::MAX_N` if smaller), does nothing if `N` does not support `K`.
+    fn test_node_power_calculations<N: node_power::NodePowerMethod<K>, const K: usize>(
+        max_n: usize,
+    ) {
         use node_power::*;
 
+        /// The max `n` for which every pair of adjacent runs is checked
+        const EXHAUSTIVE_N: usize = 24;
+        /// The max `n` for which runs of length 1 are checked, `Trivial` loses accuracy beyond
+        const DEGENERATE_N: usize = 1 << 32;
+
+        if !N::SUPPORTS_K {
+            return;
+        }
+        let max_n = max_n.min(N::MAX_N);
+
+        let check = |n: usize, run_a: Run, run_b: Run| {
+            let correct_power =
+                <Trivial as NodePowerMethod<K>>::node_power(n, run_a.clone(), run_b.clone());
+            let test_power = N::node_power(n, run_a.clone(), run_b.clone());
+
+            assert_eq!(
+                correct_power,
+                test_power,
+                "Wrong node power of {run_a:?} and {run_b:?} with n = {n} and K = {K} for {}",
+                N::display()
+            );
+        };
+
+        for n in 2..=EXHAUSTIVE_N.min(max_n) {
+            for start in 0..n - 1 {
+                for middle in start + 1..n {
+                    for end in middle + 1..=n {
+                        check(n, start..middle, middle..end);
+                    }
+                }
+            }
+        }
+
         let mut rng = crate::test::test_rng();
 
+        // Adjacent runs of length 1 and runs at the very start and end of the slice
         for _ in 0..RUNS {
-            let n = rng.random_range(2..N::MAX_N);
+            let n = rng.random_range(3..=max_n.min(DEGENERATE_N));
+            let middle = rng.random_range(1..n);
+
+            check(n, 0..1, 1..2);
+            check(n, n - 2..n - 1, n - 1..n);
+            check(n, middle - 1..middle, middle..middle + 1);
+            check(n, 0..middle, middle..n);
+            check(n, 0..1, 1..n);
+            check(n, 0..n - 1, n - 1..n);
+        }
+
+        for _ in 0..RUNS {
+            let n = rng.random_range(2..max_n);
             let start = rng.random_range(0..(n - 2));
             let middle = rng.random_range(start + 1..n - 1);
             let end = rng.random_range(middle + 1..n);
 
-            let run_a = start..middle;
-            let run_b = middle..end;
-
-            let correct_power =
-                <Trivial as NodePowerMethod<K>>::node_power(n, run_a.clone(), run_b.clone());
-            let test_power = N::node_power(n, run_a, run_b);
-
-            assert_eq!(correct_power, test_power);
+            check(n, start..middle, middle..end);
         }
     }
 }
@@ -15,6 +15,9 @@ pub const DEFAULT_NINTHER_THRESHOLD: usize = 128;
 /// The default `CHECK_SORTED` to use.
 pub const DEFAULT_CHECK_SORTED: bool = false;
 
+/// The default [`PivotStrategy`] to use.
+pub type DefaultPivotStrategy = MedianOfThreeOrNinther;
+
 /// The Quicksort [`super::Sort`].
 ///
 /// - `R` is the [`super::RngFactory`]>
@@ -23,13 +26,20 @@ pub const DEFAULT_CHECK_SORTED: bool = false;
 /// - `NINTHER_THRESHOLD` determines the minimum length of a sub slice to use multiple median of
 ///   three pivot choices.
 /// - `CHECK_SORTED` indicates whether a slice a checked for pre-sortedness before performing work.
+/// - `P` is the [`PivotStrategy`] choosing the pivots, `NINTHER_THRESHOLD` only applies to
+///   [`MedianOfThreeOrNinther`].
 pub struct QuickSort<
     R: super::RngFactory = DefaultRngFactory,
     I: super::Sort = DefaultInsertionSort,
     const INSERTION_THRESHOLD: usize = DEFAULT_INSERTION_THRESHOLD,
     const NINTHER_THRESHOLD: usize = DEFAULT_NINTHER_THRESHOLD,
     const CHECK_SORTED: bool = DEFAULT_CHECK_SORTED,
->(std::marker::PhantomData<R>, std::marker::PhantomData<I>);
+    P: PivotStrategy = DefaultPivotStrategy,
+>(
+    std::marker::PhantomData<R>,
+    std::marker::PhantomData<I>,
+    std::marker::PhantomData<P>,
+);
 
 impl<
     R: super::RngFactory,
@@ -37,7 +47,8 @@ impl<
     const INSERTION_THRESHOLD: usize,
     const NINTHER_THRESHOLD: usize,
     const CHECK_SORTED: bool,
-> super::Sort for QuickSort<R, I, INSERTION_THRESHOLD, NINTHER_THRESHOLD, CHECK_SORTED>
+    P: PivotStrategy,
+> super::Sort for QuickSort<R, I, INSERTION_THRESHOLD, NINTHER_THRESHOLD, CHECK_SORTED, P>
 {
     const IS_STABLE: bool = false && I::IS_STABLE;
 
@@ -49,6 +60,7 @@ impl<
             ("i-threshold", INSERTION_THRESHOLD.to_string()),
             ("ninther-threshold", NINTHER_THRESHOLD.to_string()),
             ("check-sorted", CHECK_SORTED.to_string()),
+            ("pivot", P::display()),
         ]
         .into_iter()
     }
@@ -60,13 +72,30 @@ impl<
     }
 }
 
+impl<
+    R: super::RngFactory,
+    I: super::Sort,
+    const INSERTION_THRESHOLD: usize,
+    const NINTHER_THRESHOLD: usize,
+    const CHECK_SORTED: bool,
+    P: PivotStrategy,
+> super::PartialSort for QuickSort<R, I, INSERTION_THRESHOLD, NINTHER_THRESHOLD, CHECK_SORTED, P>
+{
+    fn sort_first_k<T: Ord>(slice: &mut [T], k: usize) {
+        let mut rng = R::produce();
+
+        Self::quickselect(slice, k, &mut rng);
+    }
+}
+
 impl<
     RF: super::RngFactory,
     I: super::Sort,
     const INSERTION_THRESHOLD: usize,
     const NINTHER_THRESHOLD: usize,
     const CHECK_SORTED: bool,
-> QuickSort<RF, I, INSERTION_THRESHOLD, NINTHER_THRESHOLD, CHECK_SORTED>
+    P: PivotStrategy,
+> QuickSort<RF, I, INSERTION_THRESHOLD, NINTHER_THRESHOLD, CHECK_SORTED, P>
 {
     /// Quicksort the given slice
     fn quicksort<T: Ord, R: rand::Rng>(slice: &mut [T], rng: &mut R) {
@@ -91,15 +120,56 @@ impl<
         // This helps with bounds check elimination I think
         assert!(slice.len() > INSERTION_THRESHOLD);
 
-        // Increase the likelihood of having a good pivot
-        Self::move_random_median_to_first(slice, rng);
-        if slice.len() >= NINTHER_THRESHOLD {
-            Self::move_random_median_to_first(&mut slice[1..], rng);
-            Self::move_random_median_to_first(&mut slice[2..], rng);
-            Self::move_median_to_first(slice, 0, 1, 2);
+        let pivot = Self::choose_pivot_and_partition(slice, rng);
+
+        // Recurse into both partitions
+        Self::quicksort(&mut slice[..pivot], rng);
+        Self::quicksort(&mut slice[pivot + 1..], rng);
+    }
+
+    /// Sorts the `k` smallest elements of the given slice into `slice[..k]`, only recursing into
+    /// the partitions overlapping the first `k` positions.
+    fn quickselect<T: Ord, R: rand::Rng>(slice: &mut [T], k: usize, rng: &mut R) {
+        if k == 0 {
+            return;
         }
 
-        // Classic quicksort partition with pivot at index 0
+        // Use insertion sort for small slices, this only sorts more than necessary
+        if slice.len() <= INSERTION_THRESHOLD {
+            I::sort(slice);
+            return;
+        }
+
+        let pivot = Self::choose_pivot_and_partition(slice, rng);
+
+        // The left partition holds the `pivot` smallest elements, so it either contains all `k`
+        // elements or has to be sorted completely
+        if k <= pivot {
+            Self::quickselect(&mut slice[..pivot], k, rng);
+        } else {
+            Self::quicksort(&mut slice[..pivot], rng);
+            Self::quickselect(&mut slice[pivot + 1..], k - pivot - 1, rng);
+        }
+    }
+
+    /// Moves the pivot chosen by `P` to the front of `slice` and partitions around it, returning
+    /// its final index, see [`Self::partition()`].
+    fn choose_pivot_and_partition<T: Ord, R: rand::Rng>(slice: &mut [T], rng: &mut R) -> usize {
+        P::move_pivot_to_first(slice, rng, NINTHER_THRESHOLD);
+
+        // Partition around the pivot at index 0
+        Self::partition(slice)
+    }
+
+    /// Partitions `slice` around the pivot `slice[0]` and returns the final index `p` of the
+    /// pivot, such that `slice[..p] <= slice[p] <= slice[p + 1..]`.
+    ///
+    /// Both scans stop at elements equal to the pivot, so many equal elements are split evenly.
+    fn partition<T: Ord>(slice: &mut [T]) -> usize {
+        assert!(!slice.is_empty(), "Partitioning needs a pivot");
+
+        // Classic Hoare partition, `slice[1..i]` holds elements less than or equal to the pivot
+        // and `slice[j + 1..]` elements greater than or equal to the pivot
         let mut i = 0;
         let mut j = slice.len();
         loop {
@@ -108,43 +178,177 @@ impl<
             while i < slice.len() && slice[i] < slice[0] {
                 i += 1;
             }
-            while slice[j] > slice[0] {
+            // The pivot itself stops this scan at the latest, the bound only makes that explicit
+            while j > 0 && slice[j] > slice[0] {
                 j -= 1;
             }
-            if j > i {
-                slice.swap(i, j);
-            } else {
+            if i >= j {
                 break;
             }
+            slice.swap(i, j);
         }
-        i -= 1;
 
-        // Swap the pivot into place
-        slice.swap(0, i);
+        // The scans crossed, so `slice[j]` is less than or equal to the pivot and everything
+        // after it greater than or equal
+        slice.swap(0, j);
 
-        // Recurse into both partitions
-        Self::quicksort(&mut slice[..i], rng);
-        Self::quicksort(&mut slice[i + 1..], rng);
+        j
     }
+}
 
-    /// Calls [`move_median_to_first()`] with three random indices
-    fn move_random_median_to_first<T: Ord, R: rand::Rng>(slice: &mut [T], rng: &mut R) {
-        Self::move_median_to_first(
-            slice,
-            rng.random_range(0..slice.len()),
-            rng.random_range(0..slice.len()),
-            rng.random_range(0..slice.len()),
-        );
+/// Chooses the pivot of each partitioning step of [`QuickSort`].
+pub trait PivotStrategy {
+    /// Returns the string representation of this strategy.
+    fn display() -> String;
+
+    /// Moves the chosen pivot of `slice` to `slice[0]`, `slice` holds at least 3 elements.
+    ///
+    /// `ninther_threshold` is the `NINTHER_THRESHOLD` of the [`QuickSort`].
+    fn move_pivot_to_first<T: Ord, R: rand::Rng>(
+        slice: &mut [T],
+        rng: &mut R,
+        ninther_threshold: usize,
+    );
+}
+
+/// A [`PivotStrategy`] taking the first element, degrading to quadratic running time on sorted
+/// input.
+#[derive(Debug, Clone, Copy)]
+pub struct FirstElement;
+
+impl PivotStrategy for FirstElement {
+    fn display() -> String {
+        "first".to_string()
+    }
+
+    fn move_pivot_to_first<T: Ord, R: rand::Rng>(
+        _slice: &mut [T],
+        _rng: &mut R,
+        _ninther_threshold: usize,
+    ) {
+    }
+}
+
+/// A [`PivotStrategy`] taking a single random element.
+#[derive(Debug, Clone, Copy)]
+pub struct RandomElement;
+
+impl PivotStrategy for RandomElement {
+    fn display() -> String {
+        "random".to_string()
     }
 
-    /// Swap the median of the three indices with the first element of the slice
-    fn move_median_to_first<T: Ord>(slice: &mut [T], index1: usize, index2: usize, index3: usize) {
-        let indices = &mut [index1, index2, index3];
-        indices.sort_by_key(|i| &slice[*i]);
-        slice.swap(0, indices[1]);
+    fn move_pivot_to_first<T: Ord, R: rand::Rng>(
+        slice: &mut [T],
+        rng: &mut R,
+        _ninther_threshold: usize,
+    ) {
+        slice.swap(0, rng.random_range(0..slice.len()));
     }
 }
 
+/// A [`PivotStrategy`] taking the median of three random elements.
+#[derive(Debug, Clone, Copy)]
+pub struct MedianOfThree;
+
+impl PivotStrategy for MedianOfThree {
+    fn display() -> String {
+        "median-of-three".to_string()
+    }
+
+    fn move_pivot_to_first<T: Ord, R: rand::Rng>(
+        slice: &mut [T],
+        rng: &mut R,
+        _ninther_threshold: usize,
+    ) {
+        move_random_median_to_first(slice, rng);
+    }
+}
+
+/// A [`PivotStrategy`] taking the ninther, the median of the medians of three times three random
+/// elements.
+#[derive(Debug, Clone, Copy)]
+pub struct Ninther;
+
+impl PivotStrategy for Ninther {
+    fn display() -> String {
+        "ninther".to_string()
+    }
+
+    fn move_pivot_to_first<T: Ord, R: rand::Rng>(
+        slice: &mut [T],
+        rng: &mut R,
+        _ninther_threshold: usize,
+    ) {
+        move_random_median_to_first(slice, rng);
+        move_random_median_to_first(&mut slice[1..], rng);
+        move_random_median_to_first(&mut slice[2..], rng);
+        move_median_to_first(slice, 0, 1, 2);
+    }
+}
+
+/// A [`PivotStrategy`] taking the [`MedianOfThree`] for slices shorter than the ninther threshold
+/// and the [`Ninther`] otherwise.
+#[derive(Debug, Clone, Copy)]
+pub struct MedianOfThreeOrNinther;
+
+impl PivotStrategy for MedianOfThreeOrNinther {
+    fn display() -> String {
+        "median-of-three-or-ninther".to_string()
+    }
+
+    fn move_pivot_to_first<T: Ord, R: rand::Rng>(
+        slice: &mut [T],
+        rng: &mut R,
+        ninther_threshold: usize,
+    ) {
+        // Increase the likelihood of having a good pivot
+        if slice.len() >= ninther_threshold {
+            Ninther::move_pivot_to_first(slice, rng, ninther_threshold);
+        } else {
+            MedianOfThree::move_pivot_to_first(slice, rng, ninther_threshold);
+        }
+    }
+}
+
+/// A [`PivotStrategy`] taking the exact median, found by selection in linear time. Partitioning
+/// is perfectly balanced, at the cost of the additional selection in every step.
+#[derive(Debug, Clone, Copy)]
+pub struct Median;
+
+impl PivotStrategy for Median {
+    fn display() -> String {
+        "median".to_string()
+    }
+
+    fn move_pivot_to_first<T: Ord, R: rand::Rng>(
+        slice: &mut [T],
+        _rng: &mut R,
+        _ninther_threshold: usize,
+    ) {
+        let middle = slice.len() / 2;
+        slice.select_nth_unstable(middle);
+        slice.swap(0, middle);
+    }
+}
+
+/// Calls [`move_median_to_first()`] with three random indices
+fn move_random_median_to_first<T: Ord, R: rand::Rng>(slice: &mut [T], rng: &mut R) {
+    move_median_to_first(
+        slice,
+        rng.random_range(0..slice.len()),
+        rng.random_range(0..slice.len()),
+        rng.random_range(0..slice.len()),
+    );
+}
+
+/// Swap the median of the three indices with the first element of the slice
+fn move_median_to_first<T: Ord>(slice: &mut [T], index1: usize, index2: usize, index3: usize) {
+    let indices = &mut [index1, index2, index3];
+    indices.sort_by_key(|i| &slice[*i]);
+    slice.swap(0, indices[1]);
+}
+
 #[cfg(test)]
 mod tests {
     use crate::generate_test_suite;
@@ -166,4 +370,206 @@ mod tests {
         QuickSort,
         QuickSortChecked,
     }
+
+    /// A [`QuickSort`] with default parameters, choosing pivots by `P`.
+    type QuickSortPivot<P> = QuickSort<
+        DefaultRngFactory,
+        DefaultInsertionSort,
+        DEFAULT_INSERTION_THRESHOLD,
+        DEFAULT_NINTHER_THRESHOLD,
+        DEFAULT_CHECK_SORTED,
+        P,
+    >;
+
+    mod pivot_strategies {
+        use crate::generate_test_suite;
+
+        use super::*;
+
+        // Smaller, since the first element pivot is quadratic on the (partially) sorted slices
+        generate_test_suite! {
+            TEST_SIZE: 1_000;
+            TEST_RUNS: 10;
+
+            QuickSortPivot<FirstElement>,
+            QuickSortPivot<RandomElement>,
+            QuickSortPivot<MedianOfThree>,
+            QuickSortPivot<Ninther>,
+            QuickSortPivot<Median>,
+        }
+    }
+
+    /// Returns the number of comparisons needed by quicksort with `P` to sort `values`.
+    fn pivot_comparisons<P: PivotStrategy>(values: &[usize]) -> u64 {
+        use crate::algorithms::Sort as _;
+
+        let mut values: Vec<_> = values
+            .iter()
+            .copied()
+            .map(crate::test::CountingOrdered)
+            .collect();
+        let comparisons = crate::test::count_comparisons(|| QuickSortPivot::<P>::sort(&mut values));
+        assert!(values.is_sorted(), "{} did not sort", P::display());
+
+        comparisons
+    }
+
+    #[test]
+    fn test_first_element_pivot_quadratic() {
+        const SIZE: u64 = 2_000;
+
+        let sorted: Vec<usize> = (0..usize::try_from(SIZE).unwrap()).collect();
+
+        // Every partitioning step only splits off the pivot
+        let comparisons = pivot_comparisons::<FirstElement>(&sorted);
+        assert!(
+            comparisons >= SIZE * SIZE / 4,
+            "Only {comparisons} comparisons"
+        );
+
+        for comparisons in [
+            pivot_comparisons::<RandomElement>(&sorted),
+            pivot_comparisons::<MedianOfThree>(&sorted),
+            pivot_comparisons::<Ninther>(&sorted),
+            pivot_comparisons::<Median>(&sorted),
+        ] {
+            assert!(comparisons < 4 * SIZE * u64::from(SIZE.ilog2()));
+        }
+    }
+
+    #[test]
+    fn test_median_of_three_killer() {
+        const SIZE: usize = 10_000;
+
+        // Musser's median-of-3 killer, making the median of the first, middle and last element
+        // the second smallest element in every step
+        let k = SIZE / 2;
+        let mut killer = vec![0; SIZE];
+        for i in 1..=k {
+            if i % 2 == 1 {
+                killer[i - 1] = i;
+                killer[i] = k + i;
+            }
+            killer[k + i - 1] = 2 * i;
+        }
+        let mut sorted = killer.clone();
+        sorted.sort();
+        assert!(sorted.iter().copied().eq(1..=SIZE));
+
+        // Random samples are not fooled by the sequence
+        let bound = 3 * u64::try_from(SIZE).unwrap() * u64::from(SIZE.ilog2());
+        for comparisons in [
+            pivot_comparisons::<MedianOfThree>(&killer),
+            pivot_comparisons::<Ninther>(&killer),
+            pivot_comparisons::<MedianOfThreeOrNinther>(&killer),
+        ] {
+            assert!(
+                comparisons < bound,
+                "{comparisons} comparisons exceed {bound}"
+            );
+        }
+    }
+
+    /// Partitions `values` with [`QuickSort::partition()`] and checks the result.
+    fn assert_partitioned(mut values: Vec<usize>) -> usize {
+        let pivot_value = values[0];
+        let mut expected = values.clone();
+        expected.sort();
+
+        let pivot = QuickSort::<DefaultRngFactory>::partition(&mut values);
+
+        assert_eq!(values[pivot], pivot_value);
+        assert!(values[..pivot].iter().all(|&value| value <= pivot_value));
+        assert!(
+            values[pivot + 1..]
+                .iter()
+                .all(|&value| value >= pivot_value)
+        );
+
+        values.sort();
+        assert_eq!(values, expected, "Partitioning lost elements");
+
+        pivot
+    }
+
+    #[test]
+    fn test_partition_all_equal() {
+        for len in 1..100 {
+            let pivot = assert_partitioned(vec![7; len]);
+
+            // Equal elements are split evenly instead of ending up on one side
+            assert!(pivot.abs_diff((len - 1) / 2) <= 1, "Pivot {pivot} of {len}");
+        }
+
+        let mut values = vec![0; crate::test::DEFAULT_TEST_SIZE];
+        <QuickSort as crate::algorithms::Sort>::sort(&mut values);
+        assert!(values.iter().all(|&value| value == 0));
+    }
+
+    #[test]
+    fn test_partition_pivot_extremes() {
+        use rand::seq::SliceRandom as _;
+
+        let mut rng = crate::test::test_rng();
+
+        for len in 1..100 {
+            let mut values: Vec<usize> = (1..len).collect();
+            values.shuffle(&mut rng);
+
+            // Pivot is the minimum, all other elements are greater
+            values.insert(0, 0);
+            assert_eq!(assert_partitioned(values.clone()), 0);
+
+            // Pivot is the maximum, all other elements are less
+            values[0] = len;
+            assert_eq!(assert_partitioned(values.clone()), len - 1);
+        }
+    }
+
+    #[test]
+    fn test_sort_first_k() {
+        use rand::Rng as _;
+
+        use crate::algorithms::PartialSort as _;
+
+        let mut rng = crate::test::test_rng();
+
+        for _ in 0..crate::test::DEFAULT_TEST_RUNS {
+            let len = rng.random_range(0..1_000);
+            let mut values: Vec<usize> =
+                (0..len).map(|_| rng.random_range(0..len / 4 + 1)).collect();
+            let mut expected = values.clone();
+            expected.sort();
+
+            // Include the edge cases of nothing and everything to sort
+            let k = match rng.random_range(0..4) {
+                0 => 0,
+                1 => len,
+                _ => rng.random_range(0..=len),
+            };
+            QuickSort::<DefaultRngFactory>::sort_first_k(&mut values, k);
+
+            assert!(values[..k].is_sorted(), "Prefix of length {k} not sorted");
+            if let (Some(max), Some(min)) = (values[..k].iter().max(), values[k..].iter().min()) {
+                assert!(max <= min, "Prefix of length {k} not the smallest elements");
+            }
+            assert_eq!(values[..k], expected[..k]);
+
+            values.sort();
+            assert_eq!(values, expected, "Partial sorting lost elements");
+        }
+    }
+
+    #[test]
+    fn test_partition_random() {
+        use rand::Rng as _;
+
+        let mut rng = crate::test::test_rng();
+
+        for _ in 0..crate::test::DEFAULT_TEST_RUNS {
+            let len = rng.random_range(1..1_000);
+            let values = (0..len).map(|_| rng.random_range(0..len / 4 + 1)).collect();
+            assert_partitioned(values);
+        }
+    }
 }
@@ -11,20 +11,48 @@ pub type DefaultMergingMethod = super::merging::two_way::Galloping;
 /// The default [`super::BufGuardFactory`] to use.
 pub type DefaultBufGuardFactory = super::DefaultBufGuardFactory;
 
+/// The default [`super::merging::MultiMergingMethod`] to use.
+pub type DefaultMultiMergingMethod = super::merging::multi_way::TournamentTree;
+
 /// The default `MIN_MERGE` to use.
 pub const DEFAULT_MIN_MERGE: usize = 32;
 
+/// The default `MERGE_K_RUNS` to use.
+pub const DEFAULT_MERGE_K_RUNS: usize = 4;
+
+/// The default `ONLY_INCREASING_RUNS` to use.
+pub const DEFAULT_ONLY_INCREASING_RUNS: bool = false;
+
+/// The default `PERSISTENT_MERGE_STATE` to use.
+pub const DEFAULT_PERSISTENT_MERGE_STATE: bool = true;
+
+/// Trotsort, i.e. [`TimSort`] without galloping, merging with
+/// [`super::merging::two_way::CopyBoth`] instead.
+///
+/// Isolates the effect of galloping on Timsort, the run detection and merge policy are the same.
+/// Since `CopyBoth` has no state, `PERSISTENT_MERGE_STATE` has no effect.
+pub type TrotSort<I = DefaultInsertionSort, B = DefaultBufGuardFactory> =
+    TimSort<I, super::merging::two_way::CopyBoth, B>;
+
 /// The Timsort [`super::Sort`].
 ///
 /// - `I` is the insertion sort used for small slices.
 /// - `M` is the [`super::merging::MergingMethod`] used to merge slices.
 /// - `B` is the [`super::BufGuardFactory`] used to create the merging buffer.
-/// - `MIN_MERGE` determines the maximum slice length threshold to be sorted with `I`.
+/// - `MIN_MERGE` determines the maximum slice length threshold to be sorted with `I`, at most
+///   [`super::powersort::MAX_MIN_RUN_LENGTH`].
+/// - `ONLY_INCREASING_RUNS` indicates whether only preexisting weakly increasing runs are used,
+///   i.e. strictly decreasing runs are never detected and reversed.
+/// - `PERSISTENT_MERGE_STATE` indicates whether the state of `M` is carried over between merges,
+///   e.g. the adapted `min_gallop` of [`super::merging::two_way::Galloping`] like in CPython and
+///   the JDK, see [`super::merging::MergingMethod::merge_with_state()`].
 pub struct TimSort<
     I: super::PostfixSort = DefaultInsertionSort,
     M: super::merging::MergingMethod = DefaultMergingMethod,
     B: super::BufGuardFactory = DefaultBufGuardFactory,
     const MIN_MERGE: usize = DEFAULT_MIN_MERGE,
+    const ONLY_INCREASING_RUNS: bool = DEFAULT_ONLY_INCREASING_RUNS,
+    const PERSISTENT_MERGE_STATE: bool = DEFAULT_PERSISTENT_MERGE_STATE,
 >(
     std::marker::PhantomData<I>,
     std::marker::PhantomData<M>,
@@ -36,7 +64,9 @@ impl<
     M: super::merging::MergingMethod,
     B: super::BufGuardFactory,
     const MIN_MERGE: usize,
-> super::Sort for TimSort<I, M, B, MIN_MERGE>
+    const ONLY_INCREASING_RUNS: bool,
+    const PERSISTENT_MERGE_STATE: bool,
+> super::Sort for TimSort<I, M, B, MIN_MERGE, ONLY_INCREASING_RUNS, PERSISTENT_MERGE_STATE>
 {
     const IS_STABLE: bool = I::IS_STABLE && M::IS_STABLE;
 
@@ -47,11 +77,32 @@ impl<
             ("i-sort", crate::cli::display_inline::<I>()),
             ("merging", M::display()),
             ("min-merge", MIN_MERGE.to_string()),
+            ("only-increasing", ONLY_INCREASING_RUNS.to_string()),
+            ("persistent-state", PERSISTENT_MERGE_STATE.to_string()),
         ]
         .into_iter()
     }
 
+    fn merging() -> Option<String> {
+        Some(crate::cli::display_merging::<M, 2>())
+    }
+
+    fn buffer_bytes<T>(len: usize) -> usize {
+        if len < 2 {
+            return 0;
+        }
+
+        len * std::mem::size_of::<T>()
+    }
+
     fn sort<T: Ord>(slice: &mut [T]) {
+        const {
+            assert!(
+                MIN_MERGE <= super::powersort::MAX_MIN_RUN_LENGTH,
+                "MIN_MERGE must be at most MAX_MIN_RUN_LENGTH"
+            )
+        };
+
         if slice.len() < 2 {
             return;
         }
@@ -62,6 +113,11 @@ impl<
         // Delegate to helper function
         Self::timsort(slice, buffer.as_uninit_slice_mut());
     }
+
+    fn sort_descending<T: Ord>(slice: &mut [T]) {
+        // Sorting with the reversed ordering keeps equal elements in their initial order
+        Self::sort(super::Descending::from_mut_slice(slice));
+    }
 }
 
 /// A single continuous run starting at `start` followed by `len` weakly increasing elements.
@@ -78,12 +134,18 @@ impl<
     M: super::merging::MergingMethod,
     B: super::BufGuardFactory,
     const MIN_MERGE: usize,
-> TimSort<I, M, B, MIN_MERGE>
+    const ONLY_INCREASING_RUNS: bool,
+    const PERSISTENT_MERGE_STATE: bool,
+> TimSort<I, M, B, MIN_MERGE, ONLY_INCREASING_RUNS, PERSISTENT_MERGE_STATE>
 {
     /// The actual Timsort implementation.
     fn timsort<T: Ord>(slice: &mut [T], buffer: &mut [std::mem::MaybeUninit<T>]) {
         if slice.len() < MIN_MERGE {
-            let split_point = Self::count_run_and_make_ascending(slice);
+            let split_point = count_run_and_make_ascending::<_, ONLY_INCREASING_RUNS>(slice);
+
+            #[cfg(feature = "instrument")]
+            crate::instrument::on_run(0, split_point);
+
             I::sort_with_sorted_prefix(slice, split_point);
             return;
         }
@@ -91,8 +153,11 @@ impl<
         // Stack of pending runs
         let mut pending_runs: Vec<Run> = vec![];
 
+        // The state carried over between merges, see `PERSISTENT_MERGE_STATE`
+        let mut state = super::merging::two_way::MergeState::default();
+
         // Calculate the minimum run length to use for merging
-        let min_run_length = Self::min_run_length(slice.len());
+        let min_run_length = min_run_length(slice.len(), MIN_MERGE);
 
         // Tracking remaining length seems to optimize well
         let mut start = 0;
@@ -100,7 +165,11 @@ impl<
 
         while start < slice.len() {
             // Find the current run length
-            let mut run_length = Self::count_run_and_make_ascending(&mut slice[start..]);
+            let mut run_length =
+                count_run_and_make_ascending::<_, ONLY_INCREASING_RUNS>(&mut slice[start..]);
+
+            #[cfg(feature = "instrument")]
+            crate::instrument::on_run(start, run_length);
 
             // Make sure we have at least run length `min_run_length`
             if run_length < min_run_length {
@@ -118,75 +187,38 @@ impl<
                 len: run_length,
             });
 
+            #[cfg(feature = "counters")]
+            crate::GLOBAL_COUNTERS.max_stack_height.increase_to(
+                u64::try_from(pending_runs.len()).expect("Stack height should fit into u64"),
+            );
+
             // Merge top runs according to Timsort rules
-            Self::merge_collapse(slice, buffer, &mut pending_runs);
+            Self::merge_collapse(slice, buffer, &mut pending_runs, &mut state);
+            validate_assert!(
+                invariants_hold(&pending_runs),
+                "Timsort invariants violated by {pending_runs:?}"
+            );
 
             start += run_length;
             remaining_length -= run_length;
         }
 
         // Merge the rest of the runs
-        Self::merge_force_collapse(slice, buffer, &mut pending_runs);
+        Self::merge_force_collapse(slice, buffer, &mut pending_runs, &mut state);
 
         debug_assert!(pending_runs.len() == 1, "There should only be one run left");
     }
 
-    /// Find the first index `i`, such that `slice[..i]` is weakly increasing.
-    ///
-    /// If `slice` starts with a strictly decreasing run `slice[..i]`, it will be reversed and `i`
-    /// will be returned.
-    fn count_run_and_make_ascending<T: Ord>(slice: &mut [T]) -> usize {
-        if slice.len() < 2 {
-            return slice.len();
-        }
-
-        if slice[0] > slice[1] {
-            let run_end = super::merging::util::strictly_decreasing_prefix_index(slice);
-
-            slice[..run_end].reverse();
-
-            run_end
-        } else {
-            super::merging::util::weakly_increasing_prefix_index(slice)
-        }
-    }
-
-    /// Calculates the minimum run length for a given `n`.
-    fn min_run_length(mut n: usize) -> usize {
-        let mut r = 0;
-        while n >= MIN_MERGE {
-            r |= n & 1;
-            n >>= 1;
-        }
-        n + r
-    }
-
-    /// Merges runs from the top of the stack to uphold the following invariants:
-    ///
-    /// - `pending_runs[top].len > pending_runs[top - 1].len + pending_runs[top - 2].len`
-    /// - `pending_runs[top - 1].len > pending_runs[top - 2].len`
+    /// Merges runs from the top of the stack until the invariants of [`invariants_hold()`] hold
+    /// again, see [`collapse_index()`].
     fn merge_collapse<T: Ord>(
         slice: &mut [T],
         buffer: &mut [std::mem::MaybeUninit<T>],
         pending_runs: &mut Vec<Run>,
+        state: &mut super::merging::two_way::MergeState,
     ) {
-        while pending_runs.len() > 1 {
-            let mut n = pending_runs.len() - 2;
-
-            if (n > 0 && pending_runs[n - 1].len <= pending_runs[n].len + pending_runs[n + 1].len)
-                || (n > 1
-                    && pending_runs[n - 2].len <= pending_runs[n - 1].len + pending_runs[n].len)
-            {
-                if pending_runs[n - 1].len < pending_runs[n + 1].len {
-                    n -= 1;
-                }
-
-                Self::merge_at(slice, buffer, pending_runs, n);
-            } else if pending_runs[n].len <= pending_runs[n + 1].len {
-                Self::merge_at(slice, buffer, pending_runs, n);
-            } else {
-                break;
-            }
+        while let Some(index) = collapse_index(pending_runs) {
+            Self::merge_at(slice, buffer, pending_runs, state, index);
         }
     }
 
@@ -195,6 +227,7 @@ impl<
         slice: &mut [T],
         buffer: &mut [std::mem::MaybeUninit<T>],
         pending_runs: &mut Vec<Run>,
+        state: &mut super::merging::two_way::MergeState,
     ) {
         while pending_runs.len() > 1 {
             let mut n = pending_runs.len() - 2;
@@ -203,7 +236,7 @@ impl<
                 n -= 1;
             }
 
-            Self::merge_at(slice, buffer, pending_runs, n);
+            Self::merge_at(slice, buffer, pending_runs, state, n);
         }
     }
 
@@ -216,6 +249,7 @@ impl<
         slice: &mut [T],
         buffer: &mut [std::mem::MaybeUninit<T>],
         pending_runs: &mut Vec<Run>,
+        state: &mut super::merging::two_way::MergeState,
         index: usize,
     ) {
         // Check we are merging the last or second to last element
@@ -237,14 +271,363 @@ impl<
         pending_runs.pop();
 
         // Merge the actual runs
+        super::merging::record_merge();
+        let merge_slice = &mut slice[run1.start..run1.start + run1.len + run2.len];
+        if PERSISTENT_MERGE_STATE {
+            M::merge_with_state(merge_slice, run1.len, buffer, state);
+        } else {
+            M::merge(merge_slice, run1.len, buffer);
+        }
+    }
+}
+
+/// Returns whether the lengths of the `pending_runs` satisfy the Timsort invariants, i.e. for
+/// all `i`
+///
+/// - `pending_runs[i].len > pending_runs[i + 1].len + pending_runs[i + 2].len`
+/// - `pending_runs[i].len > pending_runs[i + 1].len`
+fn invariants_hold(pending_runs: &[Run]) -> bool {
+    pending_runs
+        .windows(2)
+        .all(|runs| runs[0].len > runs[1].len)
+        && pending_runs
+            .windows(3)
+            .all(|runs| runs[0].len > runs[1].len + runs[2].len)
+}
+
+/// Returns the index `n` such that `pending_runs[n]` and `pending_runs[n + 1]` are merged next
+/// by [`TimSort::merge_collapse()`], or `None` if no merge is necessary.
+///
+/// Assuming the invariants of [`invariants_hold()`] held before the last run was pushed, they
+/// hold again once this returns `None`. Unlike the original Timsort, this checks the top four
+/// runs instead of only the top three, since merging may otherwise break the invariants deeper
+/// in the stack, as found by de Gouw et al. in the CPython and JDK implementations.
+fn collapse_index(pending_runs: &[Run]) -> Option<usize> {
+    if pending_runs.len() < 2 {
+        return None;
+    }
+
+    let n = pending_runs.len() - 2;
+
+    if (n > 0 && pending_runs[n - 1].len <= pending_runs[n].len + pending_runs[n + 1].len)
+        || (n > 1 && pending_runs[n - 2].len <= pending_runs[n - 1].len + pending_runs[n].len)
+    {
+        // Merge the smaller one of the outer runs with the middle one
+        if pending_runs[n - 1].len < pending_runs[n + 1].len {
+            Some(n - 1)
+        } else {
+            Some(n)
+        }
+    } else if pending_runs[n].len <= pending_runs[n + 1].len {
+        Some(n)
+    } else {
+        None
+    }
+}
+
+/// Find the first index `i`, such that `slice[..i]` is weakly increasing.
+///
+/// If `ONLY_INCREASING_RUNS` is `false`, and `slice` starts with a strictly decreasing run
+/// `slice[..i]`, it will be reversed and `i` will be returned.
+fn count_run_and_make_ascending<T: Ord, const ONLY_INCREASING_RUNS: bool>(
+    slice: &mut [T],
+) -> usize {
+    if slice.len() < 2 {
+        return slice.len();
+    }
+
+    if !ONLY_INCREASING_RUNS && slice[0] > slice[1] {
+        let run_end = super::merging::util::strictly_decreasing_prefix_index(slice);
+
+        slice[..run_end].reverse();
+
+        run_end
+    } else {
+        super::merging::util::weakly_increasing_prefix_index(slice)
+    }
+}
+
+/// The Multiway Timsort [`super::Sort`], merging up to `MERGE_K_RUNS` pending runs at once.
+///
+/// - `I` is the insertion sort used for small slices.
+/// - `M` is the [`super::merging::MultiMergingMethod`] used to merge slices.
+/// - `B` is the [`super::BufGuardFactory`] used to create the merging buffer.
+/// - `MERGE_K_RUNS` determines how many runs are merged together at most.
+/// - `MIN_MERGE` determines the maximum slice length threshold to be sorted with `I`, at most
+///   [`super::powersort::MAX_MIN_RUN_LENGTH`].
+/// - `ONLY_INCREASING_RUNS` indicates whether only preexisting weakly increasing runs are used,
+///   i.e. strictly decreasing runs are never detected and reversed.
+///
+/// For `MERGE_K_RUNS = 2` this merges exactly like [`TimSort`].
+pub struct MultiwayTimSort<
+    I: super::PostfixSort = DefaultInsertionSort,
+    M: super::merging::MultiMergingMethod<MERGE_K_RUNS> = DefaultMultiMergingMethod,
+    B: super::BufGuardFactory = DefaultBufGuardFactory,
+    const MERGE_K_RUNS: usize = DEFAULT_MERGE_K_RUNS,
+    const MIN_MERGE: usize = DEFAULT_MIN_MERGE,
+    const ONLY_INCREASING_RUNS: bool = DEFAULT_ONLY_INCREASING_RUNS,
+>(
+    std::marker::PhantomData<I>,
+    std::marker::PhantomData<M>,
+    std::marker::PhantomData<B>,
+);
+
+impl<
+    I: super::PostfixSort,
+    M: super::merging::MultiMergingMethod<MERGE_K_RUNS>,
+    B: super::BufGuardFactory,
+    const MERGE_K_RUNS: usize,
+    const MIN_MERGE: usize,
+    const ONLY_INCREASING_RUNS: bool,
+> super::Sort for MultiwayTimSort<I, M, B, MERGE_K_RUNS, MIN_MERGE, ONLY_INCREASING_RUNS>
+{
+    const IS_STABLE: bool = I::IS_STABLE && M::IS_STABLE;
+
+    const BASE_NAME: &str = "multiway-timsort";
+
+    fn parameters() -> impl Iterator<Item = (&'static str, String)> {
+        vec![
+            ("K", MERGE_K_RUNS.to_string()),
+            ("i-sort", crate::cli::display_inline::<I>()),
+            ("merging", M::display()),
+            ("min-merge", MIN_MERGE.to_string()),
+            ("only-increasing", ONLY_INCREASING_RUNS.to_string()),
+        ]
+        .into_iter()
+    }
+
+    fn merging() -> Option<String> {
+        Some(crate::cli::display_merging::<M, MERGE_K_RUNS>())
+    }
+
+    fn buffer_bytes<T>(len: usize) -> usize {
+        if len < 2 {
+            return 0;
+        }
+
+        M::required_capacity(len) * std::mem::size_of::<T>()
+    }
+
+    fn sort<T: Ord>(slice: &mut [T]) {
+        const {
+            assert!(
+                MIN_MERGE <= super::powersort::MAX_MIN_RUN_LENGTH,
+                "MIN_MERGE must be at most MAX_MIN_RUN_LENGTH"
+            )
+        };
+
+        if slice.len() < 2 {
+            return;
+        }
+
+        // Conservatively initiate a buffer big enough to merge the complete array
+        let mut buffer = B::Guard::with_capacity(M::required_capacity(slice.len()));
+
+        // Delegate to helper function
+        Self::multiway_timsort(slice, buffer.as_uninit_slice_mut());
+    }
+}
+
+impl<
+    I: super::PostfixSort,
+    M: super::merging::MultiMergingMethod<MERGE_K_RUNS>,
+    B: super::BufGuardFactory,
+    const MERGE_K_RUNS: usize,
+    const MIN_MERGE: usize,
+    const ONLY_INCREASING_RUNS: bool,
+> MultiwayTimSort<I, M, B, MERGE_K_RUNS, MIN_MERGE, ONLY_INCREASING_RUNS>
+{
+    /// The actual Multiway Timsort implementation.
+    fn multiway_timsort<T: Ord>(slice: &mut [T], buffer: &mut [std::mem::MaybeUninit<T>]) {
+        assert!(
+            MERGE_K_RUNS >= 2,
+            "At least two runs have to be merged at once"
+        );
+
+        if slice.len() < MIN_MERGE {
+            let split_point = count_run_and_make_ascending::<_, ONLY_INCREASING_RUNS>(slice);
+
+            #[cfg(feature = "instrument")]
+            crate::instrument::on_run(0, split_point);
+
+            I::sort_with_sorted_prefix(slice, split_point);
+            return;
+        }
+
+        // Stack of pending runs
+        let mut pending_runs: Vec<Run> = vec![];
+
+        // Calculate the minimum run length to use for merging
+        let min_run_length = min_run_length(slice.len(), MIN_MERGE);
+
+        let mut start = 0;
+
+        while start < slice.len() {
+            // Find the current run length
+            let mut run_length =
+                count_run_and_make_ascending::<_, ONLY_INCREASING_RUNS>(&mut slice[start..]);
+
+            #[cfg(feature = "instrument")]
+            crate::instrument::on_run(start, run_length);
+
+            // Make sure we have at least run length `min_run_length`
+            if run_length < min_run_length {
+                let forced_run_length = std::cmp::min(slice.len() - start, min_run_length);
+                I::sort_with_sorted_prefix(
+                    &mut slice[start..start + forced_run_length],
+                    run_length,
+                );
+                run_length = forced_run_length;
+            }
+
+            // Add current run to stack
+            pending_runs.push(Run {
+                start,
+                len: run_length,
+            });
+
+            #[cfg(feature = "counters")]
+            crate::GLOBAL_COUNTERS.max_stack_height.increase_to(
+                u64::try_from(pending_runs.len()).expect("Stack height should fit into u64"),
+            );
+
+            // Merge top runs according to the generalized Timsort rules
+            Self::merge_collapse(slice, buffer, &mut pending_runs);
+
+            start += run_length;
+        }
+
+        // Merge the rest of the runs
+        Self::merge_force_collapse(slice, buffer, &mut pending_runs);
+
+        debug_assert!(pending_runs.len() == 1, "There should only be one run left");
+    }
+
+    /// Returns the summed length of `runs`.
+    fn total_len(runs: &[Run]) -> usize {
+        runs.iter().map(|run| run.len).sum()
+    }
+
+    /// Returns the start index of the `MERGE_K_RUNS` runs to merge, when the invariants are
+    /// violated and there are at least `MERGE_K_RUNS + 1` runs on the stack.
+    ///
+    /// Like Timsort, this leaves out the topmost run, if it is larger than the lowest run of the
+    /// top `MERGE_K_RUNS + 1` runs.
+    fn merge_index(pending_runs: &[Run]) -> usize {
+        let n = pending_runs.len();
+        debug_assert!(n > MERGE_K_RUNS);
+
+        if pending_runs[n - MERGE_K_RUNS - 1].len < pending_runs[n - 1].len {
+            n - MERGE_K_RUNS - 1
+        } else {
+            n - MERGE_K_RUNS
+        }
+    }
+
+    /// Merges runs from the top of the stack to uphold the following invariants, generalizing
+    /// the ones of [`TimSort`]:
+    ///
+    /// - `pending_runs[i].len > pending_runs[i + 1].len + ... + pending_runs[i + K].len`
+    /// - `pending_runs[top - 1].len > pending_runs[top].len`
+    fn merge_collapse<T: Ord>(
+        slice: &mut [T],
+        buffer: &mut [std::mem::MaybeUninit<T>],
+        pending_runs: &mut Vec<Run>,
+    ) {
+        while pending_runs.len() > 1 {
+            let n = pending_runs.len();
+
+            if (n > MERGE_K_RUNS
+                && pending_runs[n - MERGE_K_RUNS - 1].len
+                    <= Self::total_len(&pending_runs[n - MERGE_K_RUNS..]))
+                || (n > MERGE_K_RUNS + 1
+                    && pending_runs[n - MERGE_K_RUNS - 2].len
+                        <= Self::total_len(&pending_runs[n - MERGE_K_RUNS - 1..n - 1]))
+            {
+                let index = Self::merge_index(pending_runs);
+                Self::merge_at(slice, buffer, pending_runs, index, MERGE_K_RUNS);
+            } else if pending_runs[n - 2].len <= pending_runs[n - 1].len {
+                let count = std::cmp::min(n, MERGE_K_RUNS);
+                Self::merge_at(slice, buffer, pending_runs, n - count, count);
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Merges runs from the top of the `pending_runs` stack, until there is only one left.
+    fn merge_force_collapse<T: Ord>(
+        slice: &mut [T],
+        buffer: &mut [std::mem::MaybeUninit<T>],
+        pending_runs: &mut Vec<Run>,
+    ) {
+        while pending_runs.len() > 1 {
+            let n = pending_runs.len();
+
+            if n > MERGE_K_RUNS {
+                let index = Self::merge_index(pending_runs);
+                Self::merge_at(slice, buffer, pending_runs, index, MERGE_K_RUNS);
+            } else {
+                Self::merge_at(slice, buffer, pending_runs, 0, n);
+            }
+        }
+    }
+
+    /// Merge the `count` runs `pending_runs[index..index + count]`.
+    ///
+    /// # Panics
+    ///
+    /// If the merged runs do not include the last or second to last element of `pending_runs`.
+    fn merge_at<T: Ord>(
+        slice: &mut [T],
+        buffer: &mut [std::mem::MaybeUninit<T>],
+        pending_runs: &mut Vec<Run>,
+        index: usize,
+        count: usize,
+    ) {
+        // Check we are merging the top runs, potentially leaving out the last one
+        let stack_size = pending_runs.len();
+        assert!((2..=MERGE_K_RUNS).contains(&count));
+        assert!(index + count == stack_size || index + count == stack_size - 1);
+
+        // Collect the lengths of the runs, the last one is implied by the merged slice
+        let mut run_lengths = [0; MERGE_K_RUNS];
+        for (length, run) in run_lengths
+            .iter_mut()
+            .zip(&pending_runs[index..index + count])
+        {
+            assert!(run.len > 0);
+            *length = run.len;
+        }
+
+        // Merge the run markers
+        let merged = Run {
+            start: pending_runs[index].start,
+            len: Self::total_len(&pending_runs[index..index + count]),
+        };
+        pending_runs.splice(index..index + count, [merged]);
+
+        // Merge the actual runs
+        super::merging::record_merge();
         M::merge(
-            &mut slice[run1.start..run1.start + run1.len + run2.len],
-            run1.len,
+            &mut slice[merged.start..merged.start + merged.len],
+            &run_lengths[..count - 1],
             buffer,
         );
     }
 }
 
+/// Calculates the minimum run length for a given `n`, such that it lies between `min_merge / 2`
+/// and `min_merge` and `n` divided by it is close to, but not more than a power of two.
+pub fn min_run_length(mut n: usize, min_merge: usize) -> usize {
+    let mut r = 0;
+    while n >= min_merge {
+        r |= n & 1;
+        n >>= 1;
+    }
+    n + r
+}
+
 #[cfg(test)]
 mod tests {
     use crate::generate_test_suite;
@@ -256,5 +639,272 @@ mod tests {
         TEST_RUNS: crate::test::DEFAULT_TEST_RUNS;
 
         TimSort,
+        TrotSort,
+        MultiwayTimSort<DefaultInsertionSort, DefaultMultiMergingMethod, DefaultBufGuardFactory, 2>,
+        MultiwayTimSort<DefaultInsertionSort, DefaultMultiMergingMethod, DefaultBufGuardFactory, 3>,
+        MultiwayTimSort,
+        MultiwayTimSort<DefaultInsertionSort, super::super::merging::multi_way::Fourway>,
+        TimSort<DefaultInsertionSort, DefaultMergingMethod, DefaultBufGuardFactory, DEFAULT_MIN_MERGE, true>,
+        MultiwayTimSort<
+            DefaultInsertionSort,
+            DefaultMultiMergingMethod,
+            DefaultBufGuardFactory,
+            DEFAULT_MERGE_K_RUNS,
+            DEFAULT_MIN_MERGE,
+            true,
+        >,
+    }
+
+    #[test]
+    fn test_persistent_merge_state() {
+        use crate::algorithms::Sort as _;
+
+        type ResetTimSort = TimSort<
+            DefaultInsertionSort,
+            DefaultMergingMethod,
+            DefaultBufGuardFactory,
+            DEFAULT_MIN_MERGE,
+            DEFAULT_ONLY_INCREASING_RUNS,
+            false,
+        >;
+
+        // Runs of clusters, each run continues every cluster of the previous runs
+        const RUNS: usize = 64;
+        const CLUSTER: usize = 64;
+        let values: Box<[_]> = (0..RUNS)
+            .flat_map(|run| (0..RUNS).map(move |cluster| (cluster, run)))
+            .flat_map(|(cluster, run)| {
+                (0..CLUSTER).map(move |i| (cluster * RUNS + run) * CLUSTER + i)
+            })
+            .map(crate::test::CountingOrdered)
+            .collect();
+        let mut reset_values = values.clone();
+        let mut persistent_values = values;
+
+        let reset_comparisons =
+            crate::test::count_comparisons(|| ResetTimSort::sort(&mut reset_values));
+        let persistent_comparisons =
+            crate::test::count_comparisons(|| <TimSort>::sort(&mut persistent_values));
+
+        assert!(reset_values.is_sorted() && persistent_values.is_sorted());
+        assert!(
+            persistent_comparisons < reset_comparisons,
+            "{persistent_comparisons} comparisons with persistent state, {reset_comparisons} \
+             without"
+        );
+    }
+
+    #[test]
+    fn test_trotsort_comparisons() {
+        use crate::algorithms::Sort as _;
+        use rand::seq::SliceRandom as _;
+
+        const SIZE: usize = 100_000;
+        const CLUSTER_LENGTH: usize = 1_000;
+
+        type ResetTrotSort = TimSort<
+            DefaultInsertionSort,
+            crate::algorithms::merging::two_way::CopyBoth,
+            DefaultBufGuardFactory,
+            DEFAULT_MIN_MERGE,
+            DEFAULT_ONLY_INCREASING_RUNS,
+            false,
+        >;
+
+        /// Returns the comparisons of Timsort and Trotsort sorting `values`, checking that both
+        /// sort stably and Trotsort ignores the persistent merge state.
+        fn comparisons(values: Vec<usize>) -> (u64, u64) {
+            let values: Vec<_> = crate::test::IndexedOrdered::map_iter(
+                values.into_iter().map(crate::test::CountingOrdered),
+            )
+            .collect();
+            let mut timsort_values = values.clone();
+            let mut trotsort_values = values.clone();
+            let mut reset_values = values;
+
+            let timsort = crate::test::count_comparisons(|| <TimSort>::sort(&mut timsort_values));
+            let trotsort =
+                crate::test::count_comparisons(|| <TrotSort>::sort(&mut trotsort_values));
+            let reset = crate::test::count_comparisons(|| ResetTrotSort::sort(&mut reset_values));
+
+            for values in [&timsort_values, &trotsort_values] {
+                assert_eq!(
+                    crate::test::IndexedOrdered::is_stable_sorted(values.iter()),
+                    Ok(true)
+                );
+            }
+            assert_eq!(trotsort, reset, "CopyBoth depends on the merge state");
+
+            (timsort, trotsort)
+        }
+
+        let mut rng = crate::test::test_rng();
+
+        let mut values: Vec<usize> = (0..SIZE).collect();
+        values.shuffle(&mut rng);
+        let (random_timsort, random_trotsort) = comparisons(values);
+
+        // Clustered data, so Timsort gallops over most of each run while merging
+        let (clustered_timsort, clustered_trotsort) = comparisons(
+            crate::test::clustered_permutation(SIZE, CLUSTER_LENGTH, &mut rng),
+        );
+
+        assert!(
+            clustered_trotsort > clustered_timsort,
+            "Trotsort took {clustered_trotsort} comparisons on clustered data, Timsort \
+             {clustered_timsort}"
+        );
+
+        let random_ratio = crate::test::comparison_ratio(random_trotsort, random_timsort);
+        let clustered_ratio = crate::test::comparison_ratio(clustered_trotsort, clustered_timsort);
+        assert!(
+            clustered_ratio > 1.1 * random_ratio,
+            "Trotsort took {clustered_ratio:.3} times the comparisons of Timsort on clustered \
+             data, {random_ratio:.3} times on random data"
+        );
+    }
+
+    #[test]
+    fn test_two_way_matches_timsort() {
+        use crate::algorithms::Sort as _;
+        use rand::seq::SliceRandom as _;
+
+        type CopyBoth = super::super::merging::two_way::CopyBoth;
+
+        let mut rng = crate::test::test_rng();
+        let mut values: Box<[_]> = (0..crate::test::DEFAULT_TEST_SIZE)
+            .map(crate::test::CountingOrdered)
+            .collect();
+
+        for _ in 0..crate::test::DEFAULT_TEST_RUNS {
+            values.shuffle(&mut rng);
+            let mut multiway_values = values.clone();
+
+            let comparisons = crate::test::count_comparisons(|| {
+                TimSort::<DefaultInsertionSort, CopyBoth>::sort(&mut values)
+            });
+            let multiway_comparisons = crate::test::count_comparisons(|| {
+                MultiwayTimSort::<DefaultInsertionSort, CopyBoth, DefaultBufGuardFactory, 2>::sort(
+                    &mut multiway_values,
+                )
+            });
+
+            assert!(values.is_sorted() && multiway_values.is_sorted());
+            assert_eq!(comparisons, multiway_comparisons);
+        }
+    }
+
+    /// Run lengths breaking the invariants, if only the top three runs are checked when merging.
+    ///
+    /// Pushing the last run merges `25` and `20`, leaving `120 <= 80 + 45` deeper in the stack.
+    const ADVERSARIAL_RUN_LENGTHS: [usize; 5] = [120, 80, 25, 20, 30];
+
+    /// Pushes runs of the given lengths onto a stack, merging them with `collapse` after each
+    /// push, and returns whether the invariants held after every collapse.
+    fn simulate_collapse(
+        run_lengths: impl IntoIterator<Item = usize>,
+        collapse: fn(&[Run]) -> Option<usize>,
+    ) -> bool {
+        let mut pending_runs = Vec::new();
+        let mut start = 0;
+
+        for len in run_lengths {
+            pending_runs.push(Run { start, len });
+            start += len;
+
+            while let Some(index) = collapse(&pending_runs) {
+                pending_runs[index].len += pending_runs[index + 1].len;
+                pending_runs.remove(index + 1);
+            }
+
+            if !invariants_hold(&pending_runs) {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    #[test]
+    fn test_collapse_invariants() {
+        use rand::Rng as _;
+
+        /// The original rule of Timsort, only checking the top three runs.
+        fn three_run_collapse_index(pending_runs: &[Run]) -> Option<usize> {
+            let n = pending_runs.len().checked_sub(2)?;
+
+            if n > 0 && pending_runs[n - 1].len <= pending_runs[n].len + pending_runs[n + 1].len {
+                if pending_runs[n - 1].len < pending_runs[n + 1].len {
+                    Some(n - 1)
+                } else {
+                    Some(n)
+                }
+            } else if pending_runs[n].len <= pending_runs[n + 1].len {
+                Some(n)
+            } else {
+                None
+            }
+        }
+
+        assert!(!simulate_collapse(
+            ADVERSARIAL_RUN_LENGTHS,
+            three_run_collapse_index
+        ));
+        assert!(simulate_collapse(ADVERSARIAL_RUN_LENGTHS, collapse_index));
+
+        let mut rng = crate::test::test_rng();
+        for max_len in [2, 16, 100, 10_000] {
+            for _ in 0..crate::test::DEFAULT_TEST_RUNS {
+                let run_lengths: Vec<usize> =
+                    (0..1_000).map(|_| rng.random_range(1..max_len)).collect();
+
+                assert!(
+                    simulate_collapse(run_lengths.iter().copied(), collapse_index),
+                    "Timsort invariants violated for run lengths {run_lengths:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_sort_invariants() {
+        use crate::algorithms::Sort as _;
+        use crate::data::{
+            BoundaryRunsData, DescendingData, OrganPipeData, PermutationData, RandomRunsSqrtData,
+            ReversedBlocksData,
+        };
+
+        /// Sorts data of `D` with `TimSort`, which checks the invariants after each collapse.
+        fn check<D: crate::data::DataGenerator<u32>>() {
+            let mut rng = crate::data::seeded_rng(crate::test::TEST_SEED);
+            let mut generator = D::default();
+
+            for size in [100, 1_000, crate::test::DEFAULT_TEST_SIZE] {
+                let mut values = generator.initialize(size, &mut rng);
+                <TimSort>::sort(&mut values);
+                assert!(values.is_sorted());
+            }
+        }
+
+        check::<PermutationData>();
+        check::<RandomRunsSqrtData>();
+        check::<BoundaryRunsData>();
+        check::<ReversedBlocksData>();
+        check::<OrganPipeData>();
+        check::<DescendingData>();
+
+        // Ascending runs of the adversarial lengths, each starting below the previous one
+        const SCALE: usize = DEFAULT_MIN_MERGE;
+        let run_count = ADVERSARIAL_RUN_LENGTHS.len();
+        let mut values: Vec<usize> = ADVERSARIAL_RUN_LENGTHS
+            .iter()
+            .enumerate()
+            .flat_map(|(run, len)| {
+                (0..len * SCALE).map(move |i| (run_count - run) * 1_000 * SCALE + i)
+            })
+            .collect();
+
+        <TimSort>::sort(&mut values);
+        assert!(values.is_sorted());
     }
 }
@@ -0,0 +1,211 @@
+//! Slices allocated on a chosen alignment boundary, see `--align` and `--buffer aligned`.
+
+/// An owned slice whose first element is aligned to at least the requested alignment, analogous
+/// to `Box<[T]>`.
+pub struct AlignedBox<T> {
+    /// The start of the allocation, dangling (but aligned) if its size is zero
+    ptr: std::ptr::NonNull<T>,
+    /// The number of elements
+    len: usize,
+    /// The layout the allocation was made with
+    layout: std::alloc::Layout,
+}
+
+impl<T> AlignedBox<std::mem::MaybeUninit<T>> {
+    /// Allocates `len` uninitialized elements starting at a multiple of `align`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `align` is not a power of two or the allocation size overflows `isize::MAX`.
+    pub fn new_uninit_slice(len: usize, align: usize) -> Self {
+        let layout = std::alloc::Layout::array::<T>(len)
+            .and_then(|layout| layout.align_to(align))
+            .expect("The alignment must be a power of two and the size must not overflow");
+
+        let ptr = if layout.size() == 0 {
+            // Zero sized allocations are not allowed, any aligned pointer is valid for them though
+            std::ptr::NonNull::without_provenance(
+                std::num::NonZeroUsize::new(layout.align()).expect("Alignments are positive"),
+            )
+        } else {
+            // SAFETY: The size of `layout` is not zero
+            let ptr = unsafe { std::alloc::alloc(layout) };
+            std::ptr::NonNull::new(ptr)
+                .unwrap_or_else(|| std::alloc::handle_alloc_error(layout))
+                .cast()
+        };
+
+        Self { ptr, len, layout }
+    }
+
+    /// Temporarily moves the elements of `vec` into this allocation, so they start at its
+    /// alignment without allocating. The elements are moved back into `vec`, keeping its
+    /// allocation, once the returned [`MovedFromVec`] is dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `vec` holds more elements than fit into this allocation.
+    pub fn move_from_vec<'a>(&'a mut self, vec: &'a mut Vec<T>) -> MovedFromVec<'a, T> {
+        let len = vec.len();
+        assert!(
+            len <= self.len,
+            "{len} elements do not fit into {}",
+            self.len
+        );
+
+        // SAFETY: Both allocations hold at least `len` elements and do not overlap. The elements
+        // are moved out of `vec` by setting its length to zero, so they are only dropped once.
+        unsafe {
+            std::ptr::copy_nonoverlapping(vec.as_ptr(), self.ptr.as_ptr().cast(), len);
+            vec.set_len(0);
+        }
+
+        MovedFromVec {
+            aligned: self,
+            vec,
+            len,
+        }
+    }
+}
+
+/// Elements temporarily moved from a `Vec` into an [`AlignedBox`], see
+/// [`AlignedBox::move_from_vec()`].
+pub struct MovedFromVec<'a, T> {
+    /// The allocation holding the elements
+    aligned: &'a mut AlignedBox<std::mem::MaybeUninit<T>>,
+    /// The emptied `Vec` the elements are moved back into
+    vec: &'a mut Vec<T>,
+    /// The number of moved elements
+    len: usize,
+}
+
+impl<T> std::ops::Deref for MovedFromVec<'_, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        // SAFETY: The first `len` elements of `aligned` are initialized and owned by `self`
+        unsafe { std::slice::from_raw_parts(self.aligned.ptr.as_ptr().cast(), self.len) }
+    }
+}
+
+impl<T> std::ops::DerefMut for MovedFromVec<'_, T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        // SAFETY: see `deref()`, `&mut self` guarantees exclusive access
+        unsafe { std::slice::from_raw_parts_mut(self.aligned.ptr.as_ptr().cast(), self.len) }
+    }
+}
+
+impl<T> Drop for MovedFromVec<'_, T> {
+    fn drop(&mut self) {
+        // SAFETY: `vec` is empty and still has the capacity for the `len` elements it held before.
+        // The elements are moved back, leaving `aligned` uninitialized, so they are only dropped
+        // once.
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                self.aligned.ptr.as_ptr().cast(),
+                self.vec.as_mut_ptr(),
+                self.len,
+            );
+            self.vec.set_len(self.len);
+        }
+    }
+}
+
+impl<T> std::ops::Deref for AlignedBox<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        // SAFETY: `ptr` points to `len` initialized elements (or uninitialized `MaybeUninit`s)
+        // owned by `self`
+        unsafe { std::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<T> std::ops::DerefMut for AlignedBox<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        // SAFETY: see `deref()`, `&mut self` guarantees exclusive access
+        unsafe { std::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<T> Drop for AlignedBox<T> {
+    fn drop(&mut self) {
+        // SAFETY: The elements are initialized and dropped exactly once here, afterwards the
+        // allocation made with `layout` is freed, if it is not zero sized
+        unsafe {
+            std::ptr::drop_in_place(std::ptr::from_mut::<[T]>(self));
+
+            if self.layout.size() != 0 {
+                std::alloc::dealloc(self.ptr.as_ptr().cast(), self.layout);
+            }
+        }
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for AlignedBox<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Checks that `values` moved into an [`AlignedBox`] are aligned to every alignment up to a
+    /// page and unchanged.
+    fn check_aligned<T: Clone + PartialEq + std::fmt::Debug>(values: &[T]) {
+        for align in (0..=12).map(|exponent| 1 << exponent) {
+            let mut buffer = AlignedBox::new_uninit_slice(values.len(), align);
+            let mut vec = values.to_vec();
+            let aligned = buffer.move_from_vec(&mut vec);
+
+            assert_eq!(aligned.as_ptr().addr() % align, 0, "Not aligned to {align}");
+            assert!(aligned.as_ptr().is_aligned());
+            assert_eq!(&*aligned, values);
+        }
+    }
+
+    #[test]
+    fn test_aligned_box() {
+        check_aligned::<u8>(&[1, 2, 3]);
+        check_aligned::<u32>(&(0..1000).collect::<Vec<_>>());
+        check_aligned::<u64>(&[]);
+        check_aligned::<()>(&[(); 10]);
+        check_aligned::<String>(&["a".to_string(), "b".to_string()]);
+        check_aligned::<crate::cli::Blob2U64CmpFirst>(&[0.into(), 1.into()]);
+
+        let mut buffer = AlignedBox::<std::mem::MaybeUninit<u16>>::new_uninit_slice(100, 64);
+        assert_eq!(buffer.len(), 100);
+        assert_eq!(buffer.as_ptr().addr() % 64, 0);
+        buffer[99].write(7);
+    }
+
+    #[test]
+    fn test_move_from_vec() {
+        let mut aligned = AlignedBox::<std::mem::MaybeUninit<String>>::new_uninit_slice(10, 64);
+        let mut vec: Vec<String> = (0..10).map(|i| i.to_string()).collect();
+        let vec_address = vec.as_ptr().addr();
+
+        for len in [10, 3, 0] {
+            vec.truncate(len);
+
+            let mut moved = aligned.move_from_vec(&mut vec);
+            assert_eq!(moved.as_ptr().addr() % 64, 0);
+            assert_eq!(moved.len(), len);
+            moved.reverse();
+            drop(moved);
+
+            assert_eq!(vec.len(), len);
+            assert_eq!(vec.as_ptr().addr(), vec_address);
+            vec.reverse();
+            assert_eq!(vec, (0..len).map(|i| i.to_string()).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "alignment must be a power of two")]
+    fn test_invalid_alignment() {
+        AlignedBox::<std::mem::MaybeUninit<u32>>::new_uninit_slice(1, 3);
+    }
+}
@@ -6,50 +6,504 @@
     version,
     subcommand_value_name = "sort",
     subcommand_help_heading = "Sorts",
-    disable_help_subcommand = true
+    disable_help_subcommand = true,
+    subcommand_negates_reqs = true,
+    args_conflicts_with_subcommands = true,
+    group = clap::ArgGroup::new("selection").multiple(false).args([
+        "block_size",
+        "k",
+        "kway_threshold",
+        "insertion_method",
+        "merging",
+        "binary_insertion",
+        "galloping_insertion",
+        "iterative",
+        "balanced",
+        "adaptive_min_run",
+        "detect_sorted",
+        "min_gallop",
+        "pivot",
+    ])
 )]
 pub struct Args {
+    /// Run a different command instead of measuring a single algorithm
+    #[command(subcommand)]
+    pub command: Option<Command>,
     /// The sorting algorithm to run
-    #[arg()]
-    pub algorithm: Algorithm,
+    #[arg(required = true)]
+    pub algorithm: Option<Algorithm>,
     /// The datatype and distribution to use for sorting
     #[arg(short, long, default_value_t = DataType::RandomRunsSqrtU32)]
     pub data: DataType,
     /// The algorithm variant, use `-v=-1` to print available options
     #[arg(short, long, default_value_t = 0)]
     pub variant: isize,
+    /// Use the block merging variant with the given block size instead of `--variant`, only
+    /// available for powersort and timsort, see [`BLOCK_SIZES`]
+    #[arg(long)]
+    pub block_size: Option<usize>,
+    /// Use the first variant merging the given number of runs at once instead of `--variant`,
+    /// only available for the multiway algorithms and patience sort
+    #[arg(long)]
+    pub k: Option<usize>,
+    /// Use the multiway powersort variant merging slices shorter than the given length with a
+    /// 2-way method instead of `--variant`, see [`KWAY_THRESHOLDS`]
+    #[arg(long, value_parser = parse_kway_threshold)]
+    pub kway_threshold: Option<usize>,
+    /// Use the insertion sort variant moving elements into place with the given method instead of
+    /// `--variant`, only available for insertion sort
+    #[arg(long, value_name = "METHOD", value_parser = INSERTION_METHODS)]
+    pub insertion_method: Option<String>,
+    /// Use the first variant merging two runs with the given method instead of `--variant`, only
+    /// available for mergesort, see [`MERGING_METHODS`]
+    #[arg(long, value_name = "METHOD", value_parser = MERGING_METHODS)]
+    pub merging: Option<String>,
+    /// Use the first variant extending short runs with binary insertion sort instead of
+    /// `--variant`, bounding the comparisons of the extension, only available for powersort and
+    /// timsort
+    #[arg(long)]
+    pub binary_insertion: bool,
+    /// Use the first variant extending short runs with [`insertionsort::GallopingInsertionSort`]
+    /// instead of `--variant`, only available for powersort and timsort
+    #[arg(long)]
+    pub galloping_insertion: bool,
+    /// Use the first variant sorting without recursion instead of `--variant`, only available for
+    /// peeksort and mergesort
+    #[arg(long)]
+    pub iterative: bool,
+    /// Use the first variant merging equally long chunks bottom-up instead of `--variant`, only
+    /// available for mergesort
+    #[arg(long)]
+    pub balanced: bool,
+    /// Use the first variant adapting the minimum run length to the slice length like timsort
+    /// instead of `--variant`, only available for powersort and multiway powersort
+    #[arg(long)]
+    pub adaptive_min_run: bool,
+    /// Use the first variant returning early on fully sorted input, before allocating the merging
+    /// buffer, instead of `--variant`, only available for powersort
+    #[arg(long)]
+    pub detect_sorted: bool,
+    /// Use the first variant merging with [`merging::two_way::Galloping`] of the given
+    /// `MIN_GALLOP` threshold instead of `--variant`, only available for timsort, powersort and
+    /// multiway powersort, see [`MIN_GALLOPS`]. Smaller thresholds gallop sooner
+    #[arg(long, value_parser = parse_min_gallop)]
+    pub min_gallop: Option<usize>,
+    /// Use the first variant choosing pivots with the given strategy instead of `--variant`, only
+    /// available for quicksort, see [`PIVOT_STRATEGIES`]
+    #[arg(long, value_name = "STRATEGY", value_parser = PIVOT_STRATEGIES)]
+    pub pivot: Option<String>,
+    /// The maximum length of sub slices quicksort sorts with insertion sort, at least 3, see
+    /// [`QUICKSORT_INSERTION_THRESHOLDS`]
+    #[arg(long, value_parser = parse_insertion_threshold)]
+    pub insertion_threshold: Option<usize>,
+    /// The minimum length of sub slices quicksort uses the ninther as pivot for, see
+    /// [`QUICKSORT_NINTHER_THRESHOLDS`]
+    #[arg(long, value_parser = parse_ninther_threshold)]
+    pub ninther_threshold: Option<usize>,
+    /// The merging buffer to allocate, only the default variants of some algorithms support
+    /// buffers other than `vec`, see [`Buffer::supported_algorithms()`]
+    #[arg(long, default_value_t = Buffer::Vec)]
+    pub buffer: Buffer,
+    /// Only allocate the merging buffer before the first merge, shorthand for `--buffer lazy`
+    #[arg(long, conflicts_with = "buffer")]
+    pub lazy_buffer: bool,
+    /// Fault in every page of the merging buffer when allocating it, so it is first touched by the
+    /// measuring thread instead of the first merge, shorthand for `--buffer boxed`
+    #[arg(long, conflicts_with_all = ["buffer", "lazy_buffer"])]
+    pub prefault_buffer: bool,
     /// The number of runs to do
     #[arg(short, long, default_value_t = 1_000)]
     pub runs: usize,
-    /// The size of the data slices to sort
-    #[arg(short, long, default_value_t = 1_000_000)]
-    pub size: usize,
+    /// The number of unmeasured warmup runs to do before the measured runs
+    #[arg(short, long, default_value_t = 1)]
+    pub warmup: usize,
+    /// Measure the first run as well, i.e. do no warmup runs, shorthand for `--warmup 0`
+    #[arg(long, conflicts_with = "warmup")]
+    pub no_skip_first: bool,
+    /// The sizes of the data slices to sort, multiple sizes can be given separated by commas
+    #[arg(short, long = "size", value_delimiter = ',', default_value = "1000000")]
+    pub sizes: Vec<usize>,
+    /// The number of distinct values for the few distinct data types, defaults to `n.isqrt()`
+    #[arg(long)]
+    pub distinct: Option<std::num::NonZeroUsize>,
+    /// The minimum run length the boundary runs data types generate runs around, at least 3,
+    /// defaults to the minimum run length of powersort
+    #[arg(long, value_parser = parse_min_run)]
+    pub min_run: Option<usize>,
+    /// The length of the reversed blocks for the reversed blocks data types, defaults to
+    /// `n.isqrt()`
+    #[arg(long)]
+    pub block: Option<std::num::NonZeroUsize>,
+    /// The nanoseconds every comparison of the costly data types busy waits for, defaults to 0
+    #[arg(long, value_name = "NANOS")]
+    pub compare_cost: Option<u64>,
+    /// Move the generated data of each measured run to an allocation starting at a multiple of
+    /// the given power of two before sorting it, to isolate the effect of its alignment. The merging
+    /// buffer can be aligned to a cache line with `--buffer aligned`
+    #[arg(long, value_name = "BYTES", value_parser = parse_alignment)]
+    pub align: Option<usize>,
+    /// Generate the data only once and sort a clone of it in every run, the clone is made before
+    /// measuring and not included in the running times
+    #[arg(long)]
+    pub repeat_input: bool,
+    /// Pin the measuring thread to the given core before the runs, requires the `core_affinity`
+    /// feature and is ignored with a warning outside of Linux. This avoids migrations of the
+    /// thread between cores, reducing tail latency noise in the reported percentiles
+    #[arg(long, value_name = "ID")]
+    pub pin_core: Option<usize>,
+    /// The clock whose running times the statistics are computed from, both the wall clock and the
+    /// CPU time are written to the output file
+    #[arg(long, default_value_t = TimeSource::Wall)]
+    pub time_source: TimeSource,
     /// Seed for the RNG
     #[arg(long)]
     pub seed: Option<u64>,
+    /// Only sort a single slice and check the result for correctness (and stability for stable
+    /// algorithms), without measuring anything
+    #[arg(long)]
+    pub verify_only: bool,
+    /// Only sort a single slice with both the algorithm and the default variant of the given
+    /// reference algorithm, e.g. `std`, and check that the results are identical, including the
+    /// order of equal elements if both are stable
+    #[arg(long, value_name = "ALGORITHM", conflicts_with = "verify_only")]
+    pub reference: Option<Algorithm>,
+    /// Only sort a single slice for each size and print checksums of the result, to compare the
+    /// behavior of two builds for the same `--seed`. Stable algorithms additionally print a
+    /// checksum of the initial indices of the sorted elements, which also changes if equal
+    /// elements end up in a different order
+    #[arg(long, requires = "seed", conflicts_with_all = ["verify_only", "reference"])]
+    pub output_sorted_check: bool,
+    /// Only sort a single slice and print a histogram of the natural run lengths discovered by the
+    /// algorithm, requires the `instrument` feature
+    #[arg(long)]
+    pub run_histogram: bool,
+    /// Only sort a single slice and print how many merge tree nodes of each node power were
+    /// computed by the algorithm, requires the `counters` feature and is only supported by
+    /// powersort and multiway-powersort
+    #[arg(long)]
+    pub power_histogram: bool,
+    /// Only sort a single slice and write the length of every natural run discovered by the
+    /// algorithm to the given file (one per line), requires the `instrument` feature and is only
+    /// supported by powersort, timsort and natural-mergesort
+    #[arg(long, value_name = "PATH")]
+    pub dump_runs: Option<std::path::PathBuf>,
+    /// Only sort a single slice and write the sorted elements to the given file and the generated
+    /// input to the file with `.input` appended, one `Debug` representation per line, to replay
+    /// and inspect failing cases. Only supports a single size
+    #[arg(
+        long,
+        value_name = "PATH",
+        conflicts_with_all = ["verify_only", "reference", "output_sorted_check"],
+    )]
+    pub dump_output: Option<std::path::PathBuf>,
+    /// Only sort the `K` smallest elements of each slice into its front, leaving the rest
+    /// partially ordered, see [`crate::algorithms::PartialSort`]. Only supported by the default
+    /// quicksort variant
+    #[arg(
+        long,
+        value_name = "K",
+        conflicts_with_all = [
+            "verify_only",
+            "reference",
+            "output_sorted_check",
+            "dump_output",
+            "checked",
+        ],
+    )]
+    pub first_k: Option<usize>,
+    /// Check the result of every sort for correctness (and stability for stable algorithms),
+    /// panicking on failure, see [`crate::algorithms::Checked`]
+    #[arg(long)]
+    pub checked: bool,
+    /// Sort in descending order instead, see [`crate::algorithms::Sort::sort_descending()`]. Only
+    /// supported with the `vec` buffer and the default quicksort thresholds
+    #[arg(
+        long,
+        conflicts_with_all = [
+            "verify_only",
+            "reference",
+            "output_sorted_check",
+            "run_histogram",
+            "power_histogram",
+            "dump_runs",
+            "dump_output",
+            "first_k",
+            "checked",
+        ],
+    )]
+    pub descending: bool,
+    /// Print presortedness measures of the slice sorted in the first run for each size before
+    /// sorting, only supported by the text format, see [`crate::metrics::InputMetrics`]
+    #[arg(long)]
+    pub describe_input: bool,
+    /// Print the auxiliary buffer memory allocated by the algorithm for each size before sorting
+    #[arg(long)]
+    pub print_memory: bool,
+    /// The format used to print the measurement results
+    #[arg(long, default_value_t = OutputFormat::Text)]
+    pub format: OutputFormat,
+    /// The number of decimal places of the running times printed by the text format
+    #[arg(long, value_name = "DIGITS", default_value_t = 3)]
+    pub precision: usize,
     /// An optional output file to write the samples to (formatted as CSV)
     pub output: Option<std::path::PathBuf>,
 }
 
+/// The commands available instead of measuring a single algorithm
+#[derive(clap::Subcommand)]
+pub enum Command {
+    /// Benchmark algorithms with criterion, requires the `criterion` feature
+    Bench(BenchArgs),
+}
+
+/// The arguments of [`Command::Bench`]
+#[derive(clap::Args)]
+pub struct BenchArgs {
+    /// The sorting algorithms to compare, using their first variant
+    #[arg(
+        short,
+        long = "algorithm",
+        value_delimiter = ',',
+        default_value = "powersort,timsort,std"
+    )]
+    pub algorithms: Vec<Algorithm>,
+    /// The datatype and distribution to use for sorting
+    #[arg(short, long, default_value_t = DataType::RandomRunsSqrtU32)]
+    pub data: DataType,
+    /// The sizes of the data slices to sort, multiple sizes can be given separated by commas
+    #[arg(
+        short,
+        long = "size",
+        value_delimiter = ',',
+        default_value = "1000,10000,100000"
+    )]
+    pub sizes: Vec<usize>,
+    /// Seed for the RNG
+    #[arg(long, default_value_t = 0)]
+    pub seed: u64,
+}
+
 /// The available top level sorting algorithms
 #[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
 pub enum Algorithm {
     /// The default sort in [`std`]
     Std,
+    /// The default sort in [`std`], comparing through a closure
+    #[cfg(feature = "counters")]
+    StdBy,
     /// Insertionsort
     Insertionsort,
     /// Quicksort
     Quicksort,
     /// Peeksort
     Peeksort,
+    /// Multiway Peeksort
+    MultiwayPeeksort,
     /// Mergesort
     Mergesort,
     /// Timsort
     Timsort,
+    /// Multiway Timsort
+    MultiwayTimsort,
     /// Powersort
     Powersort,
     /// Powersort
     MultiwayPowersort,
+    /// Patience sort
+    Patiencesort,
+    /// Sorting cached keys and permuting afterwards
+    CachedKey,
+    /// Block sort
+    Blocksort,
+    /// Multiway bottom-up mergesort
+    MultiwayMergesort,
+    /// Cycle sort, minimizing the element writes
+    Cyclesort,
+    /// Natural mergesort, merging detected runs bottom-up
+    NaturalMergesort,
+}
+
+/// The available formats for printing measurement results
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum OutputFormat {
+    /// Human readable statistics
+    Text,
+    /// One JSON object per slice size, containing the samples and their statistics
+    Json,
+    /// One JSON object per slice size, containing the benchmark id and the estimates in the
+    /// format of the `estimates.json` files written by criterion, with bootstrapped confidence
+    /// intervals
+    CriterionJson,
+}
+
+impl std::fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(clap::ValueEnum::to_possible_value(self).unwrap().get_name())
+    }
+}
+
+/// The available clocks for measuring running times
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum TimeSource {
+    /// The elapsed wall clock time, see [`std::time::Instant`]
+    Wall,
+    /// The CPU time consumed by the measuring thread, see [`crate::cpu_time::CpuInstant`]
+    Cpu,
+}
+
+impl std::fmt::Display for TimeSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(clap::ValueEnum::to_possible_value(self).unwrap().get_name())
+    }
+}
+
+/// The available merging buffers, see [`crate::algorithms::BufGuardFactory`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Buffer {
+    /// A `Vec<T>`, see [`crate::algorithms::DefaultBufGuardFactory`]
+    Vec,
+    /// A boxed slice faulted in when allocating, see [`crate::algorithms::BoxedBufGuardFactory`]
+    Boxed,
+    /// A `Vec<T>` only allocated before the first merge, see
+    /// [`crate::algorithms::LazyBufGuardFactory`]
+    Lazy,
+    /// A slice aligned to a cache line, see [`crate::algorithms::AlignedBufGuardFactory`]
+    Aligned,
+}
+
+impl std::fmt::Display for Buffer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(clap::ValueEnum::to_possible_value(self).unwrap().get_name())
+    }
+}
+
+impl Buffer {
+    /// Returns the sorting function of the `algorithm` variant for the given datatype `T`, using
+    /// this buffer and wrapped in [`Checked`] if `checked` is set.
+    ///
+    /// Returns `None` if the variant does not support this buffer, only the default variants of
+    /// the [`Self::supported_algorithms()`] support buffers other than [`Buffer::Vec`].
+    pub fn sorter<T: Ord>(
+        self,
+        algorithm: Algorithm,
+        variant: usize,
+        checked: bool,
+    ) -> Option<fn(&mut [T])> {
+        /// Returns the sorting function of `$sort` if it is displayed as the variant.
+        macro_rules! sorter_if_variant {
+            ($sort:ty) => {{
+                let description = AlgorithmVariants::variants(algorithm).nth(variant)?;
+                if display::<$sort>() != description {
+                    return None;
+                }
+
+                Some(if checked {
+                    <Checked<$sort> as Sort>::sort
+                } else {
+                    <$sort as Sort>::sort
+                })
+            }};
+        }
+
+        match (self, algorithm) {
+            (Buffer::Vec, _) if checked => AlgorithmVariants::checked_sorter(algorithm, variant),
+            (Buffer::Vec, _) => AlgorithmVariants::sorter(algorithm, variant),
+            (Buffer::Boxed, Algorithm::Powersort) => sorter_if_variant!(
+                powersort::PowerSort<
+                    powersort::DefaultNodePowerMethod,
+                    powersort::DefaultInsertionSort,
+                    powersort::DefaultMergingMethod,
+                    BoxedBufGuardFactory,
+                >
+            ),
+            (Buffer::Boxed, Algorithm::Timsort) => sorter_if_variant!(
+                timsort::TimSort<
+                    timsort::DefaultInsertionSort,
+                    timsort::DefaultMergingMethod,
+                    BoxedBufGuardFactory,
+                >
+            ),
+            (Buffer::Boxed, Algorithm::Mergesort) => sorter_if_variant!(
+                mergesort::MergeSort<
+                    mergesort::DefaultInsertionSort,
+                    mergesort::DefaultMergingMethod,
+                    BoxedBufGuardFactory,
+                >
+            ),
+            (Buffer::Lazy, Algorithm::Powersort) => sorter_if_variant!(
+                powersort::PowerSort<
+                    powersort::DefaultNodePowerMethod,
+                    powersort::DefaultInsertionSort,
+                    powersort::DefaultMergingMethod,
+                    LazyBufGuardFactory,
+                >
+            ),
+            (Buffer::Lazy, Algorithm::MultiwayPowersort) => sorter_if_variant!(
+                powersort::MultiwayPowerSort<
+                    powersort::DefaultNodePowerMethod,
+                    powersort::DefaultInsertionSort,
+                    powersort::DefaultMultiMergingMethod,
+                    powersort::DefaultMergingMethod,
+                    LazyBufGuardFactory,
+                >
+            ),
+            (Buffer::Aligned, Algorithm::Powersort) => sorter_if_variant!(
+                powersort::PowerSort<
+                    powersort::DefaultNodePowerMethod,
+                    powersort::DefaultInsertionSort,
+                    powersort::DefaultMergingMethod,
+                    AlignedBufGuardFactory,
+                >
+            ),
+            (Buffer::Aligned, Algorithm::Timsort) => sorter_if_variant!(
+                timsort::TimSort<
+                    timsort::DefaultInsertionSort,
+                    timsort::DefaultMergingMethod,
+                    AlignedBufGuardFactory,
+                >
+            ),
+            (Buffer::Aligned, Algorithm::Mergesort) => sorter_if_variant!(
+                mergesort::MergeSort<
+                    mergesort::DefaultInsertionSort,
+                    mergesort::DefaultMergingMethod,
+                    AlignedBufGuardFactory,
+                >
+            ),
+            (Buffer::Boxed | Buffer::Lazy | Buffer::Aligned, _) => None,
+        }
+    }
+
+    /// Returns the algorithms whose default variants support this buffer, for error messages.
+    pub fn supported_algorithms(self) -> &'static str {
+        match self {
+            Buffer::Vec => "all algorithms",
+            Buffer::Boxed | Buffer::Aligned => "powersort, timsort and mergesort",
+            Buffer::Lazy => "powersort and multiway-powersort",
+        }
+    }
+}
+
+impl Algorithm {
+    /// Returns whether this algorithm reports the natural runs it discovers to
+    /// [`crate::instrument::on_run()`].
+    #[cfg(feature = "instrument")]
+    pub fn reports_runs(self) -> bool {
+        matches!(
+            self,
+            Algorithm::Timsort
+                | Algorithm::MultiwayTimsort
+                | Algorithm::Powersort
+                | Algorithm::MultiwayPowersort
+                | Algorithm::NaturalMergesort
+        )
+    }
+
+    /// Returns whether this algorithm records the node powers it computes to
+    /// [`crate::algorithms::powersort::node_powers()`].
+    #[cfg(feature = "counters")]
+    pub fn records_node_powers(self) -> bool {
+        matches!(self, Algorithm::Powersort | Algorithm::MultiwayPowersort)
+    }
 }
 
 impl std::fmt::Display for Algorithm {
@@ -82,6 +536,21 @@ pub fn display_inline<S: Sort>() -> String {
     )
 }
 
+/// Returns the description of a merging method with the given `display` representation and
+/// stability, e.g. `galloping (MIN_GALLOP = 7) (stable)`.
+pub fn format_merging(display: &str, stable: bool) -> String {
+    format!(
+        "{display} ({stability})",
+        stability = if stable { "stable" } else { "unstable" }
+    )
+}
+
+/// Returns the description of the merging method `M`, see [`format_merging()`].
+pub fn display_merging<M: crate::algorithms::merging::MultiMergingMethod<K>, const K: usize>()
+-> String {
+    format_merging(&M::display(), M::is_stable())
+}
+
 /// Declare the available algorithm variants.
 ///
 /// We use a macro to statically dispatch on the respective type, given an algorithm and variant.
@@ -103,8 +572,10 @@ macro_rules! declare_variants {
     (
         $name:ident {
             $(
+                $(#[$top_meta:meta])*
                 $top_algorithm:pat => [
                     $(
+                        $(#[$variant_meta:meta])*
                         $variant:ty
                     ),*
                     $(,)?
@@ -128,8 +599,9 @@ macro_rules! declare_variants {
                 declare_variants! { @match_algorithm
                     algorithm => Variant
                     ($(
+                        $(#[$top_meta])*
                         $top_algorithm => [
-                            $($variant),*
+                            $($(#[$variant_meta])* $variant),*
                         ]
                     ),*)
                     {
@@ -148,8 +620,9 @@ macro_rules! declare_variants {
                 declare_variants! { @match_algorithm
                     algorithm => Variant
                     ($(
+                        $(#[$top_meta])*
                         $top_algorithm => [
-                            $($variant),*
+                            $($(#[$variant_meta])* $variant),*
                         ]
                     ),*)
                     {
@@ -164,6 +637,176 @@ macro_rules! declare_variants {
                 None
             }
 
+            /// Returns the function sorting the given datatype `T` in descending order for the
+            /// `algorithm` variant, see [`Sort::sort_descending()`].
+            ///
+            /// If the `variant` is invalid, returns `None`.
+            pub fn descending_sorter<T: Ord>(
+                algorithm: Algorithm,
+                variant: usize,
+            ) -> Option<fn(&mut [T])> {
+                let mut index = 0;
+
+                declare_variants! { @match_algorithm
+                    algorithm => Variant
+                    ($(
+                        $(#[$top_meta])*
+                        $top_algorithm => [
+                            $($(#[$variant_meta])* $variant),*
+                        ]
+                    ),*)
+                    {
+                        if variant == index {
+                            return Some(<Variant as Sort>::sort_descending);
+                        } else {
+                            index += 1;
+                        }
+                    }
+                }
+
+                None
+            }
+
+            /// Returns the sorting function for the given datatype `T` and `algorithm` variant,
+            /// wrapped in [`Checked`].
+            ///
+            /// If the `variant` is invalid, returns `None`.
+            pub fn checked_sorter<T: Ord>(
+                algorithm: Algorithm,
+                variant: usize,
+            ) -> Option<fn(&mut [T])> {
+                let mut index = 0;
+
+                declare_variants! { @match_algorithm
+                    algorithm => Variant
+                    ($(
+                        $(#[$top_meta])*
+                        $top_algorithm => [
+                            $($(#[$variant_meta])* $variant),*
+                        ]
+                    ),*)
+                    {
+                        if variant == index {
+                            return Some(<Checked<Variant> as Sort>::sort);
+                        } else {
+                            index += 1;
+                        }
+                    }
+                }
+
+                None
+            }
+
+            /// Returns the auxiliary buffer memory in bytes, allocated by the `algorithm`
+            /// `variant` for sorting `len` elements of type `T`, see [`Sort::buffer_bytes()`].
+            ///
+            /// If the `variant` is invalid returns `None`.
+            pub fn buffer_bytes<T>(algorithm: Algorithm, variant: usize, len: usize) -> Option<usize> {
+                let mut index = 0;
+
+                declare_variants! { @match_algorithm
+                    algorithm => Variant
+                    ($(
+                        $(#[$top_meta])*
+                        $top_algorithm => [
+                            $($(#[$variant_meta])* $variant),*
+                        ]
+                    ),*)
+                    {
+                        if variant == index {
+                            return Some(<Variant as Sort>::buffer_bytes::<T>(len));
+                        } else {
+                            index += 1;
+                        }
+                    }
+                }
+
+                None
+            }
+
+            /// Returns the description of the merging method used by the `algorithm` `variant`,
+            /// see [`Sort::merging()`].
+            ///
+            /// If the `variant` is invalid returns `None`.
+            pub fn merging(algorithm: Algorithm, variant: usize) -> Option<Option<String>> {
+                let mut index = 0;
+
+                declare_variants! { @match_algorithm
+                    algorithm => Variant
+                    ($(
+                        $(#[$top_meta])*
+                        $top_algorithm => [
+                            $($(#[$variant_meta])* $variant),*
+                        ]
+                    ),*)
+                    {
+                        if variant == index {
+                            return Some(<Variant as Sort>::merging());
+                        } else {
+                            index += 1;
+                        }
+                    }
+                }
+
+                None
+            }
+
+            /// Returns the parameters of the `algorithm` `variant`, see [`Sort::parameters()`].
+            ///
+            /// If the `variant` is invalid returns `None`.
+            pub fn parameters(
+                algorithm: Algorithm,
+                variant: usize,
+            ) -> Option<Vec<(&'static str, String)>> {
+                let mut index = 0;
+
+                declare_variants! { @match_algorithm
+                    algorithm => Variant
+                    ($(
+                        $(#[$top_meta])*
+                        $top_algorithm => [
+                            $($(#[$variant_meta])* $variant),*
+                        ]
+                    ),*)
+                    {
+                        if variant == index {
+                            return Some(<Variant as Sort>::parameters().collect());
+                        } else {
+                            index += 1;
+                        }
+                    }
+                }
+
+                None
+            }
+
+            /// Returns the name of the `algorithm` `variant` including its parameters, see
+            /// [`Sort::name()`].
+            ///
+            /// If the `variant` is invalid returns `None`.
+            pub fn name(algorithm: Algorithm, variant: usize) -> Option<String> {
+                let mut index = 0;
+
+                declare_variants! { @match_algorithm
+                    algorithm => Variant
+                    ($(
+                        $(#[$top_meta])*
+                        $top_algorithm => [
+                            $($(#[$variant_meta])* $variant),*
+                        ]
+                    ),*)
+                    {
+                        if variant == index {
+                            return Some(<Variant as Sort>::name());
+                        } else {
+                            index += 1;
+                        }
+                    }
+                }
+
+                None
+            }
+
             /// Returns if the `algorithm` `variant` is stable.
             ///
             /// If the `variant` is invalid returns `None`.
@@ -173,8 +816,9 @@ macro_rules! declare_variants {
                 declare_variants! { @match_algorithm
                     algorithm => Variant
                     ($(
+                        $(#[$top_meta])*
                         $top_algorithm => [
-                            $($variant),*
+                            $($(#[$variant_meta])* $variant),*
                         ]
                     ),*)
                     {
@@ -194,16 +838,19 @@ macro_rules! declare_variants {
     (@match_algorithm
         $alg:expr => $variant_name:ident
         ($(
+            $(#[$top_meta:meta])*
             $top_algorithm:pat => [
-                $($variant:ty),*
+                $($(#[$variant_meta:meta])* $variant:ty),*
             ]
         ),*)
         $code:block
     ) => {
         match $alg {
             $(
+                $(#[$top_meta])*
                 $top_algorithm => {
                     $(
+                        $(#[$variant_meta])*
                         {
                             type $variant_name = $variant;
 
@@ -226,9 +873,19 @@ declare_variants! {
             StdSort,
             StdSort<false>,
         ],
+        #[cfg(feature = "counters")]
+        Algorithm::StdBy => [
+            StdSortBy,
+            StdSortBy<false>,
+        ],
         Algorithm::Insertionsort => [
             insertionsort::InsertionSort,
             insertionsort::InsertionSort<true>,
+            insertionsort::GallopingInsertionSort,
+            insertionsort::ShiftingInsertionSort,
+            insertionsort::MovingInsertionSort<insertionsort::Swapping>,
+            insertionsort::MovingInsertionSort<insertionsort::Shifting>,
+            insertionsort::MovingInsertionSort<insertionsort::BinaryShifting>,
         ],
         Algorithm::Quicksort => [
             quicksort::QuickSort,
@@ -239,15 +896,45 @@ declare_variants! {
                 { quicksort::DEFAULT_NINTHER_THRESHOLD },
                 true,
             >,
+            PivotQuickSort<quicksort::FirstElement>,
+            PivotQuickSort<quicksort::RandomElement>,
+            PivotQuickSort<quicksort::MedianOfThree>,
+            PivotQuickSort<quicksort::Ninther>,
+            PivotQuickSort<quicksort::Median>,
         ],
         Algorithm::Peeksort => [
+            peeksort::PeekSort,
             peeksort::PeekSort<
                 peeksort::DefaultInsertionSort,
                 peeksort::DefaultMergingMethod,
                 peeksort::DefaultBufGuardFactory,
                 { peeksort::DEFAULT_INSERTION_THRESHOLD },
-                false,
+                true,
+            >,
+            peeksort::PeekSort<
+                peeksort::DefaultInsertionSort,
+                peeksort::DefaultMergingMethod,
+                peeksort::DefaultBufGuardFactory,
+                { peeksort::DEFAULT_INSERTION_THRESHOLD },
+                { peeksort::DEFAULT_ONLY_INCREASING_RUNS },
+                true,
+            >,
+        ],
+        Algorithm::MultiwayPeeksort => [
+            peeksort::MultiwayPeekSort,
+            peeksort::MultiwayPeekSort<
+                peeksort::DefaultInsertionSort,
+                peeksort::DefaultMultiMergingMethod,
+                peeksort::DefaultBufGuardFactory,
+                2,
+            >,
+            peeksort::MultiwayPeekSort<
+                peeksort::DefaultInsertionSort,
+                peeksort::DefaultMultiMergingMethod,
+                peeksort::DefaultBufGuardFactory,
+                3,
             >,
+            peeksort::MultiwayPeekSort<peeksort::DefaultInsertionSort, merging::multi_way::Fourway>,
         ],
         Algorithm::Mergesort => [
             mergesort::MergeSort,
@@ -275,41 +962,542 @@ declare_variants! {
                 { mergesort::DEFAULT_INSERTION_THRESHOLD },
                 true,
             >,
-        ],
-        Algorithm::Timsort => [
-            timsort::TimSort,
-            timsort::TimSort<
-                timsort::DefaultInsertionSort,
-                merging::two_way::CopyBoth,
-                timsort::DefaultBufGuardFactory,
-                { timsort::DEFAULT_MIN_MERGE },
+            mergesort::MergeSort<
+                mergesort::DefaultInsertionSort,
+                mergesort::DefaultMergingMethod,
+                mergesort::DefaultBufGuardFactory,
+                { mergesort::DEFAULT_BOTTOM_UP },
+                { mergesort::DEFAULT_INSERTION_THRESHOLD },
+                { mergesort::DEFAULT_CHECK_SORTED },
+                true,
             >,
-            timsort::TimSort<
-                insertionsort::InsertionSort<false>,
-                merging::two_way::CopyBoth,
-                timsort::DefaultBufGuardFactory,
-                { timsort::DEFAULT_MIN_MERGE },
+            mergesort::MergeSort<
+                mergesort::DefaultInsertionSort,
+                mergesort::DefaultMergingMethod,
+                mergesort::DefaultBufGuardFactory,
+                true,
+                { mergesort::DEFAULT_INSERTION_THRESHOLD },
+                true,
+                true,
             >,
-        ],
-        Algorithm::Powersort => [
-            powersort::PowerSort,
-        ],
-        Algorithm::MultiwayPowersort => [
-            powersort::MultiwayPowerSort,
-            powersort::MultiwayPowerSort<
-                powersort::DefaultNodePowerMethod,
-                powersort::DefaultInsertionSort,
-                merging::multi_way::Fourway,
-                powersort::DefaultBufGuardFactory,
-                4,
-                { powersort::DEFAULT_MIN_RUN_LENGTH },
+            mergesort::MergeSort<
+                mergesort::DefaultInsertionSort,
+                mergesort::DefaultMergingMethod,
+                mergesort::DefaultBufGuardFactory,
+                { mergesort::DEFAULT_BOTTOM_UP },
+                1,
+                { mergesort::DEFAULT_CHECK_SORTED },
+                { mergesort::DEFAULT_PING_PONG },
+                true,
+            >,
+            mergesort::MergeSort<mergesort::DefaultInsertionSort, merging::two_way::Galloping>,
+            mergesort::MergeSort<
+                mergesort::DefaultInsertionSort,
+                merging::two_way::Galloping,
+                mergesort::DefaultBufGuardFactory,
+                true,
+            >,
+            mergesort::MergeSort<mergesort::DefaultInsertionSort, merging::two_way::AdaptiveMerge>,
+            mergesort::MergeSort<
+                mergesort::DefaultInsertionSort,
+                mergesort::DefaultMergingMethod,
+                mergesort::DefaultBufGuardFactory,
+                true,
+                { mergesort::DEFAULT_INSERTION_THRESHOLD },
+                true,
+                { mergesort::DEFAULT_PING_PONG },
+                { mergesort::DEFAULT_ITERATIVE },
+                true,
+            >,
+        ],
+        Algorithm::Timsort => [
+            timsort::TimSort,
+            timsort::TrotSort,
+            timsort::TrotSort<insertionsort::InsertionSort<false>>,
+            timsort::TimSort<
+                timsort::DefaultInsertionSort,
+                merging::two_way::Galloping<1>,
+                timsort::DefaultBufGuardFactory,
+                { timsort::DEFAULT_MIN_MERGE },
+            >,
+            timsort::TimSort<
+                timsort::DefaultInsertionSort,
+                merging::two_way::Galloping<3>,
+                timsort::DefaultBufGuardFactory,
+                { timsort::DEFAULT_MIN_MERGE },
+            >,
+            timsort::TimSort<
+                timsort::DefaultInsertionSort,
+                merging::two_way::Galloping<15>,
+                timsort::DefaultBufGuardFactory,
+                { timsort::DEFAULT_MIN_MERGE },
+            >,
+            timsort::TimSort<insertionsort::GallopingInsertionSort>,
+            timsort::TimSort<
+                timsort::DefaultInsertionSort,
+                timsort::DefaultMergingMethod,
+                timsort::DefaultBufGuardFactory,
+                { timsort::DEFAULT_MIN_MERGE },
+                true,
+            >,
+            timsort::TimSort<
+                timsort::DefaultInsertionSort,
+                merging::two_way::BlockMerge<1024>,
+            >,
+            timsort::TimSort<
+                timsort::DefaultInsertionSort,
+                merging::two_way::BlockMerge<4096>,
+            >,
+            timsort::TimSort<
+                timsort::DefaultInsertionSort,
+                merging::two_way::BlockMerge<16384>,
+            >,
+            timsort::TimSort<
+                timsort::DefaultInsertionSort,
+                timsort::DefaultMergingMethod,
+                timsort::DefaultBufGuardFactory,
+                { timsort::DEFAULT_MIN_MERGE },
+                { timsort::DEFAULT_ONLY_INCREASING_RUNS },
+                false,
+            >,
+            timsort::TimSort<timsort::DefaultInsertionSort, merging::two_way::BidirectionalMerge>,
+            timsort::TimSort<timsort::DefaultInsertionSort, merging::two_way::AdaptiveMerge>,
+        ],
+        Algorithm::MultiwayTimsort => [
+            timsort::MultiwayTimSort,
+            timsort::MultiwayTimSort<
+                timsort::DefaultInsertionSort,
+                timsort::DefaultMultiMergingMethod,
+                timsort::DefaultBufGuardFactory,
+                2,
+            >,
+            timsort::MultiwayTimSort<
+                timsort::DefaultInsertionSort,
+                timsort::DefaultMultiMergingMethod,
+                timsort::DefaultBufGuardFactory,
+                3,
+            >,
+            timsort::MultiwayTimSort<timsort::DefaultInsertionSort, merging::multi_way::Fourway>,
+            timsort::MultiwayTimSort<
+                timsort::DefaultInsertionSort,
+                timsort::DefaultMultiMergingMethod,
+                timsort::DefaultBufGuardFactory,
+                { timsort::DEFAULT_MERGE_K_RUNS },
+                { timsort::DEFAULT_MIN_MERGE },
+                true,
+            >,
+        ],
+        Algorithm::Powersort => [
+            powersort::PowerSort,
+            powersort::PowerSort<
+                powersort::DefaultNodePowerMethod,
+                powersort::DefaultInsertionSort,
+                powersort::DefaultMergingMethod,
+                powersort::DefaultBufGuardFactory,
+                { powersort::DEFAULT_MIN_RUN_LENGTH },
                 { powersort::DEFAULT_ONLY_INCREASING_RUNS },
+                { powersort::DEFAULT_USE_POWER_INDEXED_STACK },
+                true,
+            >,
+            powersort::PowerSort<
+                powersort::DefaultNodePowerMethod,
+                powersort::DefaultInsertionSort,
+                merging::two_way::Galloping<1>,
+            >,
+            powersort::PowerSort<
+                powersort::DefaultNodePowerMethod,
+                powersort::DefaultInsertionSort,
+                merging::two_way::Galloping<3>,
+            >,
+            powersort::PowerSort<
+                powersort::DefaultNodePowerMethod,
+                powersort::DefaultInsertionSort,
+                merging::two_way::Galloping<7>,
+            >,
+            powersort::PowerSort<
+                powersort::DefaultNodePowerMethod,
+                powersort::DefaultInsertionSort,
+                merging::two_way::Galloping<15>,
+            >,
+            powersort::PowerSort<
+                powersort::DefaultNodePowerMethod,
+                insertionsort::GallopingInsertionSort,
+            >,
+            powersort::PowerSort<
+                powersort::DefaultNodePowerMethod,
+                powersort::DefaultInsertionSort,
+                powersort::DefaultMergingMethod,
+                powersort::DefaultBufGuardFactory,
+                { powersort::DEFAULT_MIN_RUN_LENGTH },
+                { powersort::DEFAULT_ONLY_INCREASING_RUNS },
+                { powersort::DEFAULT_USE_POWER_INDEXED_STACK },
+                { powersort::DEFAULT_ADAPTIVE_MIN_RUN },
+                true,
+            >,
+            powersort::PowerSort<
+                powersort::DefaultNodePowerMethod,
+                powersort::DefaultInsertionSort,
+                merging::two_way::BlockMerge<1024>,
+            >,
+            powersort::PowerSort<
+                powersort::DefaultNodePowerMethod,
+                powersort::DefaultInsertionSort,
+                merging::two_way::BlockMerge<4096>,
+            >,
+            powersort::PowerSort<
+                powersort::DefaultNodePowerMethod,
+                powersort::DefaultInsertionSort,
+                merging::two_way::BlockMerge<16384>,
+            >,
+            powersort::PowerSort<
+                powersort::DefaultNodePowerMethod,
+                powersort::DefaultInsertionSort,
+                merging::two_way::BidirectionalMerge,
+            >,
+            powersort::PowerSort<powersort::DefaultNodePowerMethod, insertionsort::InsertionSort<true>>,
+            powersort::PowerSort<
+                powersort::DefaultNodePowerMethod,
+                powersort::DefaultInsertionSort,
+                merging::two_way::AdaptiveMerge,
+            >,
+        ],
+        Algorithm::MultiwayPowersort => [
+            powersort::MultiwayPowerSort,
+            powersort::MultiwayPowerSort<
+                powersort::DefaultNodePowerMethod,
+                powersort::DefaultInsertionSort,
+                merging::multi_way::Fourway,
+                powersort::DefaultMergingMethod,
+                powersort::DefaultBufGuardFactory,
+                4,
+                { powersort::DEFAULT_MIN_RUN_LENGTH },
+                { powersort::DEFAULT_ONLY_INCREASING_RUNS },
+            >,
+            powersort::MultiwayPowerSort<
+                powersort::DefaultNodePowerMethod,
+                powersort::DefaultInsertionSort,
+                powersort::DefaultMultiMergingMethod,
+                powersort::DefaultMergingMethod,
+                powersort::DefaultBufGuardFactory,
+                { powersort::DEFAULT_MERGE_K_RUNS },
+                { powersort::DEFAULT_MIN_RUN_LENGTH },
+                { powersort::DEFAULT_ONLY_INCREASING_RUNS },
+                true,
+            >,
+            powersort::MultiwayPowerSort<
+                powersort::DefaultNodePowerMethod,
+                powersort::DefaultInsertionSort,
+                merging::two_way::Galloping<1>,
+                powersort::DefaultMergingMethod,
+                powersort::DefaultBufGuardFactory,
+                2,
+            >,
+            powersort::MultiwayPowerSort<
+                powersort::DefaultNodePowerMethod,
+                powersort::DefaultInsertionSort,
+                merging::two_way::Galloping<3>,
+                powersort::DefaultMergingMethod,
+                powersort::DefaultBufGuardFactory,
+                2,
+            >,
+            powersort::MultiwayPowerSort<
+                powersort::DefaultNodePowerMethod,
+                powersort::DefaultInsertionSort,
+                merging::two_way::Galloping<7>,
+                powersort::DefaultMergingMethod,
+                powersort::DefaultBufGuardFactory,
+                2,
+            >,
+            powersort::MultiwayPowerSort<
+                powersort::DefaultNodePowerMethod,
+                powersort::DefaultInsertionSort,
+                merging::two_way::Galloping<15>,
+                powersort::DefaultMergingMethod,
+                powersort::DefaultBufGuardFactory,
+                2,
+            >,
+            powersort::MultiwayPowerSort<
+                powersort::DefaultNodePowerMethod,
+                powersort::DefaultInsertionSort,
+                merging::multi_way::CopyAll,
+            >,
+            KwayThresholdPowerSort<64>,
+            KwayThresholdPowerSort<256>,
+            KwayThresholdPowerSort<1024>,
+            #[cfg(feature = "simd")]
+            powersort::MultiwayPowerSort<
+                powersort::DefaultNodePowerMethod,
+                powersort::DefaultInsertionSort,
+                merging::multi_way::SimdMinMerge,
             >,
         ],
+        Algorithm::Patiencesort => [
+            patiencesort::PatienceSort,
+            patiencesort::PatienceSort<
+                merging::two_way::Galloping,
+                patiencesort::DefaultBufGuardFactory,
+                2,
+            >,
+        ],
+        Algorithm::CachedKey => [
+            CachedKeySort,
+            CachedKeySort<StdSort<false>>,
+            CachedKeySort<powersort::PowerSort>,
+        ],
+        Algorithm::Blocksort => [
+            blocksort::BlockSort,
+            blocksort::BlockSort<
+                blocksort::DefaultInsertionSort,
+                blocksort::DefaultBufGuardFactory,
+                32,
+            >,
+        ],
+        Algorithm::MultiwayMergesort => [
+            mergesort::MultiwayMergeSort,
+            mergesort::MultiwayMergeSort<
+                mergesort::DefaultInsertionSort,
+                mergesort::DefaultMultiMergingMethod,
+                mergesort::DefaultBufGuardFactory,
+                2,
+            >,
+            mergesort::MultiwayMergeSort<
+                mergesort::DefaultInsertionSort,
+                mergesort::DefaultMultiMergingMethod,
+                mergesort::DefaultBufGuardFactory,
+                8,
+            >,
+            mergesort::MultiwayMergeSort<
+                mergesort::DefaultInsertionSort,
+                merging::multi_way::Fourway,
+            >,
+        ],
+        Algorithm::Cyclesort => [
+            cyclesort::CycleSort,
+        ],
+        Algorithm::NaturalMergesort => [
+            mergesort::NaturalMergeSort,
+            mergesort::NaturalMergeSort<merging::two_way::Galloping>,
+        ],
     }
 }
 
+/// The block sizes of the [`merging::two_way::BlockMerge`] variants, selectable with
+/// `--block-size`.
+pub const BLOCK_SIZES: [usize; 3] = [1024, 4096, 16384];
+
+/// The `MIN_GALLOP` thresholds of the [`merging::two_way::Galloping`] variants, selectable with
+/// `--min-gallop`.
+pub const MIN_GALLOPS: [usize; 4] = [1, 3, 7, 15];
+
+/// The methods of the [`insertionsort::MovingInsertionSort`] variants, selectable with
+/// `--insertion-method`.
+pub const INSERTION_METHODS: [&str; 3] = ["swapping", "shifting", "binary-shifting"];
+
+/// The 2-way merging methods of the [`mergesort::MergeSort`] variants, selectable with `--merging`.
+pub const MERGING_METHODS: [&str; 3] = ["copy-both", "galloping", "adaptive"];
+
+/// The thresholds of the [`KwayThresholdPowerSort`] variants, selectable with `--kway-threshold`.
+pub const KWAY_THRESHOLDS: [usize; 3] = [64, 256, 1024];
+
+/// The default multiway powersort, merging slices shorter than `THRESHOLD` with 2-way
+/// [`merging::two_way::Galloping`].
+type KwayThresholdPowerSort<const THRESHOLD: usize> = powersort::MultiwayPowerSort<
+    powersort::DefaultNodePowerMethod,
+    powersort::DefaultInsertionSort,
+    powersort::DefaultMultiMergingMethod,
+    merging::two_way::Galloping,
+    powersort::DefaultBufGuardFactory,
+    { powersort::DEFAULT_MERGE_K_RUNS },
+    { powersort::DEFAULT_MIN_RUN_LENGTH },
+    { powersort::DEFAULT_ONLY_INCREASING_RUNS },
+    { powersort::DEFAULT_ADAPTIVE_MIN_RUN },
+    THRESHOLD,
+>;
+
 impl AlgorithmVariants {
+    /// Returns the index of the `algorithm` variant merging with
+    /// [`merging::two_way::BlockMerge`] of the given `block_size`.
+    ///
+    /// If there is no such variant, returns `None`.
+    pub fn block_merge_variant(algorithm: Algorithm, block_size: usize) -> Option<usize> {
+        let merging = format_merging(&merging::two_way::display_block_merge(block_size), true);
+
+        (0..Self::variants(algorithm).count())
+            .find(|&variant| Self::merging(algorithm, variant).flatten().as_ref() == Some(&merging))
+    }
+
+    /// Returns the index of the first `algorithm` variant merging with
+    /// [`merging::two_way::Galloping`] of the given `min_gallop` threshold, only considering
+    /// timsort, powersort and multiway powersort.
+    ///
+    /// If there is no such variant, returns `None`.
+    pub fn min_gallop_variant(algorithm: Algorithm, min_gallop: usize) -> Option<usize> {
+        if !matches!(
+            algorithm,
+            Algorithm::Timsort | Algorithm::Powersort | Algorithm::MultiwayPowersort
+        ) {
+            return None;
+        }
+
+        let merging = format_merging(&merging::two_way::display_galloping(min_gallop), true);
+
+        (0..Self::variants(algorithm).count())
+            .find(|&variant| Self::merging(algorithm, variant).flatten().as_ref() == Some(&merging))
+    }
+
+    /// Returns the index of the first `algorithm` variant merging `k` runs at once, i.e. with a
+    /// `K` parameter of `k`.
+    ///
+    /// If there is no such variant, returns `None`.
+    pub fn merge_k_variant(algorithm: Algorithm, k: usize) -> Option<usize> {
+        let k = k.to_string();
+
+        (0..Self::variants(algorithm).count()).find(|&variant| {
+            Self::parameters(algorithm, variant)
+                .unwrap()
+                .iter()
+                .any(|(key, value)| key.eq_ignore_ascii_case("k") && *value == k)
+        })
+    }
+
+    /// Returns the index of the first `algorithm` variant falling back to 2-way merging for slices
+    /// shorter than `threshold`, i.e. with a `kway-threshold` parameter of `threshold`.
+    ///
+    /// If there is no such variant, returns `None`.
+    pub fn kway_threshold_variant(algorithm: Algorithm, threshold: usize) -> Option<usize> {
+        let threshold = threshold.to_string();
+
+        (0..Self::variants(algorithm).count()).find(|&variant| {
+            Self::parameters(algorithm, variant)
+                .unwrap()
+                .contains(&("kway-threshold", threshold.clone()))
+        })
+    }
+
+    /// Returns the index of the first `algorithm` variant inserting elements with the given
+    /// `method`, i.e. with a `method` parameter of `method`.
+    ///
+    /// If there is no such variant, returns `None`.
+    pub fn insertion_method_variant(algorithm: Algorithm, method: &str) -> Option<usize> {
+        (0..Self::variants(algorithm).count()).find(|&variant| {
+            Self::parameters(algorithm, variant)
+                .unwrap()
+                .iter()
+                .any(|(key, value)| *key == "method" && value == method)
+        })
+    }
+
+    /// Returns the index of the first mergesort variant merging two runs with the given `method`,
+    /// i.e. with a `merging` parameter of `method`, ignoring its own parameters.
+    ///
+    /// If `algorithm` is not [`Algorithm::Mergesort`] or there is no such variant, returns `None`.
+    pub fn merging_method_variant(algorithm: Algorithm, method: &str) -> Option<usize> {
+        if algorithm != Algorithm::Mergesort {
+            return None;
+        }
+
+        (0..Self::variants(algorithm).count()).find(|&variant| {
+            Self::parameters(algorithm, variant)
+                .unwrap()
+                .iter()
+                .any(|(key, value)| {
+                    *key == "merging"
+                        && value
+                            .strip_prefix(method)
+                            .is_some_and(|rest| rest.is_empty() || rest.starts_with(" ("))
+                })
+        })
+    }
+
+    /// Returns the index of the first `algorithm` variant extending short runs with binary
+    /// insertion sort, i.e. with an `i-sort` parameter of [`insertionsort::InsertionSort<true>`].
+    ///
+    /// If there is no such variant, returns `None`.
+    pub fn binary_insertion_variant(algorithm: Algorithm) -> Option<usize> {
+        let insertion_sort = display_inline::<insertionsort::InsertionSort<true>>();
+
+        (0..Self::variants(algorithm).count()).find(|&variant| {
+            Self::parameters(algorithm, variant)
+                .unwrap()
+                .contains(&("i-sort", insertion_sort.clone()))
+        })
+    }
+
+    /// Returns the index of the first `algorithm` variant extending short runs with galloping
+    /// insertion sort, i.e. with an `i-sort` parameter of
+    /// [`insertionsort::GallopingInsertionSort`].
+    ///
+    /// If there is no such variant, returns `None`.
+    pub fn galloping_insertion_variant(algorithm: Algorithm) -> Option<usize> {
+        let insertion_sort = display_inline::<insertionsort::GallopingInsertionSort>();
+
+        (0..Self::variants(algorithm).count()).find(|&variant| {
+            Self::parameters(algorithm, variant)
+                .unwrap()
+                .contains(&("i-sort", insertion_sort.clone()))
+        })
+    }
+
+    /// Returns the index of the first `algorithm` variant choosing pivots with the given
+    /// `strategy`, see [`PIVOT_STRATEGIES`].
+    ///
+    /// If there is no such variant, returns `None`.
+    pub fn pivot_variant(algorithm: Algorithm, strategy: &str) -> Option<usize> {
+        (0..Self::variants(algorithm).count()).find(|&variant| {
+            Self::parameters(algorithm, variant)
+                .unwrap()
+                .contains(&("pivot", strategy.to_string()))
+        })
+    }
+
+    /// Returns the index of the first `algorithm` variant sorting without recursion, i.e. with an
+    /// `iterative` parameter of `true`.
+    ///
+    /// If there is no such variant, returns `None`.
+    pub fn iterative_variant(algorithm: Algorithm) -> Option<usize> {
+        (0..Self::variants(algorithm).count()).find(|&variant| {
+            Self::parameters(algorithm, variant)
+                .unwrap()
+                .contains(&("iterative", true.to_string()))
+        })
+    }
+
+    /// Returns the index of the first `algorithm` variant returning early on fully sorted input,
+    /// i.e. with a `detect-sorted` parameter of `true`.
+    ///
+    /// If there is no such variant, returns `None`.
+    pub fn detect_sorted_variant(algorithm: Algorithm) -> Option<usize> {
+        (0..Self::variants(algorithm).count()).find(|&variant| {
+            Self::parameters(algorithm, variant)
+                .unwrap()
+                .contains(&("detect-sorted", true.to_string()))
+        })
+    }
+
+    /// Returns the index of the first `algorithm` variant merging in a balanced order, i.e. with a
+    /// `balanced` parameter of `true`.
+    ///
+    /// If there is no such variant, returns `None`.
+    pub fn balanced_variant(algorithm: Algorithm) -> Option<usize> {
+        (0..Self::variants(algorithm).count()).find(|&variant| {
+            Self::parameters(algorithm, variant)
+                .unwrap()
+                .contains(&("balanced", true.to_string()))
+        })
+    }
+
+    /// Returns the index of the first `algorithm` variant adapting its minimum run length to the
+    /// slice length, i.e. with an `adaptive-min-run` parameter of `true`.
+    ///
+    /// If there is no such variant, returns `None`.
+    pub fn adaptive_min_run_variant(algorithm: Algorithm) -> Option<usize> {
+        (0..Self::variants(algorithm).count()).find(|&variant| {
+            Self::parameters(algorithm, variant)
+                .unwrap()
+                .contains(&("adaptive-min-run", true.to_string()))
+        })
+    }
+
     /// Returns the given variant index as `usize` if valid and `None` otherwise.
     ///
     /// Negative values are always invalid.
@@ -327,6 +1515,253 @@ impl AlgorithmVariants {
     }
 }
 
+/// Dispatches on a value only known at runtime, binding it to the constant `NAME` in `code`.
+///
+/// Only the listed values are supported, panics for any other value.
+///
+/// # Example usage
+///
+/// ```rust
+/// with_match_const! {
+///     threshold;
+///     THRESHOLD: usize;
+///     [8, 16, 32] => {
+///         println!("{THRESHOLD}");
+///     }
+/// };
+/// ```
+macro_rules! with_match_const {
+    ($value:expr; $name:ident: $type:ty; [$($const:expr),* $(,)?] => $code:block) => {
+        match $value {
+            $(
+                value if value == $const => {
+                    const $name: $type = $const;
+
+                    $code
+                }
+            )*
+            value => panic!("Unsupported value {value} for {}", stringify!($name)),
+        }
+    };
+}
+
+/// The [`quicksort::PivotStrategy`]s of the quicksort variants, selectable with `--pivot`.
+pub const PIVOT_STRATEGIES: [&str; 6] = [
+    "first",
+    "random",
+    "median-of-three",
+    "ninther",
+    "median-of-three-or-ninther",
+    "median",
+];
+
+/// The default quicksort, choosing pivots with `P`.
+type PivotQuickSort<P> = quicksort::QuickSort<
+    quicksort::DefaultRngFactory,
+    quicksort::DefaultInsertionSort,
+    { quicksort::DEFAULT_INSERTION_THRESHOLD },
+    { quicksort::DEFAULT_NINTHER_THRESHOLD },
+    { quicksort::DEFAULT_CHECK_SORTED },
+    P,
+>;
+
+/// The supported values of `--insertion-threshold`.
+pub const QUICKSORT_INSERTION_THRESHOLDS: [usize; 6] = [3, 8, 16, 24, 32, 64];
+
+/// The supported values of `--ninther-threshold`.
+pub const QUICKSORT_NINTHER_THRESHOLDS: [usize; 4] = [64, 128, 256, 512];
+
+/// A quicksort variant with the given thresholds, see [`QuicksortThresholds`].
+type TunedQuickSort<const INSERTION: usize, const NINTHER: usize, const CHECK_SORTED: bool> =
+    quicksort::QuickSort<
+        quicksort::DefaultRngFactory,
+        quicksort::DefaultInsertionSort,
+        INSERTION,
+        NINTHER,
+        CHECK_SORTED,
+    >;
+
+/// Statically dispatches on the [`TunedQuickSort`] type for the given [`QuicksortThresholds`],
+/// see [`QUICKSORT_INSERTION_THRESHOLDS`] and [`QUICKSORT_NINTHER_THRESHOLDS`].
+///
+/// The arms index into the threshold consts, so they can not drift apart. Arms missing for a
+/// grown const panic in `test_quicksort_thresholds`, which dispatches on every threshold.
+macro_rules! with_quicksort_thresholds {
+    ($thresholds:expr; $variant_name:ident => $code:block) => {{
+        let thresholds: QuicksortThresholds = $thresholds;
+
+        with_match_const! {
+            thresholds.insertion;
+            INSERTION: usize;
+            [
+                QUICKSORT_INSERTION_THRESHOLDS[0],
+                QUICKSORT_INSERTION_THRESHOLDS[1],
+                QUICKSORT_INSERTION_THRESHOLDS[2],
+                QUICKSORT_INSERTION_THRESHOLDS[3],
+                QUICKSORT_INSERTION_THRESHOLDS[4],
+                QUICKSORT_INSERTION_THRESHOLDS[5],
+            ] => {
+                with_match_const! {
+                    thresholds.ninther;
+                    NINTHER: usize;
+                    [
+                        QUICKSORT_NINTHER_THRESHOLDS[0],
+                        QUICKSORT_NINTHER_THRESHOLDS[1],
+                        QUICKSORT_NINTHER_THRESHOLDS[2],
+                        QUICKSORT_NINTHER_THRESHOLDS[3],
+                    ] => {
+                        if thresholds.check_sorted {
+                            type $variant_name = TunedQuickSort<INSERTION, NINTHER, true>;
+
+                            $code
+                        } else {
+                            type $variant_name = TunedQuickSort<INSERTION, NINTHER, false>;
+
+                            $code
+                        }
+                    }
+                }
+            }
+        }
+    }};
+}
+
+/// The thresholds of a quicksort variant, replacing its defaults, see `--insertion-threshold` and
+/// `--ninther-threshold`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuicksortThresholds {
+    /// The `INSERTION_THRESHOLD` of [`quicksort::QuickSort`]
+    pub insertion: usize,
+    /// The `NINTHER_THRESHOLD` of [`quicksort::QuickSort`]
+    pub ninther: usize,
+    /// The `CHECK_SORTED` of [`quicksort::QuickSort`], taken from the tuned variant
+    pub check_sorted: bool,
+}
+
+impl QuicksortThresholds {
+    /// Returns the thresholds for tuning the quicksort `variant`, thresholds which are not given
+    /// keep their defaults.
+    ///
+    /// If the `variant` can not be tuned, returns `None`.
+    pub fn new(variant: usize, insertion: Option<usize>, ninther: Option<usize>) -> Option<Self> {
+        let description = AlgorithmVariants::variants(Algorithm::Quicksort).nth(variant)?;
+
+        // Only variants differing from the default thresholds in `CHECK_SORTED` can be tuned
+        let check_sorted = [false, true].into_iter().find(|&check_sorted| {
+            let tunable = Self {
+                insertion: quicksort::DEFAULT_INSERTION_THRESHOLD,
+                ninther: quicksort::DEFAULT_NINTHER_THRESHOLD,
+                check_sorted,
+            };
+
+            tunable.display() == description
+        })?;
+
+        Some(Self {
+            insertion: insertion.unwrap_or(quicksort::DEFAULT_INSERTION_THRESHOLD),
+            ninther: ninther.unwrap_or(quicksort::DEFAULT_NINTHER_THRESHOLD),
+            check_sorted,
+        })
+    }
+
+    /// Returns the multiline string representation of the tuned variant, see [`display()`].
+    pub fn display(self) -> String {
+        with_quicksort_thresholds! {
+            self;
+            Variant => {
+                display::<Variant>()
+            }
+        }
+    }
+
+    /// Returns the sorting function of the tuned variant for the given datatype `T`, wrapped in
+    /// [`Checked`] if `checked` is set.
+    pub fn sorter<T: Ord>(self, checked: bool) -> fn(&mut [T]) {
+        with_quicksort_thresholds! {
+            self;
+            Variant => {
+                if checked {
+                    <Checked<Variant> as Sort>::sort
+                } else {
+                    <Variant as Sort>::sort
+                }
+            }
+        }
+    }
+}
+
+/// Returns `threshold` if it is one of the `supported` thresholds.
+fn supported_threshold(threshold: usize, supported: &[usize]) -> Result<usize, String> {
+    if supported.contains(&threshold) {
+        Ok(threshold)
+    } else {
+        Err(format!(
+            "supported thresholds are {supported}",
+            supported = supported
+                .iter()
+                .map(usize::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ))
+    }
+}
+
+/// Parses the value of `--insertion-threshold`, see [`QUICKSORT_INSERTION_THRESHOLDS`].
+fn parse_insertion_threshold(value: &str) -> Result<usize, String> {
+    let threshold: usize = value.parse().map_err(|error| format!("{error}"))?;
+
+    // Partitioning relies on sub slices with more than 3 elements
+    if threshold < 3 {
+        return Err("quicksort needs an insertion threshold of at least 3".to_string());
+    }
+
+    supported_threshold(threshold, &QUICKSORT_INSERTION_THRESHOLDS)
+}
+
+/// Parses the value of `--kway-threshold`, see [`KWAY_THRESHOLDS`].
+fn parse_kway_threshold(value: &str) -> Result<usize, String> {
+    let threshold: usize = value.parse().map_err(|error| format!("{error}"))?;
+
+    supported_threshold(threshold, &KWAY_THRESHOLDS)
+}
+
+/// Parses the value of `--min-gallop`, see [`MIN_GALLOPS`].
+fn parse_min_gallop(value: &str) -> Result<usize, String> {
+    let min_gallop: usize = value.parse().map_err(|error| format!("{error}"))?;
+
+    supported_threshold(min_gallop, &MIN_GALLOPS)
+}
+
+/// Parses the value of `--min-run`.
+fn parse_min_run(value: &str) -> Result<usize, String> {
+    let min_run: usize = value.parse().map_err(|error| format!("{error}"))?;
+
+    // Runs of length `min_run - 1` need at least two elements
+    if min_run < 3 {
+        return Err("the minimum run length needs to be at least 3".to_string());
+    }
+
+    Ok(min_run)
+}
+
+/// Parses the value of `--align`.
+fn parse_alignment(value: &str) -> Result<usize, String> {
+    let align: usize = value.parse().map_err(|error| format!("{error}"))?;
+
+    if !align.is_power_of_two() {
+        return Err("the alignment needs to be a power of two".to_string());
+    }
+
+    Ok(align)
+}
+
+/// Parses the value of `--ninther-threshold`, see [`QUICKSORT_NINTHER_THRESHOLDS`].
+fn parse_ninther_threshold(value: &str) -> Result<usize, String> {
+    let threshold: usize = value.parse().map_err(|error| format!("{error}"))?;
+
+    supported_threshold(threshold, &QUICKSORT_NINTHER_THRESHOLDS)
+}
+
 /// Declare the available data types and distributions variants.
 ///
 /// We the input to generate a macro that statically dispatches on the type, given a value.
@@ -434,10 +1869,51 @@ declare_data_types! {
     /// Random runs with average length of `3000000` of u32 values
     RandomRuns3000000U32 = u32 : crate::data::RandomRunsConstData<3000000>,
 
+    /// Uniformly random u32 values out of `0..d`, see `--distinct`
+    FewDistinctU32       = u32 : crate::data::FewDistinctData,
+    /// Ascending and then descending u32 values `0, 1, ..., n / 2, ..., 1, 0`
+    OrganPipeU32         = u32 : crate::data::OrganPipeData,
+    /// Reverse sorted u32 values `n - 1, n - 2, ..., 0`
+    DescendingU32        = u32 : crate::data::DescendingData,
+    /// Ascending runs of u32 values with lengths just below, at and above `--min-run`
+    BoundaryRunsU32      = u32 : crate::data::BoundaryRunsData,
+    /// The u32 values `0..n` with each block of length `--block` reversed
+    ReversedBlocksU32    = u32 : crate::data::ReversedBlocksData,
+
     /// A random permutation of L+P blobs
     PermutationLP    = Blob2U64CmpFirst : crate::data::PermutationData,
     /// Random runs with average length of `n.isqrt()` of L+P blobs
     RandomRunsSqrtLP = Blob2U64CmpFirst : crate::data::RandomRunsSqrtData,
+
+    /// A random permutation of u32 values with costly comparisons, see `--compare-cost`
+    CostlyU32 = crate::data::CostlyOrdered<0, u32> : crate::data::PermutationData,
+
+    /// Random lowercase ASCII strings of length `16`
+    RandomString16     = String : crate::data::StringData<16, 16>,
+    /// Random lowercase ASCII strings with lengths out of `0..=32`
+    RandomStringVar32  = String : crate::data::StringData<0, 32>,
+}
+
+impl DataType {
+    /// Returns whether this data type uses [`crate::data::FewDistinctData`].
+    pub fn is_few_distinct(self) -> bool {
+        matches!(self, DataType::FewDistinctU32)
+    }
+
+    /// Returns whether this data type uses [`crate::data::BoundaryRunsData`].
+    pub fn is_boundary_runs(self) -> bool {
+        matches!(self, DataType::BoundaryRunsU32)
+    }
+
+    /// Returns whether this data type uses [`crate::data::ReversedBlocksData`].
+    pub fn is_reversed_blocks(self) -> bool {
+        matches!(self, DataType::ReversedBlocksU32)
+    }
+
+    /// Returns whether this data type uses [`crate::data::CostlyOrdered`] elements.
+    pub fn is_costly(self) -> bool {
+        matches!(self, DataType::CostlyU32)
+    }
 }
 
 impl std::fmt::Display for DataType {
@@ -0,0 +1,98 @@
+//! Measuring the CPU time of the measuring thread, see `--time-source`.
+
+/// A measurement of the CPU time consumed so far, analogous to [`std::time::Instant`].
+///
+/// On Unix this is the CPU time of the calling thread reported by `clock_gettime` for
+/// `CLOCK_THREAD_CPUTIME_ID`, in nanosecond resolution. Elsewhere no CPU time is available, so the
+/// wall clock is used instead.
+#[derive(Debug, Clone, Copy)]
+pub struct CpuInstant(
+    #[cfg(unix)] std::time::Duration,
+    #[cfg(not(unix))] std::time::Instant,
+);
+
+#[cfg(unix)]
+impl CpuInstant {
+    /// Returns the CPU time consumed so far.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `clock_gettime` fails, which does not happen for valid arguments.
+    pub fn now() -> Self {
+        // SAFETY: `timespec` is a plain struct of integers, for which all zeros is a valid value,
+        // and it outlives the system call writing it.
+        let (result, time) = unsafe {
+            let mut time: libc::timespec = std::mem::zeroed();
+            let result = libc::clock_gettime(libc::CLOCK_THREAD_CPUTIME_ID, &mut time);
+            (result, time)
+        };
+        assert_eq!(
+            result,
+            0,
+            "clock_gettime failed: {}",
+            std::io::Error::last_os_error()
+        );
+
+        Self(duration(time))
+    }
+
+    /// Returns the CPU time consumed since this instant was measured.
+    pub fn elapsed(&self) -> std::time::Duration {
+        Self::now().0.saturating_sub(self.0)
+    }
+}
+
+#[cfg(not(unix))]
+impl CpuInstant {
+    /// Returns the current wall clock time, since no CPU time is available.
+    pub fn now() -> Self {
+        Self(std::time::Instant::now())
+    }
+
+    /// Returns the wall clock time elapsed since this instant was measured.
+    pub fn elapsed(&self) -> std::time::Duration {
+        self.0.elapsed()
+    }
+}
+
+/// Converts the given `time` into a [`std::time::Duration`].
+#[cfg(unix)]
+fn duration(time: libc::timespec) -> std::time::Duration {
+    std::time::Duration::new(
+        u64::try_from(time.tv_sec).expect("CPU time is never negative"),
+        u32::try_from(time.tv_nsec).expect("Nanoseconds are in 0..1_000_000_000"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_busy_loop() {
+        // The wall clock and CPU time are not read at exactly the same instants
+        const SLACK: std::time::Duration = std::time::Duration::from_millis(1);
+
+        let wall = std::time::Instant::now();
+        let cpu = CpuInstant::now();
+
+        let mut previous = std::time::Duration::ZERO;
+        let mut counter = 0u64;
+        while wall.elapsed() < std::time::Duration::from_millis(50) {
+            counter = std::hint::black_box(counter.wrapping_add(1));
+
+            let elapsed = cpu.elapsed();
+            assert!(elapsed >= previous, "CPU time decreased");
+            previous = elapsed;
+        }
+
+        let cpu_elapsed = cpu.elapsed();
+        let wall_elapsed = wall.elapsed();
+
+        assert!(cpu_elapsed > std::time::Duration::ZERO);
+        assert!(
+            cpu_elapsed <= wall_elapsed + SLACK,
+            "CPU time {cpu_elapsed:?} exceeds wall time {wall_elapsed:?}"
+        );
+    }
+}
@@ -1,6 +1,25 @@
 //! Contains various structs used to measure differences when being sorted
 
-use rand::{distr::Distribution as _, seq::SliceRandom};
+use rand::{SeedableRng as _, distr::Distribution as _, seq::SliceRandom};
+
+/// The RNG used for data generation.
+pub type DataRng = rand::rngs::StdRng;
+
+/// Creates the [`DataRng`] used for data generation from the given `seed`.
+///
+/// The seed is expanded into the full 32 byte seed of [`DataRng`] using
+/// [`rand::SeedableRng::seed_from_u64()`], so equal seeds always produce equal data.
+pub fn seeded_rng(seed: u64) -> DataRng {
+    DataRng::seed_from_u64(seed)
+}
+
+/// Creates the [`DataRng`] used to generate the data of the experiment run `run`, given the base
+/// `seed` of the experiment.
+///
+/// This makes sure each run sorts identical data, regardless of the algorithm measured.
+pub fn run_rng(seed: u64, run: usize) -> DataRng {
+    seeded_rng(seed.wrapping_add(u64::try_from(run).expect("usize should fit in a u64")))
+}
 
 /// Used to define ways to compare [`Blobs`](Blob)
 pub trait BlobComparisonMethod<T: Ord, const N: usize>: std::fmt::Debug {
@@ -153,12 +172,47 @@ impl GlobalCounter {
             .fetch_add(amount, std::sync::atomic::Ordering::Relaxed);
     }
 
+    /// Increases the counter to `value`, if it is currently less than `value`.
+    pub fn increase_to(&self, value: u64) {
+        self.0
+            .fetch_max(value, std::sync::atomic::Ordering::Relaxed);
+    }
+
     /// Returns the current value of the counter and resets it to `0`
     pub fn read_and_reset(&self) -> u64 {
         self.0.swap(0, std::sync::atomic::Ordering::Relaxed)
     }
 }
 
+std::thread_local! {
+    /// Whether comparisons on this thread are currently left uncounted, see [`uncounted()`].
+    static UNCOUNTED: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+}
+
+/// Executes `f` without counting the comparisons it performs, neither those of
+/// [`CountComparisons`] nor those of test elements counting their comparisons.
+///
+/// Debug checks comparing elements use this, so debug builds count the same comparisons as
+/// release builds.
+pub fn uncounted<R>(f: impl FnOnce() -> R) -> R {
+    /// Restores the previous state when dropped, even if `f` panics.
+    struct Restore(bool);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            UNCOUNTED.set(self.0);
+        }
+    }
+
+    let _restore = Restore(UNCOUNTED.replace(true));
+    f()
+}
+
+/// Returns whether comparisons on this thread are currently counted, see [`uncounted()`].
+pub fn is_counting() -> bool {
+    !UNCOUNTED.get()
+}
+
 /// A generic wrapper around a comparable elements, that tracks the number of times the element
 /// has been compared.
 ///
@@ -170,9 +224,11 @@ impl GlobalCounter {
 pub struct CountComparisons<T>(T);
 
 impl<T> CountComparisons<T> {
-    /// Increases the comparison counter by `amount`
+    /// Increases the comparison counter by `amount`, unless comparisons are [`uncounted()`]
     fn increase_counter(amount: u64) {
-        crate::GLOBAL_COUNTERS.comparisons.increase(amount);
+        if is_counting() {
+            crate::GLOBAL_COUNTERS.comparisons.increase(amount);
+        }
     }
 }
 
@@ -210,6 +266,161 @@ impl<T: TryFrom<usize>> TryFrom<usize> for CountComparisons<T> {
     }
 }
 
+impl<T: From<String>> From<String> for CountComparisons<T> {
+    fn from(value: String) -> Self {
+        Self(T::from(value))
+    }
+}
+
+/// The cost in nanoseconds added to every comparison of [`CostlyOrdered`] elements, see
+/// `--compare-cost`.
+static COMPARE_COST: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
+/// Sets the cost in nanoseconds added to every comparison of [`CostlyOrdered`] elements.
+pub fn set_compare_cost(nanos: u64) {
+    COMPARE_COST.store(nanos, std::sync::atomic::Ordering::Relaxed);
+}
+
+/// A generic wrapper around comparable elements, that busy waits for `NANOS` nanoseconds plus
+/// the cost set by [`set_compare_cost()`] in every comparison.
+///
+/// This simulates expensive comparisons like those of strings or structs, so comparisons dominate
+/// the running time instead of moving elements.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy)]
+pub struct CostlyOrdered<const NANOS: u64, T>(T);
+
+impl<const NANOS: u64, T> CostlyOrdered<NANOS, T> {
+    /// Busy waits for the cost of a single comparison
+    fn spend_cost() {
+        let nanos = NANOS + COMPARE_COST.load(std::sync::atomic::Ordering::Relaxed);
+        if nanos == 0 {
+            return;
+        }
+
+        let cost = std::time::Duration::from_nanos(nanos);
+        let start = std::time::Instant::now();
+        while start.elapsed() < cost {
+            std::hint::spin_loop();
+        }
+    }
+}
+
+impl<const NANOS: u64, T: PartialEq> PartialEq for CostlyOrdered<NANOS, T> {
+    fn eq(&self, other: &Self) -> bool {
+        Self::spend_cost();
+
+        self.0 == other.0
+    }
+}
+
+impl<const NANOS: u64, T: Eq> Eq for CostlyOrdered<NANOS, T> {}
+
+impl<const NANOS: u64, T: PartialOrd> PartialOrd for CostlyOrdered<NANOS, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Self::spend_cost();
+
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+impl<const NANOS: u64, T: Ord> Ord for CostlyOrdered<NANOS, T> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        Self::spend_cost();
+
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<const NANOS: u64, T: TryFrom<usize>> TryFrom<usize> for CostlyOrdered<NANOS, T> {
+    type Error = T::Error;
+
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        T::try_from(value).map(Self)
+    }
+}
+
+/// A Wrapper struct that tracks an original index with an ordered element.
+///
+/// Intended to check sort results for stability.
+///
+/// When compared, the call is intentionally forwarded to the implementation of `T`.
+/// To check for stable sorting, see [`Self::is_stable_sorted()`]
+#[derive(Debug, Clone)]
+pub struct IndexedOrdered<T: Ord>(usize, T);
+
+impl<T: Ord> IndexedOrdered<T> {
+    /// Creates a new iterator of `IndexedOrdered`, tracking the position of each element in `iter`.
+    pub fn map_iter(iter: impl Iterator<Item = T>) -> impl Iterator<Item = Self> {
+        iter.enumerate()
+            .map(|(index, element)| Self(index, element))
+    }
+
+    /// Returns the initial index of this element.
+    pub fn index(&self) -> usize {
+        self.0
+    }
+
+    /// Checks that `iter` is sorted and check for stability, e.g. equal elements keeping their
+    /// initial relative ordering.
+    ///
+    /// Returns `Ok(result)` if `iter` is sorted with regards to `T` where `result` indicates if
+    /// the sort is stable. Otherwise, returns `Err(())` if `iter` was not sorted with regards to
+    /// `T`.
+    #[allow(dead_code, reason = "Currently only used in tests")]
+    pub fn is_stable_sorted<'a>(mut iter: impl Iterator<Item = &'a Self>) -> Result<bool, ()>
+    where
+        T: 'a,
+    {
+        let Some(mut previous) = iter.next() else {
+            return Ok(true);
+        };
+
+        for current in iter {
+            match current.cmp(previous) {
+                // Slice is not sorted
+                std::cmp::Ordering::Less => return Err(()),
+                // Elements are not stable
+                std::cmp::Ordering::Equal if current.0 < previous.0 => return Ok(false),
+                _ => {}
+            }
+
+            previous = current;
+        }
+
+        Ok(true)
+    }
+
+    /// Returns the index of the first element in `slice` that is equal to its predecessor but
+    /// initially came before it, or `None` if equal elements kept their initial relative ordering.
+    pub fn first_unstable_index(slice: &[Self]) -> Option<usize> {
+        slice
+            .windows(2)
+            .position(|pair| pair[0] == pair[1] && pair[0].0 > pair[1].0)
+            .map(|index| index + 1)
+    }
+}
+
+impl<T: Ord> PartialEq for IndexedOrdered<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.1 == other.1
+    }
+}
+
+impl<T: Ord> Eq for IndexedOrdered<T> {}
+
+impl<T: Ord> PartialOrd for IndexedOrdered<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Ord> Ord for IndexedOrdered<T> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.1.cmp(&other.1)
+    }
+}
+
 /// A random permutation data distribution
 #[derive(Debug, Clone, Copy, Default)]
 pub struct PermutationData;
@@ -226,11 +437,86 @@ pub struct RandomRunsSqrtData;
 #[derive(Debug, Clone, Copy, Default)]
 pub struct RandomRunsConstData<const LENGTH: usize>;
 
+/// A distribution of `n` elements sampled uniformly from `0..d`, producing many duplicates for
+/// small `d`.
+///
+/// If `d` is not given, `n.isqrt()` is used.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FewDistinctData(Option<std::num::NonZeroUsize>);
+
+/// An "organ pipe" distribution `0, 1, ..., n / 2, ..., 1, 0`, ascending and then descending.
+///
+/// This consists of exactly two long runs, but is an adversarial case for naive quicksort pivots.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OrganPipeData;
+
+/// A reverse sorted distribution `n - 1, n - 2, ..., 0`.
+///
+/// This is a single strictly decreasing run, the worst case for insertion sort but the best case
+/// for sorts detecting and reversing decreasing runs.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DescendingData;
+
+/// A permutation of ascending runs with lengths chosen randomly out of `m - 1`, `m` and `m + 1`
+/// for a minimum run length `m`, only the last run may be shorter.
+///
+/// Each run ends with a larger element than the next run starts with, so the runs are exactly the
+/// natural runs found by the sorts. This stresses sorts extending short runs to a minimum length,
+/// like [`crate::algorithms::powersort::PowerSort`].
+///
+/// If `m` is not given, [`crate::algorithms::powersort::DEFAULT_MIN_RUN_LENGTH`] is used.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BoundaryRunsData(Option<usize>);
+
+/// The sorted values `0..n` with each contiguous block of length `b` reversed, only the last block
+/// may be shorter.
+///
+/// Consecutive blocks do not overlap in their values, so this consists of exactly `n.div_ceil(b)`
+/// strictly decreasing runs, which sorts detecting and reversing decreasing runs turn into a
+/// sequence of already ordered runs.
+///
+/// If `b` is not given, `n.isqrt()` is used.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ReversedBlocksData(Option<std::num::NonZeroUsize>);
+
+/// Random strings of lowercase ASCII letters with lengths sampled uniformly from
+/// `MIN_LENGTH..=MAX_LENGTH`, i.e. of fixed length if both are equal.
+///
+/// Comparing strings costs more the longer their common prefix, unlike the integer distributions.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StringData<const MIN_LENGTH: usize, const MAX_LENGTH: usize>;
+
+/// Runtime options for the construction of [`DataGenerator`]s.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DataOptions {
+    /// The number of distinct values used by [`FewDistinctData`]
+    pub distinct: Option<std::num::NonZeroUsize>,
+    /// The minimum run length `m` used by [`BoundaryRunsData`], at least `3`
+    pub min_run: Option<usize>,
+    /// The block length `b` used by [`ReversedBlocksData`]
+    pub block: Option<std::num::NonZeroUsize>,
+    /// The alignment in bytes the data of measured runs is moved to before sorting, see
+    /// [`crate::aligned::AlignedBox`]
+    pub align: Option<usize>,
+}
+
 /// Used to generate the data to be sorted.
 pub trait DataGenerator<T: Ord + std::fmt::Debug>: Default {
+    /// Constructs a generator using the given `options`, ignoring options not applicable to it.
+    fn from_options(_options: &DataOptions) -> Self {
+        Self::default()
+    }
+
     /// Initialize a vector of the given size
     fn initialize(&mut self, size: usize, rng: &mut impl rand::Rng) -> Vec<T>;
 
+    /// Replaces the contents of `buffer` with `size` elements, exactly like
+    /// [`Self::initialize()`] for the same `rng`, but reusing the allocation of `buffer` where
+    /// the generator supports it.
+    fn initialize_into(&mut self, buffer: &mut Vec<T>, size: usize, rng: &mut impl rand::Rng) {
+        *buffer = self.initialize(size, rng);
+    }
+
     /// Reinitialize the given slice of elements
     fn reinitialize(&mut self, slice: &mut [T], rng: &mut impl rand::Rng);
 }
@@ -248,6 +534,13 @@ where
         values
     }
 
+    fn initialize_into(&mut self, buffer: &mut Vec<T>, size: usize, rng: &mut impl rand::Rng) {
+        buffer.clear();
+        buffer.extend((0..size).map(|i| T::try_from(i).unwrap()));
+
+        self.reinitialize(buffer, rng);
+    }
+
     fn reinitialize(&mut self, slice: &mut [T], rng: &mut impl rand::Rng) {
         slice.shuffle(rng);
     }
@@ -314,3 +607,670 @@ where
         RandomRunsData(LENGTH).reinitialize(slice, rng);
     }
 }
+
+impl<T> DataGenerator<T> for FewDistinctData
+where
+    T: Ord + TryFrom<usize> + std::fmt::Debug,
+    <T as TryFrom<usize>>::Error: std::fmt::Debug,
+{
+    fn from_options(options: &DataOptions) -> Self {
+        Self(options.distinct)
+    }
+
+    fn initialize(&mut self, size: usize, rng: &mut impl rand::Rng) -> Vec<T> {
+        let distinct = self.distinct(size);
+
+        (0..size)
+            .map(|_| T::try_from(rng.random_range(0..distinct)).unwrap())
+            .collect()
+    }
+
+    fn initialize_into(&mut self, buffer: &mut Vec<T>, size: usize, rng: &mut impl rand::Rng) {
+        let distinct = self.distinct(size);
+
+        buffer.clear();
+        buffer.extend((0..size).map(|_| T::try_from(rng.random_range(0..distinct)).unwrap()));
+    }
+
+    fn reinitialize(&mut self, slice: &mut [T], rng: &mut impl rand::Rng) {
+        let distinct = self.distinct(slice.len());
+
+        for element in slice {
+            *element = T::try_from(rng.random_range(0..distinct)).unwrap();
+        }
+    }
+}
+
+impl<T> DataGenerator<T> for OrganPipeData
+where
+    T: Ord + TryFrom<usize> + std::fmt::Debug,
+    <T as TryFrom<usize>>::Error: std::fmt::Debug,
+{
+    fn initialize(&mut self, size: usize, _rng: &mut impl rand::Rng) -> Vec<T> {
+        (0..size)
+            .map(|i| T::try_from(std::cmp::min(i, size - 1 - i)).unwrap())
+            .collect()
+    }
+
+    fn reinitialize(&mut self, slice: &mut [T], _rng: &mut impl rand::Rng) {
+        let size = slice.len();
+
+        for (i, element) in slice.iter_mut().enumerate() {
+            *element = T::try_from(std::cmp::min(i, size - 1 - i)).unwrap();
+        }
+    }
+}
+
+impl<T> DataGenerator<T> for DescendingData
+where
+    T: Ord + TryFrom<usize> + std::fmt::Debug,
+    <T as TryFrom<usize>>::Error: std::fmt::Debug,
+{
+    fn initialize(&mut self, size: usize, _rng: &mut impl rand::Rng) -> Vec<T> {
+        (0..size).rev().map(|i| T::try_from(i).unwrap()).collect()
+    }
+
+    fn reinitialize(&mut self, slice: &mut [T], _rng: &mut impl rand::Rng) {
+        let size = slice.len();
+
+        for (i, element) in slice.iter_mut().enumerate() {
+            *element = T::try_from(size - 1 - i).unwrap();
+        }
+    }
+}
+
+impl<T> DataGenerator<T> for BoundaryRunsData
+where
+    T: Ord + TryFrom<usize> + std::fmt::Debug,
+    <T as TryFrom<usize>>::Error: std::fmt::Debug,
+{
+    fn from_options(options: &DataOptions) -> Self {
+        Self(options.min_run)
+    }
+
+    fn initialize(&mut self, size: usize, rng: &mut impl rand::Rng) -> Vec<T> {
+        let mut values = PermutationData.initialize(size, rng);
+
+        self.reinitialize(&mut values, rng);
+
+        values
+    }
+
+    fn reinitialize(&mut self, slice: &mut [T], rng: &mut impl rand::Rng) {
+        let min_run = self.min_run();
+
+        PermutationData.reinitialize(slice, rng);
+
+        let mut start = 0;
+        while start < slice.len() {
+            let len = std::cmp::min(
+                rng.random_range(min_run - 1..=min_run + 1),
+                slice.len() - start,
+            );
+            slice[start..start + len].sort();
+
+            // Swap the maximum of the previous run with the minimum of this run, if the previous
+            // run would continue otherwise. Both runs stay sorted and the previous run is at least
+            // two elements long, so its start is unaffected.
+            if start > 0 && slice[start - 1] < slice[start] {
+                slice.swap(start - 1, start);
+            }
+
+            start += len;
+        }
+    }
+}
+
+impl<T> DataGenerator<T> for ReversedBlocksData
+where
+    T: Ord + TryFrom<usize> + std::fmt::Debug,
+    <T as TryFrom<usize>>::Error: std::fmt::Debug,
+{
+    fn from_options(options: &DataOptions) -> Self {
+        Self(options.block)
+    }
+
+    fn initialize(&mut self, size: usize, rng: &mut impl rand::Rng) -> Vec<T> {
+        let mut values = (0..size)
+            .map(|i| T::try_from(i).unwrap())
+            .collect::<Vec<_>>();
+
+        self.reinitialize(&mut values, rng);
+
+        values
+    }
+
+    fn reinitialize(&mut self, slice: &mut [T], _rng: &mut impl rand::Rng) {
+        let block = self.block(slice.len());
+
+        for (i, element) in slice.iter_mut().enumerate() {
+            *element = T::try_from(i).unwrap();
+        }
+        for block in slice.chunks_mut(block) {
+            block.reverse();
+        }
+    }
+}
+
+impl<T, const MIN_LENGTH: usize, const MAX_LENGTH: usize> DataGenerator<T>
+    for StringData<MIN_LENGTH, MAX_LENGTH>
+where
+    T: Ord + From<String> + std::fmt::Debug,
+{
+    fn initialize(&mut self, size: usize, rng: &mut impl rand::Rng) -> Vec<T> {
+        (0..size)
+            .map(|_| T::from(Self::random_string(rng)))
+            .collect()
+    }
+
+    fn reinitialize(&mut self, slice: &mut [T], rng: &mut impl rand::Rng) {
+        for element in slice {
+            *element = T::from(Self::random_string(rng));
+        }
+    }
+}
+
+impl<const MIN_LENGTH: usize, const MAX_LENGTH: usize> StringData<MIN_LENGTH, MAX_LENGTH> {
+    /// Returns a random string of lowercase ASCII letters with a length out of
+    /// `MIN_LENGTH..=MAX_LENGTH`.
+    fn random_string(rng: &mut impl rand::Rng) -> String {
+        const {
+            assert!(
+                MIN_LENGTH <= MAX_LENGTH,
+                "MIN_LENGTH must be at most MAX_LENGTH"
+            )
+        };
+
+        let length = rng.random_range(MIN_LENGTH..=MAX_LENGTH);
+
+        (0..length)
+            .map(|_| char::from(rng.random_range(b'a'..=b'z')))
+            .collect()
+    }
+}
+
+impl ReversedBlocksData {
+    /// Returns the block length `b` to use for slices of length `size`.
+    fn block(&self, size: usize) -> usize {
+        self.0
+            .map_or(std::cmp::max(size.isqrt(), 1), std::num::NonZeroUsize::get)
+    }
+}
+
+impl BoundaryRunsData {
+    /// Returns the minimum run length `m` to generate runs around.
+    fn min_run(&self) -> usize {
+        let min_run = self
+            .0
+            .unwrap_or(crate::algorithms::powersort::DEFAULT_MIN_RUN_LENGTH);
+        assert!(min_run >= 3, "Runs need to be at least two elements long");
+
+        min_run
+    }
+}
+
+impl FewDistinctData {
+    /// Returns the number of distinct values to use for slices of length `size`.
+    fn distinct(&self, size: usize) -> usize {
+        self.0
+            .map_or(std::cmp::max(size.isqrt(), 1), std::num::NonZeroUsize::get)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Generates data of type `D` twice with the same seed and checks it is identical.
+    fn test_reproducible<D: DataGenerator<u32>>() {
+        let mut first_rng = seeded_rng(crate::test::TEST_SEED);
+        let mut second_rng = seeded_rng(crate::test::TEST_SEED);
+        let (mut first_generator, mut second_generator) = (D::default(), D::default());
+
+        let mut first = first_generator.initialize(crate::test::DEFAULT_TEST_SIZE, &mut first_rng);
+        let mut second =
+            second_generator.initialize(crate::test::DEFAULT_TEST_SIZE, &mut second_rng);
+        assert_eq!(first, second);
+
+        first_generator.reinitialize(&mut first, &mut first_rng);
+        second_generator.reinitialize(&mut second, &mut second_rng);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_seeded_data_is_reproducible() {
+        test_reproducible::<PermutationData>();
+        test_reproducible::<RandomRunsSqrtData>();
+        test_reproducible::<RandomRunsConstData<30>>();
+        test_reproducible::<FewDistinctData>();
+        test_reproducible::<OrganPipeData>();
+        test_reproducible::<DescendingData>();
+        test_reproducible::<BoundaryRunsData>();
+        test_reproducible::<ReversedBlocksData>();
+    }
+
+    /// Checks that [`DataGenerator::initialize_into()`] of `D` yields the same data as
+    /// [`DataGenerator::initialize()`] for the same seed, whatever `buffer` held before.
+    fn test_initialize_into<D: DataGenerator<u32>>() {
+        let mut generator = D::default();
+        let mut buffer: Vec<u32> = vec![7; 2 * crate::test::DEFAULT_TEST_SIZE];
+
+        for size in [crate::test::DEFAULT_TEST_SIZE, 100, 2_000] {
+            for seed in 0..3 {
+                let expected = generator.initialize(size, &mut seeded_rng(seed));
+                generator.initialize_into(&mut buffer, size, &mut seeded_rng(seed));
+                assert_eq!(buffer, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_initialize_into_matches_initialize() {
+        test_initialize_into::<PermutationData>();
+        test_initialize_into::<RandomRunsSqrtData>();
+        test_initialize_into::<FewDistinctData>();
+        test_initialize_into::<OrganPipeData>();
+        test_initialize_into::<BoundaryRunsData>();
+
+        // The overriding generators keep the allocation
+        let mut buffer: Vec<u32> = Vec::with_capacity(crate::test::DEFAULT_TEST_SIZE);
+        let pointer = buffer.as_ptr();
+        let mut rng = seeded_rng(crate::test::TEST_SEED);
+        for _ in 0..crate::test::DEFAULT_TEST_RUNS {
+            PermutationData.initialize_into(&mut buffer, crate::test::DEFAULT_TEST_SIZE, &mut rng);
+            FewDistinctData::default().initialize_into(&mut buffer, 100, &mut rng);
+        }
+        assert_eq!(buffer.as_ptr(), pointer);
+    }
+
+    #[test]
+    fn test_sort_descending() {
+        use crate::algorithms::{
+            Sort, StdSort, blocksort, insertionsort, mergesort, patiencesort, peeksort, powersort,
+            quicksort, timsort,
+        };
+
+        /// Sorts descending data with `S`, checking that it results in `0..n`.
+        fn check_sorted<S: Sort>() {
+            let mut rng = seeded_rng(crate::test::TEST_SEED);
+            let mut values: Vec<u32> =
+                DescendingData.initialize(crate::test::DEFAULT_TEST_SIZE, &mut rng);
+
+            S::sort(&mut values);
+            assert!(
+                values
+                    .iter()
+                    .copied()
+                    .eq(0..values.len().try_into().unwrap()),
+                "{} did not sort descending data",
+                S::name()
+            );
+
+            DescendingData.reinitialize(&mut values, &mut rng);
+            assert!(
+                values
+                    .iter()
+                    .rev()
+                    .copied()
+                    .eq(0..values.len().try_into().unwrap())
+            );
+        }
+
+        check_sorted::<StdSort>();
+        check_sorted::<insertionsort::InsertionSort>();
+        check_sorted::<quicksort::QuickSort>();
+        check_sorted::<peeksort::PeekSort>();
+        check_sorted::<peeksort::MultiwayPeekSort>();
+        check_sorted::<mergesort::MergeSort>();
+        check_sorted::<timsort::TimSort>();
+        check_sorted::<timsort::MultiwayTimSort>();
+        check_sorted::<powersort::PowerSort>();
+        check_sorted::<powersort::MultiwayPowerSort>();
+        check_sorted::<patiencesort::PatienceSort>();
+        check_sorted::<blocksort::BlockSort>();
+    }
+
+    #[test]
+    fn test_organ_pipe_has_two_runs() {
+        /// Counts the maximal weakly increasing or strictly decreasing runs in `slice`.
+        fn count_runs(slice: &[u32]) -> usize {
+            let mut runs = 0;
+            let mut start = 0;
+            while start < slice.len() {
+                let mut end = start + 1;
+                if end < slice.len() && slice[end] < slice[start] {
+                    while end < slice.len() && slice[end] < slice[end - 1] {
+                        end += 1;
+                    }
+                } else {
+                    while end < slice.len() && slice[end] >= slice[end - 1] {
+                        end += 1;
+                    }
+                }
+
+                runs += 1;
+                start = end;
+            }
+
+            runs
+        }
+
+        let mut rng = seeded_rng(crate::test::TEST_SEED);
+        for size in [9, 10, crate::test::DEFAULT_TEST_SIZE] {
+            let mut values: Vec<u32> = OrganPipeData.initialize(size, &mut rng);
+
+            assert_eq!(values.first(), Some(&0));
+            assert_eq!(values.last(), Some(&0));
+            assert_eq!(
+                values.iter().max().copied(),
+                u32::try_from((size - 1) / 2).ok()
+            );
+            assert_eq!(count_runs(&values), 2);
+
+            values.reverse();
+            OrganPipeData.reinitialize(&mut values, &mut rng);
+            assert_eq!(count_runs(&values), 2);
+        }
+    }
+
+    #[test]
+    fn test_single_distinct_value() {
+        use crate::algorithms::{
+            Sort, StdSort, blocksort, insertionsort, mergesort, patiencesort, peeksort, powersort,
+            quicksort, timsort,
+        };
+
+        /// Sorts index tagged `values` with `S`, checking that they are unchanged.
+        fn check_unchanged<S: Sort>(values: &[u32]) {
+            let original: Vec<_> = IndexedOrdered::map_iter(values.iter().copied()).collect();
+            let mut sorted = original.clone();
+
+            S::sort(&mut sorted);
+
+            assert!(sorted.is_sorted());
+            assert!(
+                sorted
+                    .iter()
+                    .map(|element| element.1)
+                    .eq(values.iter().copied()),
+                "{} changed the values",
+                S::name()
+            );
+            if S::IS_STABLE {
+                assert!(
+                    sorted.iter().map(IndexedOrdered::index).eq(0..values.len()),
+                    "{} reordered equal elements",
+                    S::name()
+                );
+            }
+        }
+
+        let options = DataOptions {
+            distinct: std::num::NonZeroUsize::new(1),
+            ..DataOptions::default()
+        };
+        let values: Vec<u32> = <FewDistinctData as DataGenerator<u32>>::from_options(&options)
+            .initialize(
+                crate::test::DEFAULT_TEST_SIZE,
+                &mut seeded_rng(crate::test::TEST_SEED),
+            );
+
+        assert_eq!(values.len(), crate::test::DEFAULT_TEST_SIZE);
+        assert!(values.iter().all(|&value| value == values[0]));
+
+        check_unchanged::<StdSort>(&values);
+        check_unchanged::<StdSort<false>>(&values);
+        check_unchanged::<insertionsort::InsertionSort>(&values);
+        check_unchanged::<quicksort::QuickSort>(&values);
+        check_unchanged::<peeksort::PeekSort>(&values);
+        check_unchanged::<mergesort::MergeSort>(&values);
+        check_unchanged::<timsort::TimSort>(&values);
+        check_unchanged::<timsort::MultiwayTimSort>(&values);
+        check_unchanged::<powersort::PowerSort>(&values);
+        check_unchanged::<powersort::MultiwayPowerSort>(&values);
+        check_unchanged::<patiencesort::PatienceSort>(&values);
+        check_unchanged::<blocksort::BlockSort>(&values);
+    }
+
+    #[test]
+    fn test_boundary_runs() {
+        /// Returns the lengths of the maximal weakly increasing runs in `slice`.
+        fn run_lengths(slice: &[u32]) -> Vec<usize> {
+            let mut lengths = Vec::new();
+            let mut start = 0;
+            for end in 1..=slice.len() {
+                if end == slice.len() || slice[end] < slice[end - 1] {
+                    lengths.push(end - start);
+                    start = end;
+                }
+            }
+
+            lengths
+        }
+
+        let mut rng = seeded_rng(crate::test::TEST_SEED);
+        for min_run in [None, Some(3), Some(4), Some(100)] {
+            let options = DataOptions {
+                min_run,
+                ..DataOptions::default()
+            };
+            let mut generator = <BoundaryRunsData as DataGenerator<u32>>::from_options(&options);
+            let min_run = min_run.unwrap_or(crate::algorithms::powersort::DEFAULT_MIN_RUN_LENGTH);
+
+            let mut values: Vec<u32> =
+                generator.initialize(crate::test::DEFAULT_TEST_SIZE, &mut rng);
+            for _ in 0..2 {
+                let mut sorted = values.clone();
+                sorted.sort();
+                assert!(
+                    sorted
+                        .iter()
+                        .copied()
+                        .eq(0..values.len().try_into().unwrap())
+                );
+
+                let lengths = run_lengths(&values);
+                let (last, lengths) = lengths.split_last().unwrap();
+                assert!((1..=min_run + 1).contains(last));
+                assert!(
+                    lengths
+                        .iter()
+                        .all(|length| (min_run - 1..=min_run + 1).contains(length))
+                );
+
+                // All three lengths around the boundary occur
+                for length in min_run - 1..=min_run + 1 {
+                    assert!(lengths.contains(&length), "No run of length {length}");
+                }
+
+                generator.reinitialize(&mut values, &mut rng);
+            }
+        }
+    }
+
+    #[test]
+    fn test_reversed_blocks() {
+        use crate::algorithms::{Sort, blocksort, peeksort, powersort, timsort};
+
+        /// Returns the lengths of the maximal strictly decreasing runs in `slice`.
+        fn decreasing_run_lengths(slice: &[u32]) -> Vec<usize> {
+            let mut lengths = Vec::new();
+            let mut start = 0;
+            for end in 1..=slice.len() {
+                if end == slice.len() || slice[end] > slice[end - 1] {
+                    lengths.push(end - start);
+                    start = end;
+                }
+            }
+
+            lengths
+        }
+
+        /// Sorts `values` with `S`, checking that it results in `0..n`.
+        fn check_sorted<S: Sort>(values: &[u32]) {
+            let mut sorted = values.to_vec();
+            S::sort(&mut sorted);
+
+            assert!(
+                sorted
+                    .iter()
+                    .copied()
+                    .eq(0..values.len().try_into().unwrap()),
+                "{} did not sort reversed blocks data",
+                S::name()
+            );
+        }
+
+        let mut rng = seeded_rng(crate::test::TEST_SEED);
+        let size = crate::test::DEFAULT_TEST_SIZE;
+        for block in [1, 2, 50, 100, size] {
+            let options = DataOptions {
+                block: std::num::NonZeroUsize::new(block),
+                ..DataOptions::default()
+            };
+            let mut generator = <ReversedBlocksData as DataGenerator<u32>>::from_options(&options);
+
+            let mut values: Vec<u32> = generator.initialize(size, &mut rng);
+            for _ in 0..2 {
+                let lengths = decreasing_run_lengths(&values);
+                assert_eq!(lengths.len(), size / block);
+                assert!(lengths.iter().all(|&length| length == block));
+
+                check_sorted::<powersort::PowerSort>(&values);
+                check_sorted::<powersort::MultiwayPowerSort>(&values);
+                check_sorted::<timsort::TimSort>(&values);
+                check_sorted::<peeksort::PeekSort>(&values);
+                check_sorted::<blocksort::BlockSort>(&values);
+
+                // Blocks longer than the minimum run length are found as runs of their own
+                #[cfg(feature = "instrument")]
+                if block > powersort::DEFAULT_MIN_RUN_LENGTH {
+                    let mut sorted = values.clone();
+                    let observer = crate::instrument::observe(
+                        crate::instrument::RunLengthsObserver::default(),
+                        || <powersort::PowerSort>::sort(&mut sorted),
+                    );
+                    assert_eq!(observer.lengths(), lengths);
+                }
+
+                generator.reinitialize(&mut values, &mut rng);
+            }
+        }
+
+        // Only the last block is shorter if `b` does not divide `n`
+        let values: Vec<u32> =
+            ReversedBlocksData(std::num::NonZeroUsize::new(3)).initialize(10, &mut rng);
+        assert_eq!(values, [2, 1, 0, 5, 4, 3, 8, 7, 6, 9]);
+    }
+
+    #[test]
+    fn test_string_data() {
+        use crate::algorithms::{Sort, mergesort, powersort, timsort};
+
+        /// Sorts `values` with the stable `S`, checking the result is ordered lexicographically and
+        /// equal strings kept their initial order.
+        fn check_stable_sorted<S: Sort>(values: &[String]) {
+            assert!(S::IS_STABLE);
+
+            let mut sorted: Vec<_> = IndexedOrdered::map_iter(values.iter().cloned()).collect();
+            S::sort(&mut sorted);
+
+            assert_eq!(
+                IndexedOrdered::is_stable_sorted(sorted.iter()),
+                Ok(true),
+                "{} did not sort strings stably",
+                S::name()
+            );
+
+            let mut expected = values.to_vec();
+            expected.sort_by(|a, b| a.as_bytes().cmp(b.as_bytes()));
+            assert!(sorted.iter().map(|element| &element.1).eq(expected.iter()));
+        }
+
+        /// Generates strings with `StringData<MIN_LENGTH, MAX_LENGTH>`, checking their lengths and
+        /// letters and that they are sorted stably, before and after reinitializing them.
+        fn check<const MIN_LENGTH: usize, const MAX_LENGTH: usize>(rng: &mut impl rand::Rng) {
+            let mut generator = StringData::<MIN_LENGTH, MAX_LENGTH>;
+            let mut values: Vec<String> = generator.initialize(crate::test::DEFAULT_TEST_SIZE, rng);
+
+            for _ in 0..2 {
+                assert!(values.iter().all(|string| {
+                    (MIN_LENGTH..=MAX_LENGTH).contains(&string.len())
+                        && string.bytes().all(|byte| byte.is_ascii_lowercase())
+                }));
+
+                check_stable_sorted::<powersort::PowerSort>(&values);
+                check_stable_sorted::<powersort::MultiwayPowerSort>(&values);
+                check_stable_sorted::<timsort::TimSort>(&values);
+                check_stable_sorted::<mergesort::MergeSort>(&values);
+
+                generator.reinitialize(&mut values, rng);
+            }
+        }
+
+        let mut rng = seeded_rng(crate::test::TEST_SEED);
+        check::<16, 16>(&mut rng);
+        check::<0, 32>(&mut rng);
+        // Short strings repeat often, so stability matters
+        check::<1, 2>(&mut rng);
+    }
+
+    #[test]
+    fn test_uncounted() {
+        use crate::test::{CountingOrdered, count_comparisons};
+
+        let (a, b) = (CountingOrdered(1), CountingOrdered(2));
+
+        let comparisons = count_comparisons(|| {
+            assert!(a < b);
+            assert!(uncounted(|| a < b && uncounted(|| b > a) && a != b));
+            assert!(b > a);
+        });
+        assert_eq!(comparisons, 2);
+
+        // Counting resumes even if the uncounted comparisons panic
+        let result = std::panic::catch_unwind(|| uncounted(|| panic!("uncounted panic")));
+        assert!(result.is_err());
+        assert!(is_counting());
+        assert_eq!(count_comparisons(|| assert!(a < b)), 1);
+    }
+
+    #[test]
+    fn test_costly_ordered() {
+        use crate::algorithms::{Sort as _, powersort};
+
+        const SIZE: usize = 1_000;
+
+        /// Returns the time sorting a random permutation of `CostlyOrdered<NANOS, u32>` takes.
+        fn sort_time<const NANOS: u64>() -> std::time::Duration {
+            let mut rng = seeded_rng(crate::test::TEST_SEED);
+            let mut values: Vec<CostlyOrdered<NANOS, u32>> =
+                PermutationData.initialize(SIZE, &mut rng);
+
+            let start = std::time::Instant::now();
+            <powersort::PowerSort>::sort(&mut values);
+            let elapsed = start.elapsed();
+
+            assert!(values.iter().map(|value| value.0).eq((0..).take(SIZE)));
+            elapsed
+        }
+
+        // Sorting takes about 10_000 comparisons, so each step adds at least 10ms
+        let times = [sort_time::<0>(), sort_time::<1_000>(), sort_time::<5_000>()];
+        assert!(
+            times.is_sorted(),
+            "Sorting times did not increase: {times:?}"
+        );
+        assert!(times[2] >= std::time::Duration::from_millis(40));
+
+        // The runtime cost is added to every comparison
+        set_compare_cost(5_000);
+        let runtime_cost = sort_time::<0>();
+        set_compare_cost(0);
+        assert!(
+            runtime_cost > times[1],
+            "{runtime_cost:?} <= {:?}",
+            times[1]
+        );
+    }
+}
@@ -0,0 +1,265 @@
+//! Instrumentation hooks for observing adaptive sorting algorithms, see [`RunObserver`].
+
+/// Observes the natural runs discovered by adaptive sorting algorithms.
+///
+/// The currently installed observer is notified through [`on_run()`], see [`observe()`].
+pub trait RunObserver: std::any::Any {
+    /// Called for each natural run `slice[start..start + len]`, before it is potentially extended
+    /// to a minimum run length.
+    fn on_run(&mut self, start: usize, len: usize);
+}
+
+/// A [`RunObserver`] ignoring all runs, installed by default.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoopObserver;
+
+impl RunObserver for NoopObserver {
+    fn on_run(&mut self, _start: usize, _len: usize) {}
+}
+
+/// A [`RunObserver`] counting run lengths in buckets by powers of two.
+///
+/// Bucket `i` contains the number of runs with length in `2^i..2^(i + 1)`.
+#[derive(Debug, Default, Clone)]
+pub struct HistogramObserver {
+    /// The run counts of each bucket
+    buckets: Vec<usize>,
+}
+
+impl HistogramObserver {
+    /// Returns the run counts of each bucket.
+    pub fn buckets(&self) -> &[usize] {
+        &self.buckets
+    }
+}
+
+impl RunObserver for HistogramObserver {
+    fn on_run(&mut self, _start: usize, len: usize) {
+        // Empty runs are never passed on by the sorts, but do not belong to any bucket
+        let Some(bucket) = len.checked_ilog2() else {
+            return;
+        };
+        let bucket = usize::try_from(bucket).expect("ilog2 of usize fits into usize");
+
+        if self.buckets.len() <= bucket {
+            self.buckets.resize(bucket + 1, 0);
+        }
+        self.buckets[bucket] += 1;
+    }
+}
+
+impl std::fmt::Display for HistogramObserver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (bucket, count) in self.buckets().iter().enumerate() {
+            writeln!(f, "2^{bucket:<2} .. 2^{next:<2} {count}", next = bucket + 1)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A [`RunObserver`] recording the length of every run, in the order they are discovered.
+#[derive(Debug, Default, Clone)]
+pub struct RunLengthsObserver {
+    /// The recorded run lengths
+    lengths: Vec<usize>,
+}
+
+impl RunLengthsObserver {
+    /// Returns the recorded run lengths.
+    #[allow(dead_code, reason = "Currently only used in tests")]
+    pub fn lengths(&self) -> &[usize] {
+        &self.lengths
+    }
+
+    /// Writes the recorded run lengths to `writer`, one per line.
+    pub fn write_to(&self, mut writer: impl std::io::Write) -> std::io::Result<()> {
+        for len in &self.lengths {
+            writeln!(writer, "{len}")?;
+        }
+
+        writer.flush()
+    }
+}
+
+impl RunObserver for RunLengthsObserver {
+    fn on_run(&mut self, _start: usize, len: usize) {
+        self.lengths.push(len);
+    }
+}
+
+thread_local! {
+    /// The observer currently installed for this thread
+    static OBSERVER: std::cell::RefCell<Box<dyn RunObserver>> =
+        std::cell::RefCell::new(Box::new(NoopObserver));
+}
+
+/// Notifies the currently installed observer about the run `slice[start..start + len]`.
+pub fn on_run(start: usize, len: usize) {
+    OBSERVER.with_borrow_mut(|observer| observer.on_run(start, len));
+}
+
+/// Installs `observer` for the current thread while executing `f` and returns it afterwards.
+pub fn observe<O: RunObserver>(observer: O, f: impl FnOnce()) -> O {
+    let previous = OBSERVER.replace(Box::new(observer));
+    f();
+    let observer: Box<dyn std::any::Any> = OBSERVER.replace(previous);
+
+    *observer
+        .downcast()
+        .expect("The installed observer should not change while executing f")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::algorithms::{powersort, timsort};
+
+    #[test]
+    fn test_histogram_buckets() {
+        let mut observer = HistogramObserver::default();
+        for len in [1, 2, 3, 4, 7, 8, 100] {
+            observer.on_run(0, len);
+        }
+
+        assert_eq!(observer.buckets(), &[1, 2, 2, 1, 0, 0, 1]);
+    }
+
+    #[test]
+    fn test_observe_powersort_runs() {
+        // Three increasing runs of lengths 100, 50 and 30
+        let mut slice: Vec<u32> = (0..100).chain(0..50).chain(0..30).collect();
+
+        let observer = observe(HistogramObserver::default(), || {
+            <crate::algorithms::powersort::PowerSort as crate::algorithms::Sort>::sort(&mut slice)
+        });
+
+        assert!(slice.is_sorted());
+        assert_eq!(observer.buckets(), &[0, 0, 0, 0, 1, 1, 1]);
+    }
+
+    // Variants only detecting weakly increasing runs
+    type PowerSortIncreasing = powersort::PowerSort<
+        powersort::DefaultNodePowerMethod,
+        powersort::DefaultInsertionSort,
+        powersort::DefaultMergingMethod,
+        powersort::DefaultBufGuardFactory,
+        { powersort::DEFAULT_MIN_RUN_LENGTH },
+        true,
+    >;
+    type MultiwayPowerSortIncreasing = powersort::MultiwayPowerSort<
+        powersort::DefaultNodePowerMethod,
+        powersort::DefaultInsertionSort,
+        powersort::DefaultMultiMergingMethod,
+        powersort::DefaultMergingMethod,
+        powersort::DefaultBufGuardFactory,
+        { powersort::DEFAULT_MERGE_K_RUNS },
+        { powersort::DEFAULT_MIN_RUN_LENGTH },
+        true,
+    >;
+    type TimSortIncreasing = timsort::TimSort<
+        timsort::DefaultInsertionSort,
+        timsort::DefaultMergingMethod,
+        timsort::DefaultBufGuardFactory,
+        { timsort::DEFAULT_MIN_MERGE },
+        true,
+    >;
+    type MultiwayTimSortIncreasing = timsort::MultiwayTimSort<
+        timsort::DefaultInsertionSort,
+        timsort::DefaultMultiMergingMethod,
+        timsort::DefaultBufGuardFactory,
+        { timsort::DEFAULT_MERGE_K_RUNS },
+        { timsort::DEFAULT_MIN_MERGE },
+        true,
+    >;
+
+    /// Returns the number of natural runs `S` discovers in a reverse sorted slice, as well as the
+    /// number of elements.
+    fn count_reverse_sorted_runs<S: crate::algorithms::Sort>() -> (usize, usize) {
+        use crate::data::DataGenerator as _;
+
+        let mut slice: Vec<u32> = crate::data::DescendingData
+            .initialize(1_000, &mut crate::data::seeded_rng(crate::test::TEST_SEED));
+
+        let observer = observe(HistogramObserver::default(), || S::sort(&mut slice));
+
+        assert!(slice.is_sorted());
+        (observer.buckets().iter().sum(), slice.len())
+    }
+
+    #[test]
+    fn test_reverse_sorted_run_counts() {
+        // With reversal the whole slice is a single run
+        assert_eq!(count_reverse_sorted_runs::<powersort::PowerSort>().0, 1);
+        assert_eq!(
+            count_reverse_sorted_runs::<powersort::MultiwayPowerSort>().0,
+            1
+        );
+        assert_eq!(count_reverse_sorted_runs::<timsort::TimSort>().0, 1);
+        assert_eq!(count_reverse_sorted_runs::<timsort::MultiwayTimSort>().0, 1);
+
+        // Without reversal every natural run starts out as a single element, extended to the
+        // minimum run length afterwards
+        for (runs, len) in [
+            count_reverse_sorted_runs::<PowerSortIncreasing>(),
+            count_reverse_sorted_runs::<MultiwayPowerSortIncreasing>(),
+            count_reverse_sorted_runs::<TimSortIncreasing>(),
+            count_reverse_sorted_runs::<MultiwayTimSortIncreasing>(),
+        ] {
+            assert!(runs > 1 && runs <= len);
+        }
+    }
+
+    /// Returns the lengths of the natural runs `S` discovers in a reverse sorted slice of length
+    /// `len`.
+    fn reverse_sorted_run_lengths<S: crate::algorithms::Sort>(len: usize) -> Vec<usize> {
+        use crate::data::DataGenerator as _;
+
+        let mut slice: Vec<u32> = crate::data::DescendingData
+            .initialize(len, &mut crate::data::seeded_rng(crate::test::TEST_SEED));
+
+        let observer = observe(RunLengthsObserver::default(), || S::sort(&mut slice));
+
+        assert!(slice.is_sorted());
+        observer.lengths().to_vec()
+    }
+
+    #[test]
+    fn test_reverse_sorted_run_lengths() {
+        let len = 1_000;
+
+        // With reversal the whole slice is a single run
+        for lengths in [
+            reverse_sorted_run_lengths::<powersort::PowerSort>(len),
+            reverse_sorted_run_lengths::<powersort::MultiwayPowerSort>(len),
+            reverse_sorted_run_lengths::<timsort::TimSort>(len),
+            reverse_sorted_run_lengths::<timsort::MultiwayTimSort>(len),
+        ] {
+            assert_eq!(lengths, [len]);
+        }
+
+        // Without reversal every natural run is a single element
+        for lengths in [
+            reverse_sorted_run_lengths::<PowerSortIncreasing>(len),
+            reverse_sorted_run_lengths::<MultiwayPowerSortIncreasing>(len),
+            reverse_sorted_run_lengths::<TimSortIncreasing>(len),
+            reverse_sorted_run_lengths::<MultiwayTimSortIncreasing>(len),
+        ] {
+            assert!(lengths.len() > 1);
+            assert!(lengths.iter().all(|&length| length == 1));
+        }
+    }
+
+    #[test]
+    fn test_write_run_lengths() {
+        let mut observer = RunLengthsObserver::default();
+        for len in [3, 1, 4] {
+            observer.on_run(0, len);
+        }
+
+        let mut output = Vec::new();
+        observer.write_to(&mut output).unwrap();
+        assert_eq!(output, b"3\n1\n4\n");
+    }
+}
@@ -1,3 +1,8 @@
+#![cfg_attr(feature = "simd", feature(portable_simd))]
+#![cfg_attr(
+    any(feature = "simd", feature = "poison-buffer"),
+    feature(min_specialization)
+)]
 #![warn(
     clippy::as_conversions,
     clippy::missing_safety_doc,
@@ -7,26 +12,102 @@
 use std::io::Write;
 
 use clap::Parser as _;
-use rand::SeedableRng as _;
+use rand::TryRngCore as _;
 
+#[cfg(feature = "core_affinity")]
+mod affinity;
 mod algorithms;
+mod aligned;
 mod cli;
+mod cpu_time;
 mod data;
+#[cfg(feature = "instrument")]
+mod instrument;
+mod metrics;
 
 #[cfg(test)]
 mod test;
 
 /// Executable entry point
 fn main() {
+    let args = cli::Args::parse();
+
+    // Run a different command instead, if one is given
+    if let Some(command) = args.command {
+        match command {
+            cli::Command::Bench(args) => bench(args),
+        }
+        return;
+    }
+
     let cli::Args {
+        command: _,
         algorithm,
         variant,
+        block_size,
+        k,
+        kway_threshold,
+        insertion_method,
+        merging,
+        binary_insertion,
+        galloping_insertion,
+        iterative,
+        balanced,
+        adaptive_min_run,
+        detect_sorted,
+        min_gallop,
+        pivot,
+        insertion_threshold,
+        ninther_threshold,
+        buffer,
+        lazy_buffer,
+        prefault_buffer,
         runs,
-        size,
+        warmup,
+        no_skip_first,
+        sizes,
         data,
         seed,
+        verify_only,
+        reference,
+        output_sorted_check,
+        run_histogram,
+        power_histogram,
+        dump_runs,
+        dump_output,
+        first_k,
+        checked,
+        descending,
+        describe_input,
+        print_memory,
+        format,
+        #[cfg_attr(
+            feature = "counters",
+            expect(
+                unused_variables,
+                reason = "No running times are printed while counting"
+            )
+        )]
+        precision,
+        distinct,
+        min_run,
+        block,
+        compare_cost,
+        align,
+        repeat_input,
+        pin_core,
+        time_source,
         output,
-    } = cli::Args::parse();
+    } = args;
+    let algorithm = algorithm.expect("The algorithm is required without a command");
+    let warmup = if no_skip_first { 0 } else { warmup };
+    let buffer = if lazy_buffer {
+        cli::Buffer::Lazy
+    } else if prefault_buffer {
+        cli::Buffer::Boxed
+    } else {
+        buffer
+    };
 
     // Validate the given algorithm variant
     let Some(variant) = cli::AlgorithmVariants::validate(algorithm, variant) else {
@@ -38,25 +119,616 @@ fn main() {
         return;
     };
 
-    println!(
-        "Running measurements for the following (stable: {stable}) algorithm:\n{alg}",
-        alg = cli::AlgorithmVariants::variants(algorithm)
-            .nth(variant)
-            .unwrap(),
-        stable = cli::AlgorithmVariants::is_stable(algorithm, variant).unwrap(),
+    // Select the block merging variant instead, if a block size is given
+    let variant = match block_size {
+        None => variant,
+        Some(block_size) => {
+            let Some(variant) = cli::AlgorithmVariants::block_merge_variant(algorithm, block_size)
+            else {
+                eprintln!(
+                    "--block-size {block_size} is not available for algorithm {algorithm}, \
+                     possible block sizes for powersort and timsort: {sizes}",
+                    sizes = cli::BLOCK_SIZES
+                        .iter()
+                        .map(usize::to_string)
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                );
+                std::process::exit(1);
+            };
+            variant
+        }
+    };
+
+    // Select the variant merging `k` runs at once instead, if given
+    let variant = match k {
+        None => variant,
+        Some(k) => {
+            let Some(variant) = cli::AlgorithmVariants::merge_k_variant(algorithm, k) else {
+                eprintln!("--k {k} is not available for algorithm {algorithm}");
+                std::process::exit(1);
+            };
+            variant
+        }
+    };
+
+    // Select the variant falling back to 2-way merging below the threshold instead, if given
+    let variant = match kway_threshold {
+        None => variant,
+        Some(threshold) => {
+            let Some(variant) =
+                cli::AlgorithmVariants::kway_threshold_variant(algorithm, threshold)
+            else {
+                eprintln!(
+                    "--kway-threshold {threshold} is not available for algorithm {algorithm}"
+                );
+                std::process::exit(1);
+            };
+            variant
+        }
+    };
+
+    // Select the variant inserting elements with the given method instead, if given
+    let variant = match insertion_method {
+        None => variant,
+        Some(method) => {
+            let Some(variant) =
+                cli::AlgorithmVariants::insertion_method_variant(algorithm, &method)
+            else {
+                eprintln!("--insertion-method {method} is not available for algorithm {algorithm}");
+                std::process::exit(1);
+            };
+            variant
+        }
+    };
+
+    // Select the variant merging with the given method instead, if given
+    let variant = match merging {
+        None => variant,
+        Some(method) => {
+            let Some(variant) = cli::AlgorithmVariants::merging_method_variant(algorithm, &method)
+            else {
+                eprintln!("--merging {method} is not available for algorithm {algorithm}");
+                std::process::exit(1);
+            };
+            variant
+        }
+    };
+
+    // Select the variant extending short runs with binary insertion sort instead, if given
+    let variant = if binary_insertion {
+        let Some(variant) = cli::AlgorithmVariants::binary_insertion_variant(algorithm) else {
+            eprintln!("--binary-insertion is not available for algorithm {algorithm}");
+            std::process::exit(1);
+        };
+        variant
+    } else {
+        variant
+    };
+
+    // Select the variant extending short runs with galloping insertion sort instead, if given
+    let variant = if galloping_insertion {
+        let Some(variant) = cli::AlgorithmVariants::galloping_insertion_variant(algorithm) else {
+            eprintln!("--galloping-insertion is not available for algorithm {algorithm}");
+            std::process::exit(1);
+        };
+        variant
+    } else {
+        variant
+    };
+
+    // Select the variant sorting without recursion instead, if given
+    let variant = if iterative {
+        let Some(variant) = cli::AlgorithmVariants::iterative_variant(algorithm) else {
+            eprintln!("--iterative is not available for algorithm {algorithm}");
+            std::process::exit(1);
+        };
+        variant
+    } else {
+        variant
+    };
+
+    // Select the variant merging in a balanced order instead, if given
+    let variant = if balanced {
+        let Some(variant) = cli::AlgorithmVariants::balanced_variant(algorithm) else {
+            eprintln!("--balanced is not available for algorithm {algorithm}");
+            std::process::exit(1);
+        };
+        variant
+    } else {
+        variant
+    };
+
+    // Select the variant adapting its minimum run length instead, if given
+    let variant = if adaptive_min_run {
+        let Some(variant) = cli::AlgorithmVariants::adaptive_min_run_variant(algorithm) else {
+            eprintln!("--adaptive-min-run is not available for algorithm {algorithm}");
+            std::process::exit(1);
+        };
+        variant
+    } else {
+        variant
+    };
+
+    // Select the variant returning early on fully sorted input instead, if given
+    let variant = if detect_sorted {
+        let Some(variant) = cli::AlgorithmVariants::detect_sorted_variant(algorithm) else {
+            eprintln!("--detect-sorted is not available for algorithm {algorithm}");
+            std::process::exit(1);
+        };
+        variant
+    } else {
+        variant
+    };
+
+    // Select the variant galloping with the given threshold instead, if given
+    let variant = match min_gallop {
+        None => variant,
+        Some(min_gallop) => {
+            let Some(variant) = cli::AlgorithmVariants::min_gallop_variant(algorithm, min_gallop)
+            else {
+                eprintln!("--min-gallop {min_gallop} is not available for algorithm {algorithm}");
+                std::process::exit(1);
+            };
+            variant
+        }
+    };
+
+    // Select the variant choosing pivots with the given strategy instead, if given
+    let variant = match pivot {
+        None => variant,
+        Some(strategy) => {
+            let Some(variant) = cli::AlgorithmVariants::pivot_variant(algorithm, &strategy) else {
+                eprintln!("--pivot {strategy} is not available for algorithm {algorithm}");
+                std::process::exit(1);
+            };
+            variant
+        }
+    };
+
+    // Tune the quicksort thresholds, if any are given
+    let quicksort_thresholds = if insertion_threshold.is_some() || ninther_threshold.is_some() {
+        if algorithm != cli::Algorithm::Quicksort {
+            eprintln!(
+                "--insertion-threshold and --ninther-threshold are only supported by quicksort"
+            );
+            std::process::exit(1);
+        }
+
+        let Some(thresholds) =
+            cli::QuicksortThresholds::new(variant, insertion_threshold, ninther_threshold)
+        else {
+            eprintln!("The thresholds of quicksort variant {variant} can not be tuned");
+            std::process::exit(1);
+        };
+        Some(thresholds)
+    } else {
+        None
+    };
+    // Check the buffer is supported by the variant
+    if buffer != cli::Buffer::Vec {
+        if quicksort_thresholds.is_some() {
+            eprintln!("--buffer {buffer} is not supported with tuned quicksort thresholds");
+            std::process::exit(1);
+        }
+        if buffer.sorter::<u32>(algorithm, variant, false).is_none() {
+            eprintln!(
+                "--buffer {buffer} is only supported by the default variants of {algorithms}",
+                algorithms = buffer.supported_algorithms(),
+            );
+            std::process::exit(1);
+        }
+    }
+    // Descending sorts are only dispatched for the default buffer and thresholds
+    if descending && (buffer != cli::Buffer::Vec || quicksort_thresholds.is_some()) {
+        eprintln!("--descending is only supported with the vec buffer and default thresholds");
+        std::process::exit(1);
+    }
+
+    let description = quicksort_thresholds.map_or_else(
+        || {
+            cli::AlgorithmVariants::variants(algorithm)
+                .nth(variant)
+                .unwrap()
+        },
+        cli::QuicksortThresholds::display,
     );
-    println!("Runs: {runs}, Slice size: {size}, Data type: {data}");
 
-    // Create RNG for data generation
-    let mut rng = match seed {
-        Some(partial_seed) => rand::rngs::StdRng::seed_from_u64(partial_seed),
+    // Only the measurement results are printed as JSON, so it can be parsed directly
+    let print_text = format == cli::OutputFormat::Text;
+
+    if print_text {
+        println!(
+            "Running measurements for the following (stable: {stable}) algorithm:\n{description}",
+            stable = cli::AlgorithmVariants::is_stable(algorithm, variant).unwrap(),
+        );
+        println!(
+            "Runs: {runs} (warmup: {warmup}), Slice sizes: {sizes}, Data type: {data}",
+            sizes = sizes
+                .iter()
+                .map(usize::to_string)
+                .collect::<Vec<_>>()
+                .join(", "),
+        );
+
+        if let Some(merging) = cli::AlgorithmVariants::merging(algorithm, variant).unwrap() {
+            println!("Using {merging} merge");
+        }
+        if buffer != cli::Buffer::Vec {
+            println!("Merging with a {buffer} buffer");
+        }
+        if checked {
+            println!("Checking every sort result, running times include the checks");
+        }
+        if descending {
+            println!("Sorting in descending order");
+        }
+        if let Some(k) = first_k {
+            println!("Only sorting the {k} smallest elements of each slice");
+        }
+        if repeat_input {
+            println!("Sorting the same input in every run");
+        }
+        if let Some(align) = align {
+            println!("Aligning the input of every run to {align} bytes");
+        }
+        if let Some(core) = pin_core {
+            println!("Pinning the measuring thread to core {core}");
+        }
+    }
+
+    // Pinning the measuring thread needs the platform specific implementation
+    if pin_core.is_some() && cfg!(not(feature = "core_affinity")) {
+        eprintln!("--pin-core requires the 'core_affinity' feature");
+        std::process::exit(1);
+    }
+    // No running times are reported while counting
+    if time_source != cli::TimeSource::Wall && cfg!(feature = "counters") {
+        eprintln!("--time-source is not supported with the 'counters' feature");
+        std::process::exit(1);
+    }
+    // Partially sorting is only implemented by quicksort and measured in running times
+    if first_k.is_some() {
+        if algorithm != cli::Algorithm::Quicksort || variant != 0 || quicksort_thresholds.is_some()
+        {
+            eprintln!("--first-k is only supported by the default quicksort variant");
+            std::process::exit(1);
+        }
+        if cfg!(feature = "counters") {
+            eprintln!("--first-k is not supported with the 'counters' feature");
+            std::process::exit(1);
+        }
+    }
+    // The number of distinct values is only used by the few distinct distributions
+    if distinct.is_some() && !data.is_few_distinct() {
+        eprintln!("--distinct is only supported by the few distinct data types");
+        std::process::exit(1);
+    }
+    // The input description is not part of the JSON results
+    if describe_input && !print_text {
+        eprintln!("--describe-input is only supported by the text format");
+        std::process::exit(1);
+    }
+    // The minimum run length is only used by the boundary runs distributions
+    if min_run.is_some() && !data.is_boundary_runs() {
+        eprintln!("--min-run is only supported by the boundary runs data types");
+        std::process::exit(1);
+    }
+    // The block length is only used by the reversed blocks distributions
+    if block.is_some() && !data.is_reversed_blocks() {
+        eprintln!("--block is only supported by the reversed blocks data types");
+        std::process::exit(1);
+    }
+    // The comparison cost is only spent by the costly data types
+    if let Some(nanos) = compare_cost {
+        if !data.is_costly() {
+            eprintln!("--compare-cost is only supported by the costly data types");
+            std::process::exit(1);
+        }
+        data::set_compare_cost(nanos);
+    }
+    let data_options = data::DataOptions {
+        distinct,
+        min_run,
+        block,
+        align,
+    };
+
+    // Determine the seed used for data generation
+    let seed = match seed {
+        Some(seed) => seed,
         None => {
-            println!("No seed provided, generating one using system rng");
-            rand::rngs::StdRng::from_os_rng()
+            let seed = rand::rngs::OsRng
+                .try_next_u64()
+                .expect("System rng should be available");
+            if print_text {
+                println!("No seed provided, generated {seed} using system rng");
+            }
+            seed
         }
     };
 
-    let (samples, stats);
+    // Only check the algorithm for correctness if requested
+    if verify_only {
+        let stable = cli::AlgorithmVariants::is_stable(algorithm, variant).unwrap();
+
+        let result = with_match_type! {
+            data;
+            T, D => {
+                sizes.iter().try_for_each(|&size| {
+                    verify::<T, D>(
+                        select_sorter(algorithm, variant, buffer, quicksort_thresholds, false),
+                        stable.then(|| {
+                            select_sorter(algorithm, variant, buffer, quicksort_thresholds, false)
+                        }),
+                        size,
+                        seed,
+                        &data_options,
+                    )
+                    .map_err(|error| format!("Verification failed for size {size}: {error}"))
+                })
+            }
+        };
+
+        match result {
+            Ok(()) => println!("Verification successful"),
+            Err(error) => {
+                eprintln!("{error}");
+                std::process::exit(1);
+            }
+        }
+
+        return;
+    }
+
+    // Only compare the results with those of a reference algorithm if requested
+    if let Some(reference) = reference {
+        // Equal elements only have to be in the same order if both algorithms are stable
+        let stable = cli::AlgorithmVariants::is_stable(algorithm, variant).unwrap()
+            && cli::AlgorithmVariants::is_stable(reference, 0).unwrap();
+
+        let result = with_match_type! {
+            data;
+            T, D => {
+                sizes.iter().try_for_each(|&size| {
+                    compare_with_reference::<T, D>(
+                        select_sorter(algorithm, variant, buffer, quicksort_thresholds, false),
+                        cli::AlgorithmVariants::sorter(reference, 0).unwrap(),
+                        stable,
+                        size,
+                        seed,
+                        &data_options,
+                    )
+                    .map_err(|error| {
+                        format!("Comparison with {reference} failed for size {size}: {error}")
+                    })
+                })
+            }
+        };
+
+        match result {
+            Ok(()) => println!("Results identical to {reference}"),
+            Err(error) => {
+                eprintln!("{error}");
+                std::process::exit(1);
+            }
+        }
+
+        return;
+    }
+
+    // Only print checksums of the sorted slices if requested
+    if output_sorted_check {
+        let stable = cli::AlgorithmVariants::is_stable(algorithm, variant).unwrap();
+
+        with_match_type! {
+            data;
+            T, D => {
+                for &size in &sizes {
+                    let checksums = sorted_checksums::<T, D>(
+                        select_sorter(algorithm, variant, buffer, quicksort_thresholds, false),
+                        size,
+                        seed,
+                        &data_options,
+                    );
+
+                    if stable {
+                        println!(
+                            "{size}: values {values:016x}, indices {indices:016x}",
+                            values = checksums.values,
+                            indices = checksums.indices,
+                        );
+                    } else {
+                        println!("{size}: values {values:016x}", values = checksums.values);
+                    }
+                }
+            }
+        };
+
+        return;
+    }
+
+    // Only write the input and the sorted elements of a single slice to files if requested
+    if let Some(path) = dump_output {
+        let &[size] = sizes.as_slice() else {
+            eprintln!("--dump-output only supports a single size");
+            std::process::exit(1);
+        };
+        if size >= 1_000_000 {
+            eprintln!(
+                "Warning: --dump-output writes {size} elements into each of two files, which may \
+                 take up a lot of disk space"
+            );
+        }
+
+        let mut input_path = path.clone().into_os_string();
+        input_path.push(".input");
+        let input_path = std::path::PathBuf::from(input_path);
+
+        let result = std::fs::File::create(&input_path)
+            .and_then(|input| Ok((input, std::fs::File::create(&path)?)))
+            .and_then(|(input, output)| {
+                with_match_type! {
+                    data;
+                    T, D => {
+                        dump_sorted_output::<T, D>(
+                            select_sorter(algorithm, variant, buffer, quicksort_thresholds, false),
+                            size,
+                            seed,
+                            &data_options,
+                            std::io::BufWriter::new(input),
+                            std::io::BufWriter::new(output),
+                        )
+                    }
+                }
+            });
+        if let Err(error) = result {
+            eprintln!(
+                "An error occurred while trying to write the output at {path:?} and the input at \
+                 {input_path:?}: {error}"
+            );
+            std::process::exit(1);
+        }
+
+        return;
+    }
+
+    // Only print the run length histogram of a single slice if requested
+    if run_histogram {
+        #[cfg(not(feature = "instrument"))]
+        {
+            eprintln!("Printing run histograms requires the 'instrument' feature");
+            std::process::exit(1);
+        }
+
+        #[cfg(feature = "instrument")]
+        {
+            with_match_type! {
+                data;
+                T, D => {
+                    let sorter =
+                        select_sorter(algorithm, variant, buffer, quicksort_thresholds, false);
+                    let name = cli::AlgorithmVariants::name(algorithm, variant).unwrap();
+
+                    for &size in &sizes {
+                        let histogram = observe_runs::<instrument::HistogramObserver, T, D>(
+                            sorter,
+                            size,
+                            seed,
+                            &data_options,
+                        );
+                        println!("Run lengths of {name} (size {size}):\n{histogram}");
+                    }
+                }
+            };
+
+            return;
+        }
+    }
+
+    // Only print the node power histogram of a single slice if requested
+    if power_histogram {
+        #[cfg(not(feature = "counters"))]
+        {
+            eprintln!("Printing node power histograms requires the 'counters' feature");
+            std::process::exit(1);
+        }
+
+        #[cfg(feature = "counters")]
+        {
+            if !algorithm.records_node_powers() {
+                eprintln!(
+                    "--power-histogram is only supported by powersort and multiway-powersort"
+                );
+                std::process::exit(1);
+            }
+
+            with_match_type! {
+                data;
+                T, D => {
+                    let sorter =
+                        select_sorter(algorithm, variant, buffer, quicksort_thresholds, false);
+                    let name = cli::AlgorithmVariants::name(algorithm, variant).unwrap();
+
+                    for &size in &sizes {
+                        let powers = node_powers::<T, D>(sorter, size, seed, &data_options);
+
+                        println!("Node powers of {name} (size {size}):");
+                        for (power, count) in powers.iter().enumerate().skip(1) {
+                            println!("{power:>3} {count}");
+                        }
+                    }
+                }
+            };
+
+            return;
+        }
+    }
+
+    // Only write the run lengths of a single slice to a file if requested
+    if let Some(path) = dump_runs {
+        #[cfg(not(feature = "instrument"))]
+        {
+            let _ = path;
+            eprintln!("Dumping runs requires the 'instrument' feature");
+            std::process::exit(1);
+        }
+
+        #[cfg(feature = "instrument")]
+        {
+            if !algorithm.reports_runs() {
+                eprintln!(
+                    "--dump-runs is only supported by powersort, timsort and natural-mergesort"
+                );
+                std::process::exit(1);
+            }
+            let &[size] = sizes.as_slice() else {
+                eprintln!("--dump-runs only supports a single size");
+                std::process::exit(1);
+            };
+
+            let observer = with_match_type! {
+                data;
+                T, D => {
+                    observe_runs::<instrument::RunLengthsObserver, T, D>(
+                        select_sorter(algorithm, variant, buffer, quicksort_thresholds, false),
+                        size,
+                        seed,
+                        &data_options,
+                    )
+                }
+            };
+
+            let result = std::fs::File::create(&path)
+                .map(std::io::BufWriter::new)
+                .and_then(|file| observer.write_to(file));
+            if let Err(error) = result {
+                eprintln!("An error occurred while trying to write runs at {path:?}: {error}");
+                std::process::exit(1);
+            }
+
+            return;
+        }
+    }
+
+    let mut samples = Vec::with_capacity(sizes.len());
+
+    // The experiment description shared by all JSON results
+    let info = ExperimentInfo {
+        algorithm: algorithm.to_string(),
+        variant,
+        name: cli::AlgorithmVariants::name(algorithm, variant).unwrap(),
+        description,
+        stable: cli::AlgorithmVariants::is_stable(algorithm, variant).unwrap(),
+        data: data.to_string(),
+        seed,
+        runs,
+        warmup,
+        checked,
+        descending,
+        repeat_input,
+        first_k,
+    };
 
     // Run the experiment with the given algorithm and data
     //
@@ -66,24 +738,207 @@ fn main() {
         data;
         T, D => {
             // Get the sort function pointer (data type can be inferred at this point)
-            let sorter = cli::AlgorithmVariants::sorter(algorithm, variant).unwrap();
+            let sorter = if descending {
+                cli::AlgorithmVariants::descending_sorter(algorithm, variant).unwrap()
+            } else {
+                select_sorter(algorithm, variant, buffer, quicksort_thresholds, checked)
+            };
+            let check: fn(&[_]) -> bool = if descending {
+                sorted_descending
+            } else {
+                <[_]>::is_sorted
+            };
 
-            // Measure running times
-            #[cfg(not(feature = "counters"))]
-            {
-                (samples, stats) =
-                    perform_time_experiment::<T, D>(sorter, runs, size, &mut rng);
+            for &size in &sizes {
+                let buffer_bytes =
+                    cli::AlgorithmVariants::buffer_bytes::<T>(algorithm, variant, size).unwrap();
+                if print_memory && print_text {
+                    println!("Auxiliary buffer memory (size {size}): {buffer_bytes} bytes");
+                }
+                if describe_input {
+                    let metrics = describe::<T, D>(size, seed, &data_options);
+                    println!("Input (size {size}): {metrics}");
+                }
 
-                println!("Run times in ms:\n{stats:#?}")
-            }
+                // Measure running times
+                #[cfg(not(feature = "counters"))]
+                {
+                    let size_samples = match first_k {
+                        Some(k) => perform_first_k_experiment::<
+                            algorithms::quicksort::QuickSort,
+                            T,
+                            D,
+                        >(
+                            k,
+                            runs,
+                            warmup,
+                            size,
+                            seed,
+                            &data_options,
+                            repeat_input,
+                            pin_core,
+                        ),
+                        None => perform_time_experiment::<T, D>(
+                            sorter,
+                            check,
+                            runs,
+                            warmup,
+                            size,
+                            seed,
+                            &data_options,
+                            repeat_input,
+                            pin_core,
+                        ),
+                    };
 
-            // Measure comparisons and merge costs
-            #[cfg(feature = "counters")]
-            {
-                (samples, stats) = perform_counters_experiment::<T, D>(sorter, runs, size, &mut rng);
+                    let nanos: Vec<u128> = size_samples
+                        .iter()
+                        .map(|sample| sample.time(time_source).as_nanos())
+                        .collect();
+                    #[expect(
+                        clippy::as_conversions,
+                        reason = "Nanos should not get high enough for this cast to become inaccurate"
+                    )]
+                    let values: Vec<f64> = nanos.iter().map(|&nanos| nanos as f64).collect();
+                    let summary = Summary::new(values.iter().copied());
 
-                println!("Comparisons:\n{stats:#?}")
-            };
+                    match format {
+                        cli::OutputFormat::Text => {
+                            let label = match time_source {
+                                cli::TimeSource::Wall => "Run times",
+                                cli::TimeSource::Cpu => "CPU times",
+                            };
+                            println!("{label} (size {size}):");
+                            print!("{}", summary.format_durations(precision));
+                        }
+                        cli::OutputFormat::Json => {
+                            print_json(&ExperimentResult {
+                                info: &info,
+                                size,
+                                buffer_bytes,
+                                metric: match time_source {
+                                    cli::TimeSource::Wall => "ns",
+                                    cli::TimeSource::Cpu => "cpu_ns",
+                                },
+                                summary,
+                                samples: nanos,
+                            });
+                        }
+                        cli::OutputFormat::CriterionJson => {
+                            print_json(&CriterionResult::new(&info, size, &values));
+                        }
+                    }
+
+                    samples.push((size, size_samples));
+                }
+
+                // Measure comparisons and merge costs
+                #[cfg(feature = "counters")]
+                {
+                    let (size_samples, stats) =
+                        perform_counters_experiment::<T, D>(
+                            sorter,
+                            check,
+                            runs,
+                            warmup,
+                            size,
+                            seed,
+                            &data_options,
+                            repeat_input,
+                            pin_core,
+                        );
+                    let max_stack_height = size_samples
+                        .iter()
+                        .map(|sample| sample.max_stack_height)
+                        .max()
+                        .unwrap_or(0);
+
+                    match format {
+                        cli::OutputFormat::Text => {
+                            println!(
+                                "Comparisons of {name} (size {size}):\n{stats:#?}",
+                                name = info.name,
+                            );
+                            println!("Max stack height (size {size}): {max_stack_height}");
+
+                            let allocated = size_samples
+                                .iter()
+                                .map(|sample| sample.merge_alloc_largest)
+                                .max()
+                                .unwrap_or(0);
+                            let prefix_used = size_samples
+                                .iter()
+                                .map(|sample| sample.merge_buffer_prefix)
+                                .max()
+                                .unwrap_or(0);
+                            if allocated > 0 {
+                                println!(
+                                    "Merge buffer (size {size}): largest allocated {allocated}, largest prefix used {prefix_used}"
+                                );
+                            }
+
+                            let merges: u64 = size_samples.iter().map(|sample| sample.merges).sum();
+                            #[expect(
+                                clippy::as_conversions,
+                                reason = "Merge counts should not get high enough for this cast to become inaccurate"
+                            )]
+                            let mean_merges = merges as f64 / size_samples.len().max(1) as f64;
+                            println!("Merges (size {size}): {mean_merges} on average");
+
+                            let galloped: u64 =
+                                size_samples.iter().map(|sample| sample.galloped).sum();
+                            let merged: u64 = size_samples
+                                .iter()
+                                .map(|sample| sample.galloped + sample.gallop_single)
+                                .sum();
+                            if merged > 0 {
+                                println!("Galloping (size {size}): galloped {galloped} of {merged} elements");
+                            }
+
+                            let moves: u64 =
+                                size_samples.iter().map(|sample| sample.element_moves).sum();
+                            if moves > 0 {
+                                #[expect(
+                                    clippy::as_conversions,
+                                    reason = "Move counts should not get high enough for this cast to become inaccurate"
+                                )]
+                                let mean_moves = moves as f64 / size_samples.len().max(1) as f64;
+                                println!("Element moves (size {size}): {mean_moves} on average");
+                            }
+                        }
+                        cli::OutputFormat::Json => {
+                            #[expect(
+                                clippy::as_conversions,
+                                reason = "Comparisons should not get high enough for this cast to become inaccurate"
+                            )]
+                            let summary = Summary::new(
+                                size_samples.iter().map(|sample| sample.comparisons as f64),
+                            );
+
+                            print_json(&ExperimentResult {
+                                info: &info,
+                                size,
+                                buffer_bytes,
+                                metric: "comparisons",
+                                summary,
+                                samples: &size_samples,
+                            });
+                        }
+                        cli::OutputFormat::CriterionJson => {
+                            #[expect(
+                                clippy::as_conversions,
+                                reason = "Comparisons should not get high enough for this cast to become inaccurate"
+                            )]
+                            let values: Vec<f64> =
+                                size_samples.iter().map(|sample| sample.comparisons as f64).collect();
+
+                            print_json(&CriterionResult::new(&info, size, &values));
+                        }
+                    }
+
+                    samples.push((size, size_samples));
+                };
+            }
         }
     };
 
@@ -95,155 +950,1038 @@ fn main() {
     }
 }
 
+/// Returns the sorting function for the given datatype `T` and `algorithm` variant, wrapped in
+/// [`algorithms::Checked`] if `checked` is set.
+///
+/// The variant merges with the given `buffer`. If `quicksort_thresholds` are given, the tuned
+/// quicksort variant is used instead.
+fn select_sorter<T: Ord>(
+    algorithm: cli::Algorithm,
+    variant: usize,
+    buffer: cli::Buffer,
+    quicksort_thresholds: Option<cli::QuicksortThresholds>,
+    checked: bool,
+) -> fn(&mut [T]) {
+    match quicksort_thresholds {
+        Some(thresholds) => thresholds.sorter(checked),
+        None => buffer.sorter(algorithm, variant, checked).unwrap(),
+    }
+}
+
 /// Writes `samples` to a file at `path`, which is created in case it does not exist.
 ///
+/// Each entry of `samples` consists of the slice size and the samples measured for that size, the
+/// size is appended as the last column of every line.
+///
 /// Returns IO error if writing to the file is not possible.
 fn write_output<S: Samples<N>, const N: usize>(
     path: impl AsRef<std::path::Path>,
-    samples: S,
+    samples: Vec<(usize, S)>,
 ) -> std::io::Result<()> {
     let mut file = std::fs::File::create(path)?;
 
     // Write the CSV header
     file.write_all(S::headers().join(",").as_bytes())?;
-    file.write_all(b"\n")?;
+    file.write_all(b",size\n")?;
 
     // Write the individual lines (escaping should not be necessary since we only write integers)
-    for line in samples.csv_lines() {
-        file.write_all(line.join(",").as_bytes())?;
-        file.write_all(b"\n")?;
+    for (size, samples) in samples {
+        for line in samples.csv_lines() {
+            file.write_all(line.join(",").as_bytes())?;
+            file.write_all(format!(",{size}\n").as_bytes())?;
+        }
     }
 
     Ok(())
 }
 
-/// A trait for encoding samples as CSV with `N` columns
-trait Samples<const N: usize> {
-    /// Returns the column headers for this data
-    fn headers() -> [String; N];
+/// The reason a verified sort failed, see [`verify()`].
+#[derive(Debug, PartialEq, Eq)]
+enum VerificationError {
+    /// The element at the given index was smaller than its predecessor.
+    Unsorted(usize),
+    /// The element at the given index was equal to its predecessor but initially came before it.
+    Unstable(usize),
+    /// The element at the given index differed from the one in the result of the reference sort.
+    DiffersFromReference(usize),
+}
 
-    /// Returns the individual CSV lines, with one [`String`] per column
-    fn csv_lines(self) -> impl Iterator<Item = [String; N]>;
+impl std::fmt::Display for VerificationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VerificationError::Unsorted(index) => {
+                write!(
+                    f,
+                    "element at index {index} is smaller than its predecessor"
+                )
+            }
+            VerificationError::Unstable(index) => write!(
+                f,
+                "element at index {index} is equal to its predecessor but was initially before it"
+            ),
+            VerificationError::DiffersFromReference(index) => write!(
+                f,
+                "element at index {index} differs from the result of the reference"
+            ),
+        }
+    }
 }
 
-impl Samples<1> for Vec<std::time::Duration> {
-    fn headers() -> [std::string::String; 1] {
-        ["ns".to_string()]
+/// Sorts a single slice of generated data and checks that the result is sorted.
+///
+/// - `sorter`: The function used for sorting
+/// - `stable_sorter`: If given, additionally used to sort index tagged data, checking that equal
+///   elements keep their initial relative ordering
+/// - `size`: The size of the slice to sort
+/// - `seed`: The base seed used for sampling the data, see [`data::run_rng()`]
+/// - `options`: The options used to construct the data generator, see [`data::DataOptions`]
+fn verify<T: Ord + std::fmt::Debug, D: data::DataGenerator<T>>(
+    sorter: fn(&mut [T]),
+    stable_sorter: Option<fn(&mut [data::IndexedOrdered<T>])>,
+    size: usize,
+    seed: u64,
+    options: &data::DataOptions,
+) -> Result<(), VerificationError> {
+    let mut data = D::from_options(options).initialize(size, &mut data::run_rng(seed, 0));
+
+    sorter(&mut data);
+
+    if let Some(index) = data.windows(2).position(|pair| pair[0] > pair[1]) {
+        return Err(VerificationError::Unsorted(index + 1));
     }
 
-    fn csv_lines(self) -> impl Iterator<Item = [String; 1]> {
-        self.into_iter()
-            .map(|duration| [duration.as_nanos().to_string()])
+    if let Some(stable_sorter) = stable_sorter {
+        // Generate the same data again, to tag it with the initial indices
+        let data = D::from_options(options).initialize(size, &mut data::run_rng(seed, 0));
+        let mut data: Vec<_> = data::IndexedOrdered::map_iter(data.into_iter()).collect();
+
+        stable_sorter(&mut data);
+
+        if let Some(index) = data.windows(2).position(|pair| pair[0] > pair[1]) {
+            return Err(VerificationError::Unsorted(index + 1));
+        }
+        if let Some(index) = data::IndexedOrdered::first_unstable_index(&data) {
+            return Err(VerificationError::Unstable(index));
+        }
     }
+
+    Ok(())
 }
 
-impl Samples<4> for Vec<CounterSample> {
-    fn headers() -> [std::string::String; 4] {
-        ["comparisons", "alloc", "slice", "buffer"].map(str::to_string)
-    }
+/// Sorts a single slice of generated data with both `sorter` and `reference`, checking that the
+/// results are identical.
+///
+/// Both sort index tagged clones of the same data, so unlike [`verify()`] this also catches sorts
+/// losing or duplicating elements, as long as their results are still sorted.
+///
+/// - `sorter`: The function used for sorting
+/// - `reference`: The trusted function used for sorting the same data
+/// - `stable`: Whether equal elements also have to end up in the same order, i.e. with the same
+///   initial indices
+/// - `size`: The size of the slice to sort
+/// - `seed`: The base seed used for sampling the data, see [`data::run_rng()`]
+/// - `options`: The options used to construct the data generator, see [`data::DataOptions`]
+fn compare_with_reference<T: Ord + Clone + std::fmt::Debug, D: data::DataGenerator<T>>(
+    sorter: fn(&mut [data::IndexedOrdered<T>]),
+    reference: fn(&mut [data::IndexedOrdered<T>]),
+    stable: bool,
+    size: usize,
+    seed: u64,
+    options: &data::DataOptions,
+) -> Result<(), VerificationError> {
+    let data = D::from_options(options).initialize(size, &mut data::run_rng(seed, 0));
+    let mut data: Vec<_> = data::IndexedOrdered::map_iter(data.into_iter()).collect();
+    let mut expected = data.clone();
 
-    fn csv_lines(self) -> impl Iterator<Item = [String; 4]> {
-        self.into_iter().map(|sample| {
-            [
-                sample.comparisons.to_string(),
-                sample.merge_alloc_cost.to_string(),
-                sample.merge_slice_cost.to_string(),
-                sample.merge_buffer_cost.to_string(),
-            ]
-        })
+    sorter(&mut data);
+    reference(&mut expected);
+
+    let differs = |(element, expected): (&data::IndexedOrdered<T>, &data::IndexedOrdered<T>)| {
+        element != expected || (stable && element.index() != expected.index())
+    };
+
+    match data.iter().zip(&expected).position(differs) {
+        Some(index) => Err(VerificationError::DiffersFromReference(index)),
+        None => Ok(()),
     }
 }
 
-/// The global counters used during the experiment
-pub static GLOBAL_COUNTERS: GlobalCounters = GlobalCounters {
-    comparisons: data::GlobalCounter::new(),
-    merge_alloc: data::GlobalCounter::new(),
-    merge_slice: data::GlobalCounter::new(),
-    merge_buffer: data::GlobalCounter::new(),
-};
+/// The checksums of a sorted slice, see [`sorted_checksums()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct SortedChecksums {
+    /// The checksum of the sorted values
+    values: u64,
+    /// The checksum of the initial indices of the sorted values
+    indices: u64,
+}
 
-/// Container for global counters used during the experiment
-pub struct GlobalCounters {
-    pub comparisons: data::GlobalCounter,
-    pub merge_alloc: data::GlobalCounter,
-    pub merge_slice: data::GlobalCounter,
-    pub merge_buffer: data::GlobalCounter,
+/// The 64 bit FNV-1a hash, which unlike [`std::hash::DefaultHasher`] is guaranteed to stay the
+/// same across builds.
+struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
 }
 
-impl GlobalCounters {
-    /// Reset all global counters
-    pub fn reset(&self) {
-        self.comparisons.read_and_reset();
-        self.merge_alloc.read_and_reset();
-        self.merge_slice.read_and_reset();
-        self.merge_buffer.read_and_reset();
+impl Fnv1a {
+    /// Feeds the given `bytes` into the hash.
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3);
+        }
     }
 }
 
-/// A single sample point for measuring comparisons and merge costs
-#[derive(Debug)]
-struct CounterSample {
-    /// The number of comparisons
-    comparisons: u64,
-    /// The number of elements needed as additional merge allocation
-    merge_alloc_cost: u64,
-    /// The number of elements written to the original slice during merging
-    merge_slice_cost: u64,
-    /// The number of elements written to the buffer during merging
-    merge_buffer_cost: u64,
+impl std::fmt::Write for Fnv1a {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.write(s.as_bytes());
+        Ok(())
+    }
 }
 
-/// Performs a time sampling experiment on the given sorting algorithm
+/// Sorts a single slice of generated data and returns checksums of the result, see
+/// `--output-sorted-check`.
+///
+/// The values are checksummed by their [`Debug`](std::fmt::Debug) representation, which unlike
+/// comparisons captures all of their contents, e.g. every entry of a blob. The checksum of the
+/// initial indices also detects equal elements ending up in a different order.
 ///
 /// - `sorter`: The function used for sorting
-/// - `runs`: The number of samples to measure
-/// - `size`: The size of the slices to sort
-/// - `rng`: The RNG used for sampling the data
-#[allow(dead_code, reason = "Unused when feature 'counters' is active")]
-fn perform_time_experiment<T: Ord + std::fmt::Debug, D: data::DataGenerator<T>>(
+/// - `size`: The size of the slice to sort
+/// - `seed`: The base seed used for sampling the data, see [`data::run_rng()`]
+/// - `options`: The options used to construct the data generator, see [`data::DataOptions`]
+fn sorted_checksums<T: Ord + Clone + std::fmt::Debug, D: data::DataGenerator<T>>(
+    sorter: fn(&mut [data::IndexedOrdered<T>]),
+    size: usize,
+    seed: u64,
+    options: &data::DataOptions,
+) -> SortedChecksums {
+    use std::fmt::Write as _;
+
+    let data = D::from_options(options).initialize(size, &mut data::run_rng(seed, 0));
+    let mut sorted: Vec<_> = data::IndexedOrdered::map_iter(data.iter().cloned()).collect();
+
+    sorter(&mut sorted);
+
+    let mut values = Fnv1a::default();
+    let mut indices = Fnv1a::default();
+    for element in &sorted {
+        write!(values, "{:?},", data[element.index()]).expect("Hashing can not fail");
+        indices.write(&u64::try_from(element.index()).unwrap().to_le_bytes());
+    }
+
+    SortedChecksums {
+        values: values.0,
+        indices: indices.0,
+    }
+}
+
+/// Sorts a single slice of generated data, writing the generated elements to `input` and the
+/// sorted elements to `output`, see `--dump-output`.
+///
+/// Every element is written on its own line in its [`Debug`](std::fmt::Debug) representation.
+///
+/// - `sorter`: The function used for sorting
+/// - `size`: The size of the slice to sort
+/// - `seed`: The base seed used for sampling the data, see [`data::run_rng()`]
+/// - `options`: The options used to construct the data generator, see [`data::DataOptions`]
+fn dump_sorted_output<T: Ord + Clone + std::fmt::Debug, D: data::DataGenerator<T>>(
+    sorter: fn(&mut [T]),
+    size: usize,
+    seed: u64,
+    options: &data::DataOptions,
+    mut input: impl std::io::Write,
+    mut output: impl std::io::Write,
+) -> std::io::Result<()> {
+    let mut data = D::from_options(options).initialize(size, &mut data::run_rng(seed, 0));
+
+    for element in &data {
+        writeln!(input, "{element:?}")?;
+    }
+    input.flush()?;
+
+    sorter(&mut data);
+
+    for element in &data {
+        writeln!(output, "{element:?}")?;
+    }
+    output.flush()
+}
+
+/// Sorts a single slice of generated data and returns the observer `O`, which was notified about
+/// the natural runs discovered while sorting, see [`instrument::RunObserver`].
+///
+/// - `sorter`: The function used for sorting
+/// - `size`: The size of the slice to sort
+/// - `seed`: The base seed used for sampling the data, see [`data::run_rng()`]
+/// - `options`: The options used to construct the data generator, see [`data::DataOptions`]
+#[cfg(feature = "instrument")]
+fn observe_runs<
+    O: instrument::RunObserver + Default,
+    T: Ord + std::fmt::Debug,
+    D: data::DataGenerator<T>,
+>(
     sorter: fn(&mut [T]),
+    size: usize,
+    seed: u64,
+    options: &data::DataOptions,
+) -> O {
+    let mut data = D::from_options(options).initialize(size, &mut data::run_rng(seed, 0));
+
+    instrument::observe(O::default(), || sorter(&mut data))
+}
+
+/// Sorts a single slice of generated data and returns the number of merge tree nodes computed for
+/// each node power, see [`algorithms::powersort::node_powers()`].
+///
+/// - `sorter`: The function used for sorting
+/// - `size`: The size of the slice to sort
+/// - `seed`: The base seed used for sampling the data, see [`data::run_rng()`]
+/// - `options`: The options used to construct the data generator, see [`data::DataOptions`]
+#[cfg(feature = "counters")]
+fn node_powers<T: Ord + std::fmt::Debug, D: data::DataGenerator<T>>(
+    sorter: fn(&mut [T]),
+    size: usize,
+    seed: u64,
+    options: &data::DataOptions,
+) -> Vec<u64> {
+    let mut data = D::from_options(options).initialize(size, &mut data::run_rng(seed, 0));
+
+    algorithms::powersort::reset_node_powers();
+    sorter(&mut data);
+    algorithms::powersort::node_powers()
+}
+
+/// Benchmarks the given algorithms with criterion, see [`cli::Command::Bench`].
+///
+/// The algorithms of each data type form one benchmark group, the data is generated outside of
+/// the measurements.
+fn bench(args: cli::BenchArgs) {
+    #[cfg(not(feature = "criterion"))]
+    {
+        let _ = args;
+        eprintln!("Benchmarking requires the 'criterion' feature");
+        std::process::exit(1);
+    }
+
+    #[cfg(feature = "criterion")]
+    {
+        let cli::BenchArgs {
+            algorithms,
+            data,
+            sizes,
+            seed,
+        } = args;
+
+        let data_options = data::DataOptions::default();
+        let mut criterion = criterion::Criterion::default();
+        let mut group = criterion.benchmark_group(data.to_string());
+
+        with_match_type! {
+            data;
+            T, D => {
+                bench_group::<T, D>(&mut group, &algorithms, &sizes, seed, &data_options);
+            }
+        };
+
+        group.finish();
+        criterion.final_summary();
+    }
+}
+
+/// Adds a benchmark of each algorithm for each size to `group`, sorting data of type `T`
+/// generated by `D` outside of the measurements.
+#[cfg(feature = "criterion")]
+fn bench_group<T: Ord + std::fmt::Debug, D: data::DataGenerator<T>>(
+    group: &mut criterion::BenchmarkGroup<'_, criterion::measurement::WallTime>,
+    algorithms: &[cli::Algorithm],
+    sizes: &[usize],
+    seed: u64,
+    options: &data::DataOptions,
+) {
+    for &algorithm in algorithms {
+        let sorter = cli::AlgorithmVariants::sorter::<T>(algorithm, 0).unwrap();
+
+        for &size in sizes {
+            let mut run = 0;
+
+            group.bench_with_input(
+                criterion::BenchmarkId::new(algorithm.to_string(), size),
+                &size,
+                |bencher, &size| {
+                    bencher.iter_batched_ref(
+                        || {
+                            // Generate new data for every sort, like the experiments
+                            run += 1;
+                            D::from_options(options).initialize(size, &mut data::run_rng(seed, run))
+                        },
+                        |data| sorter(data),
+                        criterion::BatchSize::LargeInput,
+                    );
+                },
+            );
+        }
+    }
+}
+
+/// Returns the [`metrics::InputMetrics`] of the slice sorted in the first run with the given
+/// `size`, `seed` and data `options`.
+fn describe<T: Ord + std::fmt::Debug, D: data::DataGenerator<T>>(
+    size: usize,
+    seed: u64,
+    options: &data::DataOptions,
+) -> metrics::InputMetrics {
+    let data = D::from_options(options).initialize(size, &mut data::run_rng(seed, 0));
+
+    metrics::InputMetrics::new(&data)
+}
+
+/// A trait for encoding samples as CSV with `N` columns
+trait Samples<const N: usize> {
+    /// Returns the column headers for this data
+    fn headers() -> [String; N];
+
+    /// Returns the individual CSV lines, with one [`String`] per column
+    fn csv_lines(self) -> impl Iterator<Item = [String; N]>;
+}
+
+impl Samples<2> for Vec<TimeSample> {
+    fn headers() -> [std::string::String; 2] {
+        ["ns", "cpu_ns"].map(str::to_string)
+    }
+
+    fn csv_lines(self) -> impl Iterator<Item = [String; 2]> {
+        self.into_iter().map(|sample| {
+            [
+                sample.wall.as_nanos().to_string(),
+                sample.cpu.as_nanos().to_string(),
+            ]
+        })
+    }
+}
+
+impl Samples<15> for Vec<CounterSample> {
+    fn headers() -> [std::string::String; 15] {
+        [
+            "comparisons",
+            "alloc",
+            "slice",
+            "buffer",
+            "stack",
+            "galloped",
+            "single",
+            "decrements",
+            "merges",
+            "moves",
+            "adaptive-galloping",
+            "adaptive-copy-both",
+            "writes",
+            "buffer-prefix",
+            "alloc-largest",
+        ]
+        .map(str::to_string)
+    }
+
+    fn csv_lines(self) -> impl Iterator<Item = [String; 15]> {
+        self.into_iter().map(|sample| {
+            [
+                sample.comparisons.to_string(),
+                sample.merge_alloc_cost.to_string(),
+                sample.merge_slice_cost.to_string(),
+                sample.merge_buffer_cost.to_string(),
+                sample.max_stack_height.to_string(),
+                sample.galloped.to_string(),
+                sample.gallop_single.to_string(),
+                sample.min_gallop_decrements.to_string(),
+                sample.merges.to_string(),
+                sample.element_moves.to_string(),
+                sample.adaptive_galloping.to_string(),
+                sample.adaptive_copy_both.to_string(),
+                sample.writes.to_string(),
+                sample.merge_buffer_prefix.to_string(),
+                sample.merge_alloc_largest.to_string(),
+            ]
+        })
+    }
+}
+
+/// The global counters used during the experiment
+pub static GLOBAL_COUNTERS: GlobalCounters = GlobalCounters {
+    comparisons: data::GlobalCounter::new(),
+    merge_alloc: data::GlobalCounter::new(),
+    merge_alloc_largest: data::GlobalCounter::new(),
+    merge_slice: data::GlobalCounter::new(),
+    merge_buffer: data::GlobalCounter::new(),
+    max_stack_height: data::GlobalCounter::new(),
+};
+
+/// Container for global counters used during the experiment
+pub struct GlobalCounters {
+    pub comparisons: data::GlobalCounter,
+    pub merge_alloc: data::GlobalCounter,
+    pub merge_alloc_largest: data::GlobalCounter,
+    pub merge_slice: data::GlobalCounter,
+    pub merge_buffer: data::GlobalCounter,
+    pub max_stack_height: data::GlobalCounter,
+}
+
+impl GlobalCounters {
+    /// Reset all global counters
+    pub fn reset(&self) {
+        self.comparisons.read_and_reset();
+        self.merge_alloc.read_and_reset();
+        self.merge_alloc_largest.read_and_reset();
+        self.merge_slice.read_and_reset();
+        self.merge_buffer.read_and_reset();
+        self.max_stack_height.read_and_reset();
+        algorithms::merging::reset_merge_count();
+        algorithms::merging::reset_buffer_prefix();
+        algorithms::merging::two_way::reset_gallop_stats();
+        algorithms::merging::two_way::reset_adaptive_choices();
+        algorithms::cyclesort::reset_writes();
+        algorithms::powersort::reset_node_powers();
+        algorithms::insertionsort::reset_element_moves();
+    }
+}
+
+/// A single sample point for measuring running times
+#[derive(Debug, Clone, Copy)]
+struct TimeSample {
+    /// The elapsed wall clock time
+    wall: std::time::Duration,
+    /// The CPU time consumed by the measuring thread, see [`cpu_time::CpuInstant`]
+    cpu: std::time::Duration,
+}
+
+impl TimeSample {
+    /// Returns the running time measured by the given `source`.
+    #[allow(dead_code, reason = "Unused when feature 'counters' is active")]
+    fn time(self, source: cli::TimeSource) -> std::time::Duration {
+        match source {
+            cli::TimeSource::Wall => self.wall,
+            cli::TimeSource::Cpu => self.cpu,
+        }
+    }
+}
+
+/// A single sample point for measuring comparisons and merge costs
+#[derive(Debug, serde::Serialize)]
+struct CounterSample {
+    /// The number of comparisons
+    comparisons: u64,
+    /// The number of elements needed as additional merge allocation
+    merge_alloc_cost: u64,
+    /// The number of elements written to the original slice during merging
+    merge_slice_cost: u64,
+    /// The number of elements written to the buffer during merging
+    merge_buffer_cost: u64,
+    /// The maximum number of runs held on the run stack at once
+    max_stack_height: u64,
+    /// The number of elements galloped or bulk copied by galloping merges
+    galloped: u64,
+    /// The number of elements merged one at a time by galloping merges
+    gallop_single: u64,
+    /// The number of times galloping merges decremented their `min_gallop` threshold
+    min_gallop_decrements: u64,
+    /// The number of merges, see [`algorithms::merging::merge_count()`]
+    merges: u64,
+    /// The number of elements moved by insertion sorts, see
+    /// [`algorithms::insertionsort::element_moves()`]
+    element_moves: u64,
+    /// The number of merges adaptive merging delegated to galloping, see
+    /// [`algorithms::merging::two_way::adaptive_choices()`]
+    adaptive_galloping: u64,
+    /// The number of merges adaptive merging delegated to copy-both
+    adaptive_copy_both: u64,
+    /// The number of elements written by cycle sort, see [`algorithms::cyclesort::writes()`]
+    writes: u64,
+    /// The length of the largest buffer prefix used during merging, see
+    /// [`algorithms::merging::buffer_prefix()`]
+    merge_buffer_prefix: u64,
+    /// The capacity of the largest merging buffer allocated
+    merge_alloc_largest: u64,
+}
+
+/// The description of an experiment, shared by its [`ExperimentResult`]s
+#[derive(Debug, serde::Serialize)]
+struct ExperimentInfo {
+    /// The top level algorithm name
+    algorithm: String,
+    /// The index of the algorithm variant
+    variant: usize,
+    /// The name of the algorithm variant including its parameters, see [`algorithms::Sort::name()`]
+    name: String,
+    /// The multiline description of the algorithm variant, see [`cli::display()`]
+    description: String,
+    /// Whether the algorithm variant is stable
+    stable: bool,
+    /// The name of the datatype and distribution
+    data: String,
+    /// The base seed used for sampling the data
+    seed: u64,
+    /// The number of measured runs
+    runs: usize,
+    /// The number of unmeasured warmup runs
+    warmup: usize,
+    /// Whether every sort result was checked, see [`algorithms::Checked`]
+    checked: bool,
+    /// Whether the slices were sorted in descending order, see
+    /// [`algorithms::Sort::sort_descending()`]
+    descending: bool,
+    /// Whether every run sorted the same input
+    repeat_input: bool,
+    /// The number of smallest elements sorted in each run, if only those were sorted
+    first_k: Option<usize>,
+}
+
+/// The results measured for a single slice size, printed in JSON format
+#[derive(Debug, serde::Serialize)]
+struct ExperimentResult<'a, S: serde::Serialize> {
+    /// The experiment description
+    #[serde(flatten)]
+    info: &'a ExperimentInfo,
+    /// The size of the sorted slices
+    size: usize,
+    /// The auxiliary buffer memory in bytes allocated by the algorithm
+    buffer_bytes: usize,
+    /// The name of the measurement the [`Summary`] is computed for
+    metric: &'static str,
+    /// The statistics of the measurements
+    summary: Summary,
+    /// The individual samples
+    samples: S,
+}
+
+/// Statistics computed over a list of measurements
+#[derive(Debug, PartialEq, serde::Serialize)]
+struct Summary {
+    /// The arithmetic mean
+    mean: f64,
+    /// The sample standard deviation
+    std_dev: f64,
+    /// The minimum
+    min: f64,
+    /// The 25th percentile
+    p25: f64,
+    /// The median
+    p50: f64,
+    /// The 75th percentile
+    p75: f64,
+    /// The 90th percentile
+    p90: f64,
+    /// The 99th percentile
+    p99: f64,
+    /// The maximum
+    max: f64,
+}
+
+impl Summary {
+    /// Computes the summary of `values`, percentiles are computed by nearest rank.
+    ///
+    /// All statistics are `NaN` if `values` is empty.
+    fn new(values: impl Iterator<Item = f64>) -> Self {
+        let mut values: Vec<f64> = values.collect();
+        values.sort_by(f64::total_cmp);
+
+        #[expect(
+            clippy::as_conversions,
+            reason = "Realistically the number of samples fits into a f64"
+        )]
+        let len = values.len() as f64;
+        let mean = values.iter().sum::<f64>() / len;
+        let variance = values
+            .iter()
+            .map(|value| (value - mean).powi(2))
+            .sum::<f64>()
+            / (len - 1.0);
+
+        let percentile = |percent: usize| {
+            if values.is_empty() {
+                return f64::NAN;
+            }
+            let rank = (percent * values.len()).div_ceil(100);
+            values[rank.saturating_sub(1)]
+        };
+
+        Self {
+            mean,
+            std_dev: variance.sqrt(),
+            min: percentile(0),
+            p25: percentile(25),
+            p50: percentile(50),
+            p75: percentile(75),
+            p90: percentile(90),
+            p99: percentile(99),
+            max: percentile(100),
+        }
+    }
+
+    /// Formats the mean, standard deviation, minimum, median and maximum of nanosecond values as
+    /// aligned columns of durations with `precision` decimal places, see [`format_duration()`].
+    #[allow(dead_code, reason = "Unused when feature 'counters' is active")]
+    fn format_durations(&self, precision: usize) -> String {
+        let columns = [
+            ("mean", self.mean),
+            ("stddev", self.std_dev),
+            ("min", self.min),
+            ("median", self.p50),
+            ("max", self.max),
+        ]
+        .map(|(name, nanos)| (name, format_duration(nanos, precision)));
+        let width = columns
+            .iter()
+            .map(|(name, value)| name.len().max(value.chars().count()))
+            .max()
+            .unwrap_or(0);
+
+        let mut header = String::new();
+        let mut values = String::new();
+        for (name, value) in columns {
+            header.push_str(&format!("  {name:>width$}"));
+            values.push_str(&format!("  {value:>width$}"));
+        }
+
+        format!("{header}\n{values}\n")
+    }
+}
+
+/// Formats a duration of `nanos` nanoseconds with `precision` decimal places, scaled to the
+/// largest of ns, µs, ms and s keeping the value at least `1`.
+#[allow(dead_code, reason = "Unused when feature 'counters' is active")]
+fn format_duration(nanos: f64, precision: usize) -> String {
+    const UNITS: [(f64, &str); 3] = [(1e9, "s"), (1e6, "ms"), (1e3, "µs")];
+
+    let (scale, unit) = UNITS
+        .into_iter()
+        .find(|&(scale, _)| nanos.abs() >= scale)
+        .unwrap_or((1.0, "ns"));
+
+    format!("{:.precision$} {unit}", nanos / scale)
+}
+
+/// The number of resamples used to bootstrap the confidence intervals of [`CriterionEstimates`].
+///
+/// Criterion uses 100000 by default, but only for 100 samples, while experiments usually measure
+/// far more runs.
+const BOOTSTRAP_RESAMPLES: usize = 1_000;
+
+/// The confidence level of the confidence intervals of [`CriterionEstimates`].
+const CONFIDENCE_LEVEL: f64 = 0.95;
+
+/// The results measured for a single slice size, printed in the criterion JSON format
+#[derive(Debug, serde::Serialize)]
+struct CriterionResult {
+    /// The benchmark group, the name of the datatype and distribution
+    group_id: String,
+    /// The benchmarked function, the name of the algorithm variant
+    function_id: String,
+    /// The benchmark parameter, the size of the sorted slices
+    value_str: String,
+    /// The full benchmark id, `group_id/function_id/value_str`
+    full_id: String,
+    /// The estimates of the measurements
+    estimates: CriterionEstimates,
+}
+
+impl CriterionResult {
+    /// Estimates the statistics of the measurements `values` for the slice `size` of the
+    /// experiment described by `info`, bootstrapping from the experiment seed.
+    fn new(info: &ExperimentInfo, size: usize, values: &[f64]) -> Self {
+        Self {
+            group_id: info.data.clone(),
+            function_id: info.name.clone(),
+            value_str: size.to_string(),
+            full_id: format!("{data}/{name}/{size}", data = info.data, name = info.name),
+            estimates: CriterionEstimates::new(values, &mut data::seeded_rng(info.seed)),
+        }
+    }
+}
+
+/// The estimates of a benchmark, as in the `estimates.json` files written by criterion
+#[derive(Debug, serde::Serialize)]
+struct CriterionEstimates {
+    /// The arithmetic mean
+    mean: CriterionEstimate,
+    /// The median
+    median: CriterionEstimate,
+    /// The median absolute deviation, scaled to estimate the standard deviation
+    median_abs_dev: CriterionEstimate,
+    /// The slope of a linear regression over iteration counts, never measured here
+    slope: Option<CriterionEstimate>,
+    /// The sample standard deviation
+    std_dev: CriterionEstimate,
+}
+
+impl CriterionEstimates {
+    /// Estimates the statistics of `values`, bootstrapping the confidence intervals and standard
+    /// errors with [`BOOTSTRAP_RESAMPLES`] resamples drawn using `rng`.
+    ///
+    /// All estimates are `NaN` if `values` is empty.
+    fn new(values: &[f64], rng: &mut impl rand::Rng) -> Self {
+        let point_estimates = Self::statistics(&mut values.to_vec());
+
+        let mut resample = Vec::with_capacity(values.len());
+        let bootstrap: Vec<[f64; 4]> = if values.is_empty() {
+            Vec::new()
+        } else {
+            (0..BOOTSTRAP_RESAMPLES)
+                .map(|_| {
+                    resample.clear();
+                    resample.extend(
+                        (0..values.len()).map(|_| values[rng.random_range(0..values.len())]),
+                    );
+                    Self::statistics(&mut resample)
+                })
+                .collect()
+        };
+
+        let [mean, median, median_abs_dev, std_dev] = std::array::from_fn(|statistic| {
+            CriterionEstimate::new(
+                point_estimates[statistic],
+                bootstrap.iter().map(|statistics| statistics[statistic]),
+            )
+        });
+
+        Self {
+            mean,
+            median,
+            median_abs_dev,
+            slope: None,
+            std_dev,
+        }
+    }
+
+    /// Returns the mean, median, median absolute deviation and sample standard deviation of
+    /// `values`, sorting them.
+    fn statistics(values: &mut [f64]) -> [f64; 4] {
+        /// Scales the median absolute deviation to estimate the standard deviation of normally
+        /// distributed values, like criterion
+        const MAD_SCALE: f64 = 1.4826;
+
+        /// Returns the median of the sorted `values`, interpolating between the middle values
+        fn median(values: &[f64]) -> f64 {
+            match values.len() {
+                0 => f64::NAN,
+                len if len % 2 == 0 => (values[len / 2 - 1] + values[len / 2]) / 2.0,
+                len => values[len / 2],
+            }
+        }
+
+        values.sort_by(f64::total_cmp);
+        let summary = Summary::new(values.iter().copied());
+        let center = median(values);
+
+        let mut deviations: Vec<f64> = values.iter().map(|value| (value - center).abs()).collect();
+        deviations.sort_by(f64::total_cmp);
+
+        [
+            summary.mean,
+            center,
+            median(&deviations) * MAD_SCALE,
+            summary.std_dev,
+        ]
+    }
+}
+
+/// A single estimate of a statistic, as in the `estimates.json` files written by criterion
+#[derive(Debug, serde::Serialize)]
+struct CriterionEstimate {
+    /// The bootstrapped confidence interval
+    confidence_interval: ConfidenceInterval,
+    /// The statistic computed over all measurements
+    point_estimate: f64,
+    /// The standard deviation of the statistic over the bootstrap resamples
+    standard_error: f64,
+}
+
+impl CriterionEstimate {
+    /// Creates the estimate with the given `point_estimate` from the statistic computed for every
+    /// bootstrap resample.
+    fn new(point_estimate: f64, bootstrap: impl Iterator<Item = f64>) -> Self {
+        let mut bootstrap: Vec<f64> = bootstrap.collect();
+        bootstrap.sort_by(f64::total_cmp);
+
+        #[expect(
+            clippy::as_conversions,
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss,
+            reason = "The rank lies between 0 and the number of resamples"
+        )]
+        let percentile = |fraction: f64| {
+            let rank = (fraction * (bootstrap.len() as f64 - 1.0)).round() as usize;
+            bootstrap.get(rank).copied().unwrap_or(f64::NAN)
+        };
+
+        Self {
+            confidence_interval: ConfidenceInterval {
+                confidence_level: CONFIDENCE_LEVEL,
+                lower_bound: percentile((1.0 - CONFIDENCE_LEVEL) / 2.0),
+                upper_bound: percentile((1.0 + CONFIDENCE_LEVEL) / 2.0),
+            },
+            point_estimate,
+            standard_error: Summary::new(bootstrap.into_iter()).std_dev,
+        }
+    }
+}
+
+/// A confidence interval, as in the `estimates.json` files written by criterion
+#[derive(Debug, serde::Serialize)]
+struct ConfidenceInterval {
+    /// The probability of the statistic lying in the interval
+    confidence_level: f64,
+    /// The lower bound of the interval
+    lower_bound: f64,
+    /// The upper bound of the interval
+    upper_bound: f64,
+}
+
+/// Prints `result` as a single line of JSON.
+fn print_json(result: &impl serde::Serialize) {
+    println!(
+        "{}",
+        serde_json::to_string(result).expect("Experiment results should always serialize")
+    );
+}
+
+/// Performs a time sampling experiment on the given sorting algorithm
+///
+/// - `sorter`: The function used for sorting
+/// - `check`: Checks that a slice was sorted, see [`perform_experiment()`]
+/// - `runs`: The number of samples to measure
+/// - `warmup`: The number of unmeasured sorts to perform before measuring
+/// - `size`: The size of the slices to sort
+/// - `seed`: The base seed used for sampling the data, see [`data::run_rng()`]
+/// - `options`: The options used to construct the data generator, see [`data::DataOptions`]
+/// - `repeat_input`: Whether every run sorts the same input, see [`perform_experiment()`]
+/// - `pin_core`: The core to pin the measuring thread to, see [`perform_experiment()`]
+#[expect(clippy::too_many_arguments, reason = "Plain experiment parameters")]
+#[allow(dead_code, reason = "Unused when feature 'counters' is active")]
+fn perform_time_experiment<T: Ord + Clone + std::fmt::Debug, D: data::DataGenerator<T>>(
+    sorter: fn(&mut [T]),
+    check: fn(&[T]) -> bool,
     runs: usize,
+    warmup: usize,
     size: usize,
-    rng: &mut impl rand::Rng,
-) -> (Vec<std::time::Duration>, rolling_stats::Stats<f64>) {
+    seed: u64,
+    options: &data::DataOptions,
+    repeat_input: bool,
+    pin_core: Option<usize>,
+) -> Vec<TimeSample> {
     let mut samples = Vec::with_capacity(runs);
-    let mut stats: rolling_stats::Stats<f64> = rolling_stats::Stats::new();
 
     perform_experiment::<_, T, D>(
-        |elapsed| {
-            samples.push(elapsed);
-            #[expect(
-                clippy::as_conversions,
-                reason = "Millis should not get high enough for this cast to become inaccurate"
-            )]
-            stats.update(elapsed.as_millis() as f64);
-        },
+        |sample| samples.push(sample),
         sorter,
+        check,
         runs,
+        warmup,
         size,
-        rng,
+        seed,
+        options,
+        repeat_input,
+        pin_core,
     );
 
-    (samples, stats)
+    samples
+}
+
+/// Performs a running time experiment, only sorting the `k` smallest elements of each slice with
+/// `S`, see [`algorithms::PartialSort`].
+///
+/// Instead of the whole slice, only `data[..k]` is checked to hold the sorted `k` smallest
+/// elements, see [`first_k_sorted()`].
+///
+/// - `k`: The number of smallest elements to sort
+/// - `runs`: The number of samples to measure
+/// - `warmup`: The number of unmeasured sorts to perform before measuring
+/// - `size`: The size of the slices to sort
+/// - `seed`: The base seed used for sampling the data, see [`data::run_rng()`]
+/// - `options`: The options used to construct the data generator, see [`data::DataOptions`]
+/// - `repeat_input`: Whether every run sorts the same input, see [`perform_experiment()`]
+/// - `pin_core`: The core to pin the measuring thread to, see [`perform_experiment()`]
+#[expect(clippy::too_many_arguments, reason = "Plain experiment parameters")]
+#[allow(dead_code, reason = "Unused when feature 'counters' is active")]
+fn perform_first_k_experiment<
+    S: algorithms::PartialSort,
+    T: Ord + Clone + std::fmt::Debug,
+    D: data::DataGenerator<T>,
+>(
+    k: usize,
+    runs: usize,
+    warmup: usize,
+    size: usize,
+    seed: u64,
+    options: &data::DataOptions,
+    repeat_input: bool,
+    pin_core: Option<usize>,
+) -> Vec<TimeSample> {
+    let mut samples = Vec::with_capacity(runs);
+    let k = std::cmp::min(k, size);
+
+    perform_experiment::<_, T, D>(
+        |sample| samples.push(sample),
+        |data| S::sort_first_k(data, k),
+        |data| first_k_sorted(data, k),
+        runs,
+        warmup,
+        size,
+        seed,
+        options,
+        repeat_input,
+        pin_core,
+    );
+
+    samples
+}
+
+/// Returns whether `data[..k]` holds the `k` smallest elements of `data` in sorted order, with
+/// `k` clamped to the length of `data`.
+fn first_k_sorted<T: Ord>(data: &[T], k: usize) -> bool {
+    let (smallest, rest) = data.split_at(std::cmp::min(k, data.len()));
+
+    let separated = match (smallest.last(), rest.iter().min()) {
+        (Some(max), Some(min)) => max <= min,
+        _ => true,
+    };
+
+    smallest.is_sorted() && separated
+}
+
+/// Returns whether `data` is sorted in descending order, see `--descending`.
+fn sorted_descending<T: Ord>(data: &[T]) -> bool {
+    data.is_sorted_by(|a, b| a >= b)
 }
 
 /// Performs a sampling experiment on the given sorting algorithm.
 ///
-/// Records comparisons, as well as different merge costs, see [`CounterSample`].
+/// Records comparisons, as well as different merge costs and stack heights, see [`CounterSample`].
 ///
 /// - `sorter`: The function used for sorting
+/// - `check`: Checks that a slice was sorted, see [`perform_experiment()`]
 /// - `runs`: The number of samples to measure
+/// - `warmup`: The number of unmeasured sorts to perform before measuring
 /// - `size`: The size of the slices to sort
-/// - `rng`: The RNG used for sampling the data
+/// - `seed`: The base seed used for sampling the data, see [`data::run_rng()`]
+/// - `options`: The options used to construct the data generator, see [`data::DataOptions`]
+/// - `repeat_input`: Whether every run sorts the same input, see [`perform_experiment()`]
+/// - `pin_core`: The core to pin the measuring thread to, see [`perform_experiment()`]
+#[expect(clippy::too_many_arguments, reason = "Plain experiment parameters")]
 #[allow(dead_code, reason = "Unused when feature 'counters' is inactive")]
 fn perform_counters_experiment<
-    T: Ord + std::fmt::Debug,
+    T: Ord + Clone + std::fmt::Debug,
     D: data::DataGenerator<crate::data::CountComparisons<T>>,
 >(
     sorter: fn(&mut [crate::data::CountComparisons<T>]),
+    check: fn(&[crate::data::CountComparisons<T>]) -> bool,
     runs: usize,
+    warmup: usize,
     size: usize,
-    rng: &mut impl rand::Rng,
+    seed: u64,
+    options: &data::DataOptions,
+    repeat_input: bool,
+    pin_core: Option<usize>,
 ) -> (Vec<CounterSample>, rolling_stats::Stats<f64>) {
     let mut samples = Vec::with_capacity(runs);
     let mut stats = rolling_stats::Stats::<f64>::new();
@@ -252,14 +1990,32 @@ fn perform_counters_experiment<
         |_| {
             let comparisons = GLOBAL_COUNTERS.comparisons.read_and_reset();
             let merge_alloc_cost = GLOBAL_COUNTERS.merge_alloc.read_and_reset();
+            let merge_alloc_largest = GLOBAL_COUNTERS.merge_alloc_largest.read_and_reset();
             let merge_slice_cost = GLOBAL_COUNTERS.merge_slice.read_and_reset();
             let merge_buffer_cost = GLOBAL_COUNTERS.merge_buffer.read_and_reset();
+            let max_stack_height = GLOBAL_COUNTERS.max_stack_height.read_and_reset();
+            let merges = algorithms::merging::merge_count();
+            let merge_buffer_prefix = u64::try_from(algorithms::merging::buffer_prefix())
+                .expect("Buffer lengths should fit into u64");
+            let gallop_stats = algorithms::merging::two_way::gallop_stats();
+            let adaptive_choices = algorithms::merging::two_way::adaptive_choices();
 
             let sample = CounterSample {
                 comparisons,
+                merges,
                 merge_alloc_cost,
                 merge_slice_cost,
                 merge_buffer_cost,
+                max_stack_height,
+                galloped: gallop_stats.galloped(),
+                gallop_single: gallop_stats.single,
+                min_gallop_decrements: gallop_stats.min_gallop_decrements,
+                element_moves: algorithms::insertionsort::element_moves(),
+                adaptive_galloping: adaptive_choices.galloping,
+                adaptive_copy_both: adaptive_choices.copy_both,
+                writes: algorithms::cyclesort::writes(),
+                merge_buffer_prefix,
+                merge_alloc_largest,
             };
 
             samples.push(sample);
@@ -271,9 +2027,14 @@ fn perform_counters_experiment<
             stats.update(comparisons as f64);
         },
         sorter,
+        check,
         runs,
+        warmup,
         size,
-        rng,
+        seed,
+        options,
+        repeat_input,
+        pin_core,
     );
 
     (samples, stats)
@@ -281,49 +2042,1128 @@ fn perform_counters_experiment<
 
 /// Perform a generic sampling experiment on the given sorting algorithm.
 ///
-/// - `sampler`: The function used for sampling, receiving the running time of each sort iteration
+/// - `sampler`: The function used for sampling, receiving the running times of each sort iteration
 /// - `sorter`: The function used for sorting
+/// - `check`: Checks the data was sorted by `sorter`, usually [`slice::is_sorted()`]
 /// - `runs`: The number of samples to measure
+/// - `warmup`: The number of unmeasured sorts to perform before measuring
 /// - `size`: The size of the slices to sort
-/// - `rng`: The RNG used for sampling the data
+/// - `seed`: The base seed used for sampling the data, see [`data::run_rng()`]
+/// - `options`: The options used to construct the data generator, see [`data::DataOptions`]
+/// - `repeat_input`: Whether every run sorts a clone of the same input generated once, instead of
+///   generating new data for each run. The clone is made before starting the timer, so its cost is
+///   not included in the measured running time.
+/// - `pin_core`: The core to pin the measuring thread to before the runs, if any, see
+///   [`affinity::pin_current_thread()`]
+#[expect(clippy::too_many_arguments, reason = "Plain experiment parameters")]
 fn perform_experiment<
-    F: FnMut(std::time::Duration),
-    T: Ord + std::fmt::Debug,
+    F: FnMut(TimeSample),
+    T: Ord + Clone + std::fmt::Debug,
     D: data::DataGenerator<T>,
 >(
     mut sampler: F,
-    sorter: fn(&mut [T]),
+    mut sorter: impl FnMut(&mut [T]),
+    check: impl Fn(&[T]) -> bool,
     runs: usize,
+    warmup: usize,
     size: usize,
-    rng: &mut impl rand::Rng,
+    seed: u64,
+    options: &data::DataOptions,
+    repeat_input: bool,
+    pin_core: Option<usize>,
 ) {
+    #[cfg(not(feature = "core_affinity"))]
+    let _ = pin_core;
+    #[cfg(feature = "core_affinity")]
+    if let Some(core) = pin_core
+        && let Err(error) = affinity::pin_current_thread(core)
+    {
+        eprintln!("Failed to pin the measuring thread to core {core}: {error}");
+        std::process::exit(1);
+    }
+
     #[expect(
         clippy::as_conversions,
         reason = "Realistically runs is not gonna be higher than u64::MAX"
     )]
     let bar = indicatif::ProgressBar::new(runs as u64);
-    let mut generator = D::default();
-    let mut data = generator.initialize(size, rng);
+    let mut generator = D::from_options(options);
+    let repeated_input =
+        repeat_input.then(|| generator.initialize(size, &mut data::run_rng(seed, 0)));
+
+    // Reused by every run, to avoid allocating the input each time
+    let mut buffer = Vec::new();
+    let mut aligned_buffer = options
+        .align
+        .map(|align| aligned::AlignedBox::new_uninit_slice(size, align));
+
+    for run in 0..warmup + runs {
+        match &repeated_input {
+            Some(input) => buffer.clone_from(input),
+            // Generate the data for this run from its own seed, so every algorithm sorts the same
+            // data
+            None => generator.initialize_into(&mut buffer, size, &mut data::run_rng(seed, run)),
+        }
+
+        // Move the data to the aligned allocation for this run, if requested
+        let mut aligned;
+        let data: &mut [T] = match &mut aligned_buffer {
+            Some(aligned_buffer) => {
+                aligned = aligned_buffer.move_from_vec(&mut buffer);
+                &mut aligned
+            }
+            None => &mut buffer,
+        };
 
-    for run in 0..=runs {
         #[cfg(feature = "counters")]
         GLOBAL_COUNTERS.reset();
 
-        let now = std::time::Instant::now();
-        sorter(std::hint::black_box(&mut data));
-        let elapsed = now.elapsed();
+        // Read the CPU time outside of the wall clock measurement, so its system call is not
+        // charged to the wall time
+        let cpu = cpu_time::CpuInstant::now();
+        let wall = std::time::Instant::now();
+        sorter(std::hint::black_box(data));
+        let sample = TimeSample {
+            wall: wall.elapsed(),
+            cpu: cpu.elapsed(),
+        };
 
-        // Skip first sample (behavior taken from original codebase)
-        if run != 0 {
-            sampler(elapsed);
+        // Skip the warmup samples (the original codebase skipped exactly one)
+        if run >= warmup {
+            sampler(sample);
             bar.inc(1);
         }
 
+        // Checked in every build profile, since the experiments are usually run in release
         assert!(
-            data.is_sorted(),
-            "Data was not sorted after algorithm run: {run}"
+            check(data),
+            "Data was not sorted after algorithm run {run} (size {size}, seed {seed}{repeated})",
+            repeated = if repeat_input { ", repeated input" } else { "" },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    thread_local! {
+        /// The inputs recorded by [`record_and_sort()`].
+        static RECORDED_INPUTS: std::cell::RefCell<Vec<Vec<u32>>> = const {
+            std::cell::RefCell::new(Vec::new())
+        };
+    }
+
+    /// Records the input in [`RECORDED_INPUTS`] and then sorts it using `S`.
+    fn record_and_sort<S: algorithms::Sort>(slice: &mut [u32]) {
+        RECORDED_INPUTS.with_borrow_mut(|inputs| inputs.push(slice.to_vec()));
+        S::sort(slice);
+    }
+
+    /// Runs an experiment with `S` and returns the recorded inputs.
+    fn recorded_inputs<S: algorithms::Sort>(seed: u64, repeat_input: bool) -> Vec<Vec<u32>> {
+        perform_experiment::<_, u32, data::RandomRunsSqrtData>(
+            |_| {},
+            record_and_sort::<S>,
+            <[_]>::is_sorted,
+            crate::test::DEFAULT_TEST_RUNS,
+            1,
+            crate::test::DEFAULT_TEST_SIZE,
+            seed,
+            &data::DataOptions::default(),
+            repeat_input,
+            None,
+        );
+
+        RECORDED_INPUTS.take()
+    }
+
+    /// Sorts `slice` in descending order, i.e. incorrectly.
+    fn broken_sort(slice: &mut [u32]) {
+        slice.sort_by(|a, b| b.cmp(a));
+    }
+
+    thread_local! {
+        /// The addresses of the slices sorted by [`record_address_and_sort()`].
+        static RECORDED_ADDRESSES: std::cell::RefCell<Vec<usize>> = const {
+            std::cell::RefCell::new(Vec::new())
+        };
+    }
+
+    /// Records the address of `slice` in [`RECORDED_ADDRESSES`] and then sorts it.
+    fn record_address_and_sort(slice: &mut [u32]) {
+        RECORDED_ADDRESSES.with_borrow_mut(|addresses| addresses.push(slice.as_ptr().addr()));
+        slice.sort();
+    }
+
+    #[test]
+    fn test_aligned_input() {
+        for align in [1, 64, 4096, 1 << 16] {
+            RECORDED_ADDRESSES.with_borrow_mut(Vec::clear);
+
+            perform_experiment::<_, u32, data::PermutationData>(
+                |_| {},
+                record_address_and_sort,
+                <[_]>::is_sorted,
+                crate::test::DEFAULT_TEST_RUNS,
+                1,
+                crate::test::DEFAULT_TEST_SIZE,
+                crate::test::TEST_SEED,
+                &data::DataOptions {
+                    align: Some(align),
+                    ..data::DataOptions::default()
+                },
+                false,
+                None,
+            );
+
+            let addresses = RECORDED_ADDRESSES.take();
+            assert_eq!(addresses.len(), crate::test::DEFAULT_TEST_RUNS + 1);
+            assert!(
+                addresses.iter().all(|address| address % align == 0),
+                "Input not aligned to {align}"
+            );
+            // Every run reuses the same aligned allocation
+            assert!(addresses.iter().all(|&address| address == addresses[0]));
+        }
+
+        let parse =
+            |arguments: &[&str]| cli::Args::try_parse_from(["sort", "std"].iter().chain(arguments));
+        assert_eq!(parse(&["--align", "64"]).unwrap().align, Some(64));
+        assert!(parse(&["--align", "0"]).is_err());
+        assert!(parse(&["--align", "48"]).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "not sorted after algorithm run 0 (size 100, seed 42)")]
+    fn test_unsorted_run() {
+        perform_experiment::<_, u32, data::PermutationData>(
+            |_| {},
+            broken_sort,
+            <[_]>::is_sorted,
+            1,
+            0,
+            100,
+            42,
+            &data::DataOptions::default(),
+            false,
+            None,
+        );
+    }
+
+    #[test]
+    fn test_first_k_experiment() {
+        for k in [
+            0,
+            10,
+            crate::test::DEFAULT_TEST_SIZE,
+            2 * crate::test::DEFAULT_TEST_SIZE,
+        ] {
+            let samples = perform_first_k_experiment::<
+                algorithms::quicksort::QuickSort,
+                u32,
+                data::FewDistinctData,
+            >(
+                k,
+                crate::test::DEFAULT_TEST_RUNS,
+                1,
+                crate::test::DEFAULT_TEST_SIZE,
+                crate::test::TEST_SEED,
+                &data::DataOptions::default(),
+                k == 10,
+                None,
+            );
+
+            assert_eq!(samples.len(), crate::test::DEFAULT_TEST_RUNS);
+        }
+
+        assert!(first_k_sorted(&[1, 2, 4, 3], 2));
+        assert!(first_k_sorted(&[1, 2, 3, 4], 5));
+        assert!(!first_k_sorted(&[1, 2, 4, 3], 5));
+        assert!(!first_k_sorted(&[1, 3, 2, 4], 2));
+        assert!(!first_k_sorted(&[2, 1, 3, 4], 2));
+
+        let parse = |arguments: &[&str]| {
+            cli::Args::try_parse_from(["sort", "quicksort"].iter().chain(arguments))
+        };
+        assert_eq!(parse(&["--first-k", "10"]).unwrap().first_k, Some(10));
+        assert!(parse(&["--first-k", "10", "--checked"]).is_err());
+        assert!(parse(&["--first-k", "10", "--verify-only"]).is_err());
+    }
+
+    #[test]
+    fn test_descending() {
+        let sorter =
+            cli::AlgorithmVariants::descending_sorter(cli::Algorithm::Powersort, 0).unwrap();
+        let samples = perform_time_experiment::<u32, data::PermutationData>(
+            sorter,
+            sorted_descending,
+            crate::test::DEFAULT_TEST_RUNS,
+            1,
+            crate::test::DEFAULT_TEST_SIZE,
+            crate::test::TEST_SEED,
+            &data::DataOptions::default(),
+            false,
+            None,
+        );
+        assert_eq!(samples.len(), crate::test::DEFAULT_TEST_RUNS);
+
+        assert!(sorted_descending(&[3, 2, 2, 1]));
+        assert!(!sorted_descending(&[1, 2, 3]));
+
+        let parse = |arguments: &[&str]| {
+            cli::Args::try_parse_from(["sort", "powersort"].iter().chain(arguments))
+        };
+        assert!(parse(&["--descending"]).unwrap().descending);
+        assert!(parse(&["--descending", "--checked"]).is_err());
+        assert!(parse(&["--descending", "--verify-only"]).is_err());
+    }
+
+    #[test]
+    fn test_sample_count() {
+        for warmup in [0, 1, 3] {
+            let samples = perform_time_experiment::<u32, data::PermutationData>(
+                <algorithms::StdSort as algorithms::Sort>::sort,
+                <[_]>::is_sorted,
+                crate::test::DEFAULT_TEST_RUNS,
+                warmup,
+                crate::test::DEFAULT_TEST_SIZE,
+                crate::test::TEST_SEED,
+                &data::DataOptions::default(),
+                false,
+                None,
+            );
+
+            assert_eq!(samples.len(), crate::test::DEFAULT_TEST_RUNS);
+        }
+
+        let parse =
+            |arguments: &[&str]| cli::Args::try_parse_from(["sort", "std"].iter().chain(arguments));
+        assert_eq!(parse(&[]).unwrap().warmup, 1);
+        assert!(parse(&["--no-skip-first"]).unwrap().no_skip_first);
+        assert!(parse(&["--no-skip-first", "--warmup", "2"]).is_err());
+    }
+
+    #[test]
+    fn test_verify() {
+        type Data = data::RandomRunsConstData<30>;
+        let size = crate::test::DEFAULT_TEST_SIZE;
+
+        assert_eq!(
+            verify::<u32, Data>(
+                <algorithms::powersort::PowerSort as algorithms::Sort>::sort,
+                Some(<algorithms::powersort::PowerSort as algorithms::Sort>::sort),
+                size,
+                crate::test::TEST_SEED,
+                &data::DataOptions::default(),
+            ),
+            Ok(())
+        );
+        // The stability check sorts with the selected buffer as well
+        assert_eq!(
+            verify::<u32, Data>(
+                select_sorter(cli::Algorithm::Powersort, 0, cli::Buffer::Lazy, None, false),
+                Some(select_sorter(
+                    cli::Algorithm::Powersort,
+                    0,
+                    cli::Buffer::Lazy,
+                    None,
+                    false
+                )),
+                size,
+                crate::test::TEST_SEED,
+                &data::DataOptions::default(),
+            ),
+            Ok(())
+        );
+        assert!(matches!(
+            verify::<u32, Data>(
+                <[u32]>::reverse,
+                None,
+                size,
+                crate::test::TEST_SEED,
+                &data::DataOptions::default(),
+            ),
+            Err(VerificationError::Unsorted(_))
+        ));
+    }
+
+    #[test]
+    fn test_dump_output() {
+        use data::DataGenerator as _;
+
+        let sorter = <algorithms::powersort::PowerSort as algorithms::Sort>::sort;
+        let options = data::DataOptions::default();
+        let parse = |bytes: Vec<u8>| -> Vec<u32> {
+            String::from_utf8(bytes)
+                .unwrap()
+                .lines()
+                .map(|line| line.parse().unwrap())
+                .collect()
+        };
+
+        let (mut input, mut output) = (Vec::new(), Vec::new());
+        dump_sorted_output::<u32, data::RandomRunsSqrtData>(
+            sorter,
+            1_000,
+            crate::test::TEST_SEED,
+            &options,
+            &mut input,
+            &mut output,
+        )
+        .unwrap();
+        let (input, output) = (parse(input), parse(output));
+
+        let mut sorted: Vec<u32> = data::RandomRunsSqrtData
+            .initialize(1_000, &mut data::run_rng(crate::test::TEST_SEED, 0));
+        assert_eq!(input, sorted);
+        sorter(&mut sorted);
+
+        assert_eq!(output, sorted);
+        assert!(output.is_sorted());
+    }
+
+    #[test]
+    fn test_sorted_checksums() {
+        use algorithms::Sort;
+
+        type Data = data::FewDistinctData;
+        let size = crate::test::DEFAULT_TEST_SIZE;
+        let checksums = |sorter| {
+            sorted_checksums::<u32, Data>(
+                sorter,
+                size,
+                crate::test::TEST_SEED,
+                &data::DataOptions::default(),
+            )
+        };
+
+        let stable = checksums(<algorithms::powersort::PowerSort as Sort>::sort);
+        let unstable = checksums(<algorithms::quicksort::QuickSort as Sort>::sort);
+
+        // Stable sorts agree on the order of equal elements, which unstable sorts change
+        assert_eq!(stable, checksums(<algorithms::StdSort as Sort>::sort));
+        assert_eq!(stable.values, unstable.values);
+        assert_ne!(stable.indices, unstable.indices);
+    }
+
+    #[test]
+    fn test_compare_with_reference() {
+        use algorithms::Sort;
+
+        /// Sorts `slice` with std, but makes every element a copy of the first one of its value.
+        fn duplicating_sort(slice: &mut [data::IndexedOrdered<u32>]) {
+            slice.sort();
+            for index in 1..slice.len() {
+                if slice[index] == slice[index - 1] {
+                    slice[index] = slice[index - 1].clone();
+                }
+            }
+        }
+
+        type Data = data::FewDistinctData;
+        let size = crate::test::DEFAULT_TEST_SIZE;
+        let compare = |sorter, stable| {
+            compare_with_reference::<u32, Data>(
+                sorter,
+                <algorithms::StdSort as Sort>::sort,
+                stable,
+                size,
+                crate::test::TEST_SEED,
+                &data::DataOptions::default(),
+            )
+        };
+
+        assert_eq!(
+            compare(<algorithms::powersort::PowerSort as Sort>::sort, true),
+            Ok(())
+        );
+        assert_eq!(
+            compare(<algorithms::quicksort::QuickSort as Sort>::sort, false),
+            Ok(())
+        );
+        assert!(matches!(
+            compare(<algorithms::quicksort::QuickSort as Sort>::sort, true),
+            Err(VerificationError::DiffersFromReference(_))
+        ));
+        assert!(matches!(
+            compare(<[data::IndexedOrdered<u32>]>::reverse, false),
+            Err(VerificationError::DiffersFromReference(0))
+        ));
+
+        // Still sorted, but only caught with stability, since the duplicated values are equal
+        assert!(matches!(
+            compare(duplicating_sort, true),
+            Err(VerificationError::DiffersFromReference(_))
+        ));
+    }
+
+    #[test]
+    fn test_algorithms_sort_identical_data() {
+        let std_inputs = recorded_inputs::<algorithms::StdSort>(crate::test::TEST_SEED, false);
+        let powersort_inputs =
+            recorded_inputs::<algorithms::powersort::PowerSort>(crate::test::TEST_SEED, false);
+
+        assert_eq!(std_inputs.len(), crate::test::DEFAULT_TEST_RUNS + 1);
+        assert_eq!(std_inputs, powersort_inputs);
+
+        // Different runs should still see different data
+        assert_ne!(std_inputs[0], std_inputs[1]);
+    }
+
+    #[test]
+    fn test_repeat_input() {
+        let inputs = recorded_inputs::<algorithms::StdSort>(crate::test::TEST_SEED, true);
+
+        assert_eq!(inputs.len(), crate::test::DEFAULT_TEST_RUNS + 1);
+        assert!(inputs.iter().all(|input| *input == inputs[0]));
+
+        // The repeated input is the one the first run sees without repetition
+        let fresh_inputs = recorded_inputs::<algorithms::StdSort>(crate::test::TEST_SEED, false);
+        assert_eq!(inputs[0], fresh_inputs[0]);
+    }
+
+    #[test]
+    fn test_registered_stable_sorts() {
+        let mut rng = crate::test::test_rng();
+
+        for &algorithm in <cli::Algorithm as clap::ValueEnum>::value_variants() {
+            for variant in 0..cli::AlgorithmVariants::variants(algorithm).count() {
+                if !cli::AlgorithmVariants::is_stable(algorithm, variant).unwrap() {
+                    continue;
+                }
+
+                let sorter = cli::AlgorithmVariants::sorter(algorithm, variant).unwrap();
+                for n in [0, 1, 100, 1_000] {
+                    crate::test::assert_stable_sorter(sorter, n, &mut rng);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_quicksort_thresholds() {
+        use rand::seq::SliceRandom as _;
+
+        let mut rng = crate::test::test_rng();
+        let mut values: Vec<usize> = (0..crate::test::DEFAULT_TEST_SIZE).map(|i| i / 4).collect();
+
+        let mut tunable = 0;
+        for variant in 0..cli::AlgorithmVariants::variants(cli::Algorithm::Quicksort).count() {
+            // Variants with other pivot strategies can not be tuned
+            let Some(thresholds) = cli::QuicksortThresholds::new(variant, None, None) else {
+                continue;
+            };
+            tunable += 1;
+
+            // Without tuning, the thresholds describe the variant itself
+            assert_eq!(
+                thresholds.display(),
+                cli::AlgorithmVariants::variants(cli::Algorithm::Quicksort)
+                    .nth(variant)
+                    .unwrap()
+            );
+
+            for insertion in cli::QUICKSORT_INSERTION_THRESHOLDS {
+                for ninther in cli::QUICKSORT_NINTHER_THRESHOLDS {
+                    let thresholds =
+                        cli::QuicksortThresholds::new(variant, Some(insertion), Some(ninther))
+                            .unwrap();
+
+                    values.shuffle(&mut rng);
+                    thresholds.sorter(false)(&mut values);
+                    assert!(
+                        values.is_sorted(),
+                        "Not sorted with thresholds {thresholds:?}"
+                    );
+                }
+            }
+        }
+        assert!(tunable >= 2, "Only {tunable} tunable quicksort variants");
+    }
+
+    #[test]
+    fn test_quicksort_threshold_arguments() {
+        let parse = |arguments: &[&str]| {
+            cli::Args::try_parse_from(["sort", "quicksort"].iter().chain(arguments))
+        };
+
+        let args = parse(&["--insertion-threshold", "8", "--ninther-threshold", "256"]).unwrap();
+        assert_eq!(args.insertion_threshold, Some(8));
+        assert_eq!(args.ninther_threshold, Some(256));
+
+        assert!(parse(&["--insertion-threshold", "2"]).is_err());
+        assert!(parse(&["--insertion-threshold", "25"]).is_err());
+        assert!(parse(&["--ninther-threshold", "100"]).is_err());
+    }
+
+    #[test]
+    fn test_buffer_variants() {
+        for buffer in [cli::Buffer::Boxed, cli::Buffer::Aligned] {
+            for algorithm in [
+                cli::Algorithm::Powersort,
+                cli::Algorithm::Timsort,
+                cli::Algorithm::Mergesort,
+            ] {
+                let sorter = buffer
+                    .sorter::<u32>(algorithm, 0, true)
+                    .unwrap_or_else(|| panic!("No {buffer} buffer variant for {algorithm}"));
+
+                let mut values: Vec<u32> = (0..1000).rev().collect();
+                sorter(&mut values);
+                assert!(values.is_sorted());
+
+                assert!(buffer.sorter::<u32>(algorithm, 1, false).is_none());
+            }
+        }
+
+        assert!(
+            cli::Buffer::Boxed
+                .sorter::<u32>(cli::Algorithm::Std, 0, false)
+                .is_none()
+        );
+
+        let parse = |arguments: &[&str]| {
+            cli::Args::try_parse_from(["sort", "powersort"].iter().chain(arguments))
+        };
+        assert!(parse(&["--prefault-buffer"]).unwrap().prefault_buffer);
+        assert!(parse(&["--prefault-buffer", "--lazy-buffer"]).is_err());
+        assert!(parse(&["--prefault-buffer", "--buffer", "vec"]).is_err());
+    }
+
+    #[test]
+    fn test_bench_arguments() {
+        let args = cli::Args::try_parse_from(["sort", "bench"]).unwrap();
+        let Some(cli::Command::Bench(bench_args)) = args.command else {
+            panic!("Expected the bench command");
+        };
+        assert_eq!(
+            bench_args.algorithms,
+            [
+                cli::Algorithm::Powersort,
+                cli::Algorithm::Timsort,
+                cli::Algorithm::Std
+            ]
+        );
+        assert_eq!(bench_args.sizes, [1_000, 10_000, 100_000]);
+
+        let args =
+            cli::Args::try_parse_from(["sort", "bench", "-a", "quicksort", "-s", "10,20"]).unwrap();
+        let Some(cli::Command::Bench(bench_args)) = args.command else {
+            panic!("Expected the bench command");
+        };
+        assert_eq!(bench_args.algorithms, [cli::Algorithm::Quicksort]);
+        assert_eq!(bench_args.sizes, [10, 20]);
+
+        // Measuring a single algorithm still requires it
+        assert!(cli::Args::try_parse_from(["sort"]).is_err());
+        let args = cli::Args::try_parse_from(["sort", "powersort"]).unwrap();
+        assert!(args.command.is_none());
+        assert_eq!(args.algorithm, Some(cli::Algorithm::Powersort));
+    }
+
+    #[test]
+    fn test_merge_k_variants() {
+        for k in [2, 4, 8] {
+            let variant =
+                cli::AlgorithmVariants::merge_k_variant(cli::Algorithm::MultiwayMergesort, k)
+                    .unwrap_or_else(|| panic!("No multiway mergesort variant merging {k} runs"));
+
+            assert!(
+                cli::AlgorithmVariants::parameters(cli::Algorithm::MultiwayMergesort, variant)
+                    .unwrap()
+                    .contains(&("K", k.to_string()))
+            );
+        }
+
+        // Peeksort calls the parameter `k`
+        assert!(
+            cli::AlgorithmVariants::merge_k_variant(cli::Algorithm::MultiwayPeeksort, 3).is_some()
+        );
+        assert_eq!(
+            cli::AlgorithmVariants::merge_k_variant(cli::Algorithm::MultiwayMergesort, 5),
+            None
+        );
+        assert_eq!(
+            cli::AlgorithmVariants::merge_k_variant(cli::Algorithm::Powersort, 2),
+            None
         );
+    }
+
+    #[test]
+    fn test_kway_threshold_variants() {
+        for threshold in cli::KWAY_THRESHOLDS {
+            let variant = cli::AlgorithmVariants::kway_threshold_variant(
+                cli::Algorithm::MultiwayPowersort,
+                threshold,
+            )
+            .unwrap_or_else(|| panic!("No multiway powersort variant with threshold {threshold}"));
+
+            assert_eq!(
+                cli::AlgorithmVariants::is_stable(cli::Algorithm::MultiwayPowersort, variant),
+                Some(true)
+            );
+        }
+
+        assert_eq!(
+            cli::AlgorithmVariants::kway_threshold_variant(cli::Algorithm::Powersort, 64),
+            None
+        );
+    }
+
+    #[test]
+    fn test_insertion_method_variants() {
+        for method in cli::INSERTION_METHODS {
+            let variant = cli::AlgorithmVariants::insertion_method_variant(
+                cli::Algorithm::Insertionsort,
+                method,
+            )
+            .unwrap_or_else(|| panic!("No insertion sort variant inserting by {method}"));
+
+            assert!(
+                cli::AlgorithmVariants::parameters(cli::Algorithm::Insertionsort, variant)
+                    .unwrap()
+                    .contains(&("method", method.to_string()))
+            );
+        }
+
+        assert_eq!(
+            cli::AlgorithmVariants::insertion_method_variant(cli::Algorithm::Quicksort, "swapping"),
+            None
+        );
+        assert!(
+            cli::Args::try_parse_from(["sort", "insertionsort", "--insertion-method", "rotating"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_merging_method_variants() {
+        for method in cli::MERGING_METHODS {
+            let variant =
+                cli::AlgorithmVariants::merging_method_variant(cli::Algorithm::Mergesort, method)
+                    .unwrap_or_else(|| panic!("No mergesort variant merging by {method}"));
+
+            let parameters =
+                cli::AlgorithmVariants::parameters(cli::Algorithm::Mergesort, variant).unwrap();
+            assert!(
+                parameters
+                    .iter()
+                    .any(|(key, value)| *key == "merging" && value.starts_with(method))
+            );
+            assert!(parameters.contains(&("bottom-up", "false".to_string())));
+
+            let sorter =
+                cli::AlgorithmVariants::checked_sorter::<u32>(cli::Algorithm::Mergesort, variant)
+                    .unwrap();
+            let mut values: Vec<u32> = (0..1000).rev().collect();
+            sorter(&mut values);
+            assert!(values.is_sorted());
+        }
+
+        assert_eq!(
+            cli::AlgorithmVariants::merging_method_variant(cli::Algorithm::Timsort, "galloping"),
+            None
+        );
+        assert!(cli::Args::try_parse_from(["sort", "mergesort", "--merging", "block"]).is_err());
+        assert!(
+            cli::Args::try_parse_from(["sort", "mergesort", "--merging", "galloping", "--k", "2"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_binary_insertion_variants() {
+        for algorithm in [cli::Algorithm::Powersort, cli::Algorithm::Timsort] {
+            let variant = cli::AlgorithmVariants::binary_insertion_variant(algorithm)
+                .unwrap_or_else(|| panic!("No {algorithm} variant with binary insertion"));
+
+            let parameters = cli::AlgorithmVariants::parameters(algorithm, variant).unwrap();
+            assert!(
+                parameters
+                    .iter()
+                    .any(|(key, value)| *key == "i-sort" && value.contains("binary = true"))
+            );
+
+            let sorter = cli::AlgorithmVariants::checked_sorter::<u32>(algorithm, variant).unwrap();
+            let mut values: Vec<u32> = (0..1000).rev().collect();
+            sorter(&mut values);
+            assert!(values.is_sorted());
+        }
+
+        assert_eq!(
+            cli::AlgorithmVariants::binary_insertion_variant(cli::Algorithm::Quicksort),
+            None
+        );
+        assert!(
+            cli::Args::try_parse_from(["sort", "powersort", "--binary-insertion", "--k", "2"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_galloping_insertion_variants() {
+        for algorithm in [cli::Algorithm::Powersort, cli::Algorithm::Timsort] {
+            let variant = cli::AlgorithmVariants::galloping_insertion_variant(algorithm)
+                .unwrap_or_else(|| panic!("No {algorithm} variant with galloping insertion"));
+
+            let parameters = cli::AlgorithmVariants::parameters(algorithm, variant).unwrap();
+            assert!(
+                parameters
+                    .iter()
+                    .any(|(key, value)| *key == "i-sort" && value.contains("galloping"))
+            );
+
+            let sorter = cli::AlgorithmVariants::checked_sorter::<u32>(algorithm, variant).unwrap();
+            let mut values: Vec<u32> = (0..1000).rev().collect();
+            sorter(&mut values);
+            assert!(values.is_sorted());
+        }
+
+        assert_eq!(
+            cli::AlgorithmVariants::galloping_insertion_variant(cli::Algorithm::Quicksort),
+            None
+        );
+        assert!(
+            cli::Args::try_parse_from([
+                "sort",
+                "powersort",
+                "--galloping-insertion",
+                "--binary-insertion",
+            ])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_pivot_variants() {
+        for strategy in cli::PIVOT_STRATEGIES {
+            let variant =
+                cli::AlgorithmVariants::pivot_variant(cli::Algorithm::Quicksort, strategy)
+                    .unwrap_or_else(|| {
+                        panic!("No quicksort variant with pivot strategy {strategy}")
+                    });
+
+            let parameters =
+                cli::AlgorithmVariants::parameters(cli::Algorithm::Quicksort, variant).unwrap();
+            assert!(parameters.contains(&("pivot", strategy.to_string())));
+            assert!(parameters.contains(&("check-sorted", "false".to_string())));
+
+            let sorter =
+                cli::AlgorithmVariants::checked_sorter::<u32>(cli::Algorithm::Quicksort, variant)
+                    .unwrap();
+            let mut values: Vec<u32> = (0..1000).rev().collect();
+            sorter(&mut values);
+            assert!(values.is_sorted());
+        }
+
+        // The default strategy is the default variant
+        assert_eq!(
+            cli::AlgorithmVariants::pivot_variant(
+                cli::Algorithm::Quicksort,
+                "median-of-three-or-ninther"
+            ),
+            Some(0)
+        );
+        assert_eq!(
+            cli::AlgorithmVariants::pivot_variant(cli::Algorithm::Peeksort, "first"),
+            None
+        );
+        assert!(cli::Args::try_parse_from(["sort", "quicksort", "--pivot", "middle"]).is_err());
+    }
+
+    #[test]
+    fn test_iterative_variants() {
+        let variant = cli::AlgorithmVariants::iterative_variant(cli::Algorithm::Peeksort)
+            .expect("No iterative peeksort variant");
+
+        let parameters =
+            cli::AlgorithmVariants::parameters(cli::Algorithm::Peeksort, variant).unwrap();
+        assert!(parameters.contains(&("iterative", "true".to_string())));
+        assert!(parameters.contains(&("only-increasing", "false".to_string())));
+
+        let sorter =
+            cli::AlgorithmVariants::checked_sorter::<u32>(cli::Algorithm::Peeksort, variant)
+                .unwrap();
+        let mut values: Vec<u32> = (0..1000).rev().collect();
+        sorter(&mut values);
+        assert!(values.is_sorted());
+
+        assert_eq!(
+            cli::AlgorithmVariants::iterative_variant(cli::Algorithm::MultiwayPeeksort),
+            None
+        );
+        assert!(
+            cli::Args::try_parse_from([
+                "sort",
+                "peeksort",
+                "--iterative",
+                "--merging",
+                "galloping"
+            ])
+            .is_err()
+        );
+
+        let variant = cli::AlgorithmVariants::iterative_variant(cli::Algorithm::Mergesort)
+            .expect("No iterative mergesort variant");
+
+        let parameters =
+            cli::AlgorithmVariants::parameters(cli::Algorithm::Mergesort, variant).unwrap();
+        assert!(parameters.contains(&("iterative", "true".to_string())));
+
+        let sorter =
+            cli::AlgorithmVariants::checked_sorter::<u32>(cli::Algorithm::Mergesort, variant)
+                .unwrap();
+        let mut values: Vec<u32> = (0..1000).rev().collect();
+        sorter(&mut values);
+        assert!(values.is_sorted());
+    }
+
+    #[test]
+    fn test_balanced_variants() {
+        let variant = cli::AlgorithmVariants::balanced_variant(cli::Algorithm::Mergesort)
+            .expect("No balanced mergesort variant");
+
+        let parameters =
+            cli::AlgorithmVariants::parameters(cli::Algorithm::Mergesort, variant).unwrap();
+        assert!(parameters.contains(&("balanced", "true".to_string())));
+        assert!(parameters.contains(&("bottom-up", "true".to_string())));
+
+        let sorter =
+            cli::AlgorithmVariants::checked_sorter::<u32>(cli::Algorithm::Mergesort, variant)
+                .unwrap();
+        let mut values: Vec<u32> = (0..1000).rev().collect();
+        sorter(&mut values);
+        assert!(values.is_sorted());
+
+        assert_eq!(
+            cli::AlgorithmVariants::balanced_variant(cli::Algorithm::Peeksort),
+            None
+        );
+        assert!(
+            cli::Args::try_parse_from(["sort", "mergesort", "--balanced", "--iterative"]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_adaptive_min_run_variants() {
+        for algorithm in [cli::Algorithm::Powersort, cli::Algorithm::MultiwayPowersort] {
+            let variant = cli::AlgorithmVariants::adaptive_min_run_variant(algorithm)
+                .unwrap_or_else(|| panic!("No adaptive min run variant for {algorithm}"));
+
+            let parameters = cli::AlgorithmVariants::parameters(algorithm, variant).unwrap();
+            assert!(parameters.contains(&("adaptive-min-run", "true".to_string())));
+
+            let sorter = cli::AlgorithmVariants::checked_sorter::<u32>(algorithm, variant).unwrap();
+            let mut values: Vec<u32> = (0..1000).rev().collect();
+            sorter(&mut values);
+            assert!(values.is_sorted());
+        }
+
+        assert_eq!(
+            cli::AlgorithmVariants::adaptive_min_run_variant(cli::Algorithm::Mergesort),
+            None
+        );
+        assert!(
+            cli::Args::try_parse_from(["sort", "powersort", "--adaptive-min-run", "--balanced"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_detect_sorted_variants() {
+        let algorithm = cli::Algorithm::Powersort;
+        let variant = cli::AlgorithmVariants::detect_sorted_variant(algorithm)
+            .expect("No powersort variant detecting sorted input");
+
+        let parameters = cli::AlgorithmVariants::parameters(algorithm, variant).unwrap();
+        assert!(parameters.contains(&("detect-sorted", "true".to_string())));
+
+        // Sorted input costs exactly the comparisons of finding the single run
+        let sorter = cli::AlgorithmVariants::sorter(algorithm, variant).unwrap();
+        let n = crate::test::DEFAULT_TEST_SIZE;
+        let mut values: Vec<_> = (0..n).map(crate::test::CountingOrdered).collect();
+        let comparisons = crate::test::count_comparisons(|| sorter(&mut values));
+        assert!(values.is_sorted());
+        assert_eq!(comparisons, u64::try_from(n - 1).unwrap());
+
+        assert_eq!(
+            cli::AlgorithmVariants::detect_sorted_variant(cli::Algorithm::Timsort),
+            None
+        );
+        assert!(
+            cli::Args::try_parse_from(["sort", "powersort", "--detect-sorted", "--iterative"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_min_gallop_variants() {
+        for algorithm in [
+            cli::Algorithm::Timsort,
+            cli::Algorithm::Powersort,
+            cli::Algorithm::MultiwayPowersort,
+        ] {
+            for min_gallop in cli::MIN_GALLOPS {
+                let variant = cli::AlgorithmVariants::min_gallop_variant(algorithm, min_gallop)
+                    .unwrap_or_else(|| {
+                        panic!("No galloping variant with threshold {min_gallop} for {algorithm}")
+                    });
+
+                let sorter =
+                    cli::AlgorithmVariants::checked_sorter::<u32>(algorithm, variant).unwrap();
+                let mut values: Vec<u32> = (0..1000).rev().chain(0..1000).collect();
+                sorter(&mut values);
+                assert!(values.is_sorted());
+            }
+        }
+
+        assert_eq!(
+            cli::AlgorithmVariants::min_gallop_variant(cli::Algorithm::NaturalMergesort, 7),
+            None
+        );
+        assert!(cli::Args::try_parse_from(["sort", "timsort", "--min-gallop", "5"]).is_err());
+        assert!(
+            cli::Args::try_parse_from([
+                "sort",
+                "timsort",
+                "--min-gallop",
+                "3",
+                "--merging",
+                "adaptive"
+            ])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_block_size_variants() {
+        for algorithm in [cli::Algorithm::Powersort, cli::Algorithm::Timsort] {
+            for block_size in cli::BLOCK_SIZES {
+                assert!(
+                    cli::AlgorithmVariants::block_merge_variant(algorithm, block_size).is_some(),
+                    "No block merging variant with block size {block_size} for {algorithm}"
+                );
+            }
+        }
+
+        assert_eq!(
+            cli::AlgorithmVariants::block_merge_variant(cli::Algorithm::Powersort, 3),
+            None
+        );
+        assert_eq!(
+            cli::AlgorithmVariants::block_merge_variant(cli::Algorithm::Std, cli::BLOCK_SIZES[0]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_summary() {
+        let summary = Summary::new((1..=100).map(f64::from));
+
+        assert_eq!(summary.mean, 50.5);
+        assert!((summary.std_dev - 29.011_491).abs() < 1e-6);
+        assert_eq!(
+            [summary.min, summary.p25, summary.p50, summary.p75],
+            [1.0, 25.0, 50.0, 75.0]
+        );
+        assert_eq!([summary.p90, summary.p99, summary.max], [90.0, 99.0, 100.0]);
+
+        let summary = Summary::new([3.0, 1.0, 2.0].into_iter());
+        assert_eq!([summary.min, summary.p50, summary.max], [1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn test_criterion_json() {
+        use rand::Rng as _;
+
+        let info = ExperimentInfo {
+            algorithm: "std".to_string(),
+            variant: 0,
+            name: "std-stable".to_string(),
+            description: String::new(),
+            stable: true,
+            data: cli::DataType::PermutationU32.to_string(),
+            seed: crate::test::TEST_SEED,
+            runs: 1_000,
+            warmup: 1,
+            checked: false,
+            descending: false,
+            repeat_input: false,
+            first_k: None,
+        };
+
+        let mut rng = crate::test::test_rng();
+        let values: Vec<f64> = (0..info.runs)
+            .map(|_| 1e6 + rng.random_range(0.0..1e5))
+            .collect();
+        let mut stats = rolling_stats::Stats::<f64>::new();
+        values.iter().for_each(|&value| stats.update(value));
+
+        let json = serde_json::to_string(&CriterionResult::new(&info, 1_000, &values)).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(
+            parsed["full_id"],
+            format!("{data}/std-stable/1000", data = info.data)
+        );
+        assert!(parsed["estimates"]["slope"].is_null());
+
+        let mean = &parsed["estimates"]["mean"];
+        let point_estimate = mean["point_estimate"].as_f64().unwrap();
+        assert!((point_estimate - stats.mean).abs() < 1e-6 * stats.mean);
+
+        // The bootstrapped intervals contain the point estimates
+        for statistic in ["mean", "median", "median_abs_dev", "std_dev"] {
+            let estimate = &parsed["estimates"][statistic];
+            let point_estimate = estimate["point_estimate"].as_f64().unwrap();
+            let interval = &estimate["confidence_interval"];
+
+            assert_eq!(interval["confidence_level"], CONFIDENCE_LEVEL);
+            assert!(
+                interval["lower_bound"].as_f64().unwrap() <= point_estimate
+                    && point_estimate <= interval["upper_bound"].as_f64().unwrap(),
+                "{statistic} not within its confidence interval: {estimate}"
+            );
+            assert!(estimate["standard_error"].as_f64().unwrap() > 0.0);
+        }
+
+        // The standard error of the mean is close to the standard deviation over `sqrt(runs)`
+        let standard_error = mean["standard_error"].as_f64().unwrap();
+        let expected = stats.std_dev / f64::from(u32::try_from(info.runs).unwrap()).sqrt();
+        assert!((standard_error - expected).abs() < 0.2 * expected);
+
+        // Empty measurements do not panic
+        let estimates = CriterionEstimates::new(&[], &mut crate::test::test_rng());
+        assert!(estimates.mean.point_estimate.is_nan());
+
+        let parse =
+            |arguments: &[&str]| cli::Args::try_parse_from(["sort", "std"].iter().chain(arguments));
+        assert_eq!(
+            parse(&["--format", "criterion-json"]).unwrap().format,
+            cli::OutputFormat::CriterionJson
+        );
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(0.0, 3), "0.000 ns");
+        assert_eq!(format_duration(412.5, 1), "412.5 ns");
+        assert_eq!(format_duration(999.0, 0), "999 ns");
+        assert_eq!(format_duration(1_000.0, 2), "1.00 µs");
+        assert_eq!(format_duration(2_345_678.0, 3), "2.346 ms");
+        assert_eq!(format_duration(3_725_000_000.0, 2), "3.73 s");
+        assert_eq!(format_duration(125_000_000_000.0, 1), "125.0 s");
+
+        let lines = Summary::new([500.0, 1_500.0, 2_500_000.0].into_iter()).format_durations(3);
+        let lines: Vec<&str> = lines.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].chars().count(), lines[1].chars().count());
+        assert!(lines[0].contains("median") && lines[1].contains("1.500 µs"));
 
-        generator.reinitialize(&mut data, rng);
+        let parse =
+            |arguments: &[&str]| cli::Args::try_parse_from(["sort", "std"].iter().chain(arguments));
+        assert_eq!(parse(&[]).unwrap().precision, 3);
+        assert_eq!(parse(&["--precision", "1"]).unwrap().precision, 1);
     }
 }
@@ -0,0 +1,170 @@
+//! Presortedness measures of input data, see [`InputMetrics`].
+
+/// Measures of how presorted a slice is, see `--describe-input`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InputMetrics {
+    /// The number of pairs in the wrong order, see [`inversions()`]
+    pub inversions: u64,
+    /// The number of natural runs, see [`natural_runs()`]
+    pub runs: usize,
+    /// The normalized entropy of the value frequencies, see [`normalized_entropy()`]
+    pub entropy: f64,
+}
+
+impl InputMetrics {
+    /// Measures the given `slice`.
+    pub fn new<T: Ord>(slice: &[T]) -> Self {
+        Self {
+            inversions: inversions(slice),
+            runs: natural_runs(slice),
+            entropy: normalized_entropy(slice),
+        }
+    }
+}
+
+impl std::fmt::Display for InputMetrics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{inversions} inversions, {runs} runs, entropy {entropy:.4}",
+            inversions = self.inversions,
+            runs = self.runs,
+            entropy = self.entropy,
+        )
+    }
+}
+
+/// Returns the number of pairs `i < j` with `slice[i] > slice[j]`.
+///
+/// The inversions are counted while merge sorting references to the elements, taking
+/// `O(n log n)` comparisons.
+pub fn inversions<T: Ord>(slice: &[T]) -> u64 {
+    let mut elements: Vec<&T> = slice.iter().collect();
+    let mut buffer = Vec::with_capacity(elements.len());
+
+    count_inversions(&mut elements, &mut buffer)
+}
+
+/// Sorts `elements` and returns the number of inversions in it, see [`inversions()`].
+///
+/// `buffer` is used for merging and cleared before each use.
+fn count_inversions<'a, T: Ord>(elements: &mut [&'a T], buffer: &mut Vec<&'a T>) -> u64 {
+    if elements.len() < 2 {
+        return 0;
+    }
+
+    let mid = elements.len() / 2;
+    let mut inversions = count_inversions(&mut elements[..mid], buffer)
+        + count_inversions(&mut elements[mid..], buffer);
+
+    // Every element taken from the right run is inverted with all remaining left elements
+    buffer.clear();
+    let (mut left, mut right) = (0, mid);
+    while left < mid && right < elements.len() {
+        if elements[right] < elements[left] {
+            buffer.push(elements[right]);
+            right += 1;
+            inversions += u64::try_from(mid - left).unwrap();
+        } else {
+            buffer.push(elements[left]);
+            left += 1;
+        }
+    }
+    buffer.extend_from_slice(&elements[left..mid]);
+    buffer.extend_from_slice(&elements[right..]);
+    elements.copy_from_slice(buffer);
+
+    inversions
+}
+
+/// Returns the number of maximal weakly increasing runs in `slice`.
+///
+/// Strictly decreasing runs are not reversed, so a reverse sorted slice of distinct elements
+/// consists of `slice.len()` runs.
+pub fn natural_runs<T: Ord>(slice: &[T]) -> usize {
+    if slice.is_empty() {
+        return 0;
+    }
+
+    slice.windows(2).filter(|pair| pair[0] > pair[1]).count() + 1
+}
+
+/// Returns the entropy of the value frequencies in `slice`, normalized to `0.0..=1.0` by the
+/// entropy of `slice.len()` distinct values.
+///
+/// Slices with less than two elements have an entropy of `0.0`.
+pub fn normalized_entropy<T: Ord>(slice: &[T]) -> f64 {
+    if slice.len() < 2 {
+        return 0.0;
+    }
+
+    let mut elements: Vec<&T> = slice.iter().collect();
+    elements.sort_unstable();
+
+    #[expect(
+        clippy::as_conversions,
+        reason = "The slice length will realistically stay below 2^52, so this is lossless"
+    )]
+    let len = slice.len() as f64;
+    let entropy: f64 = elements
+        .chunk_by(|a, b| a == b)
+        .map(|values| {
+            #[expect(
+                clippy::as_conversions,
+                reason = "The frequency will realistically stay below 2^52, so this is lossless"
+            )]
+            let probability = values.len() as f64 / len;
+            -probability * probability.log2()
+        })
+        .sum();
+
+    entropy / len.log2()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sorted_metrics() {
+        let values: Vec<usize> = (0..1_000).collect();
+
+        let metrics = InputMetrics::new(&values);
+        assert_eq!(metrics.inversions, 0);
+        assert_eq!(metrics.runs, 1);
+        assert!((metrics.entropy - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_reverse_sorted_metrics() {
+        let values: Vec<u64> = (0..1_000).rev().collect();
+        let n = 1_000;
+
+        let metrics = InputMetrics::new(&values);
+        assert_eq!(metrics.inversions, n * (n - 1) / 2);
+        assert_eq!(metrics.runs, values.len());
+    }
+
+    #[test]
+    fn test_inversions() {
+        use rand::Rng as _;
+
+        let mut rng = crate::test::test_rng();
+        let values: Vec<usize> = (0..500).map(|_| rng.random_range(0..50)).collect();
+
+        let expected = (0..values.len())
+            .flat_map(|i| (i + 1..values.len()).map(move |j| (i, j)))
+            .filter(|&(i, j)| values[i] > values[j])
+            .count();
+        assert_eq!(inversions(&values), u64::try_from(expected).unwrap());
+    }
+
+    #[test]
+    fn test_normalized_entropy() {
+        assert_eq!(normalized_entropy::<u32>(&[]), 0.0);
+        assert_eq!(normalized_entropy(&[7; 100]), 0.0);
+
+        // Two equally frequent values in four elements carry half the maximum entropy
+        assert!((normalized_entropy(&[1, 2, 1, 2]) - 0.5).abs() < 1e-9);
+    }
+}
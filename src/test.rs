@@ -115,72 +115,104 @@ impl<const LIKELIHOOD: usize, T: Ord> Ord for MaybePanickingOrdered<LIKELIHOOD,
     }
 }
 
-/// A Wrapper struct that tracks an original index with an ordered element.
-///
-/// Intended to test sort results for stability.
+pub use crate::data::IndexedOrdered;
+
+std::thread_local! {
+    /// The number of comparisons of [`CountingOrdered`] elements on this thread.
+    static COMPARISONS: std::cell::Cell<u64> = const { std::cell::Cell::new(0) };
+}
+
+/// A wrapper counting its comparisons in a thread local counter, see [`count_comparisons()`].
 ///
-/// When compared, the call is intentionally forwarded to the implementation of `T`.
-/// To check for stable sorting, see [`Self::is_stable_sorted()`]
-#[derive(Debug, Clone)]
-pub struct IndexedOrdered<T: Ord>(usize, T);
+/// Unlike [`crate::data::CountComparisons`] this does not use the global counters, so tests
+/// running in parallel do not interfere with each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CountingOrdered<T: Ord>(pub T);
 
-impl<T: Ord> IndexedOrdered<T> {
-    /// Creates a new iterator of `IndexedOrdered`, tracking the position of each element in `iter`.
-    pub fn map_iter(iter: impl Iterator<Item = T>) -> impl Iterator<Item = Self> {
-        iter.enumerate()
-            .map(|(index, element)| Self(index, element))
+impl<T: Ord> PartialOrd for CountingOrdered<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
     }
+}
 
-    /// Checks that `iter` is sorted and check for stability, e.g. equal elements keeping their
-    /// initial relative ordering.
-    ///
-    /// Returns `Ok(result)` if `iter` is sorted with regards to `T` where `result` indicates if
-    /// the sort is stable. Otherwise, returns `Err(())` if `iter` was not sorted with regards to
-    /// `T`.
-    pub fn is_stable_sorted<'a>(mut iter: impl Iterator<Item = &'a Self>) -> Result<bool, ()>
-    where
-        T: 'a,
-    {
-        let Some(mut previous) = iter.next() else {
-            return Ok(true);
-        };
-
-        for current in iter {
-            match current.cmp(previous) {
-                // Slice is not sorted
-                std::cmp::Ordering::Less => return Err(()),
-                // Elements are not stable
-                std::cmp::Ordering::Equal if current.0 < previous.0 => return Ok(false),
-                _ => {}
-            }
-
-            previous = current;
+impl<T: Ord> Ord for CountingOrdered<T> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        if crate::data::is_counting() {
+            COMPARISONS.set(COMPARISONS.get() + 1);
         }
-
-        Ok(true)
+        self.0.cmp(&other.0)
     }
 }
 
-impl<T: Ord> PartialEq for IndexedOrdered<T> {
-    fn eq(&self, other: &Self) -> bool {
-        self.1 == other.1
-    }
+/// Executes `f` and returns the number of [`CountingOrdered`] comparisons it performed, excluding
+/// [`crate::data::uncounted()`] ones.
+pub fn count_comparisons(f: impl FnOnce()) -> u64 {
+    let before = COMPARISONS.get();
+    f();
+    COMPARISONS.get() - before
 }
 
-impl<T: Ord> Eq for IndexedOrdered<T> {}
+/// Returns a permutation of `0..size` made of shuffled sorted clusters of `cluster_length`
+/// consecutive values, so most merges take long blocks from either run.
+pub fn clustered_permutation(size: usize, cluster_length: usize, rng: &mut Rng) -> Vec<usize> {
+    use rand::seq::SliceRandom as _;
 
-impl<T: Ord> PartialOrd for IndexedOrdered<T> {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
-    }
+    let mut clusters: Vec<_> = (0..size)
+        .collect::<Vec<usize>>()
+        .chunks(cluster_length)
+        .map(<[usize]>::to_vec)
+        .collect();
+    clusters.shuffle(rng);
+
+    clusters.into_iter().flatten().collect()
 }
 
-impl<T: Ord> Ord for IndexedOrdered<T> {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.1.cmp(&other.1)
+/// Returns the ratio between the comparison counts `comparisons` and `baseline`.
+pub fn comparison_ratio(comparisons: u64, baseline: u64) -> f64 {
+    #[expect(
+        clippy::as_conversions,
+        reason = "The comparison counts stay way below 2^52, so this is lossless"
+    )]
+    let ratio = comparisons as f64 / baseline as f64;
+    ratio
+}
+
+std::thread_local! {
+    /// The number of bytes allocated by [`RecordingBufGuardFactory`] buffers on this thread.
+    static ALLOCATED_BYTES: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+/// A [`crate::algorithms::BufGuardFactory`] producing `Vec<T>` buffers, recording their allocated
+/// capacity in a thread local counter, see [`count_allocated_bytes()`].
+pub struct RecordingBufGuardFactory;
+
+impl crate::algorithms::BufGuardFactory for RecordingBufGuardFactory {
+    type Guard<T> = RecordingBufGuard<T>;
+}
+
+/// The buffer produced by [`RecordingBufGuardFactory`].
+pub struct RecordingBufGuard<T>(Vec<T>);
+
+impl<T> crate::algorithms::merging::BufGuard<T> for RecordingBufGuard<T> {
+    fn with_capacity(capacity: usize) -> Self {
+        let buffer = Vec::with_capacity(capacity);
+        ALLOCATED_BYTES.set(ALLOCATED_BYTES.get() + buffer.capacity() * std::mem::size_of::<T>());
+        Self(buffer)
+    }
+
+    fn as_uninit_slice_mut(&mut self) -> &mut [std::mem::MaybeUninit<T>] {
+        self.0.spare_capacity_mut()
     }
 }
 
+/// Executes `f` and returns the number of bytes allocated by [`RecordingBufGuardFactory`]
+/// buffers in the meantime.
+pub fn count_allocated_bytes(f: impl FnOnce()) -> usize {
+    let before = ALLOCATED_BYTES.get();
+    f();
+    ALLOCATED_BYTES.get() - before
+}
+
 /// Generates a sequence of random test functions, to test a [`crate::algorithms::Sort`].
 ///
 /// # Example usage
@@ -316,6 +348,74 @@ pub fn test_random_stable_sorted<
     );
 }
 
+/// Sorts `n` random values with many duplicates using `sorter` and asserts that the result is
+/// sorted and equal values kept their initial relative order.
+///
+/// The values are tagged with their initial index, see [`IndexedOrdered`].
+pub fn assert_stable_sorter(sorter: fn(&mut [IndexedOrdered<usize>]), n: usize, rng: &mut Rng) {
+    let distinct = std::cmp::max(n / 4, 1);
+    let mut values: Box<[_]> =
+        IndexedOrdered::map_iter((0..n).map(|_| rng.random_range(0..distinct))).collect();
+
+    sorter(&mut values);
+
+    match IndexedOrdered::is_stable_sorted(values.iter()) {
+        Ok(stable) => assert!(stable, "Elements were not sorted stable"),
+        Err(()) => panic!("Elements were not sorted at all"),
+    }
+}
+
+/// Asserts that `S` sorts `n` random values stably, see [`assert_stable_sorter()`].
+pub fn assert_stable_sort<S: crate::algorithms::Sort>(n: usize, rng: &mut Rng) {
+    assert_stable_sorter(S::sort, n, rng);
+}
+
+/// Runs the sort with [`RandomOrdered`] elements and [`MaybePanickingOrdered`] elements, checking
+/// that no elements got lost or duplicated, mostly useful for running under MIRI.
+pub fn test_soundness<const RUNS: usize, const TEST_SIZE: usize, S: crate::algorithms::Sort>() {
+    use rand::RngCore as _;
+
+    let mut rng = test_rng();
+
+    // Test RandomOrdered slices
+    for _ in 0..RUNS {
+        let mut elements: Box<[RandomOrdered]> = RandomOrdered::new_iter(rng.next_u64())
+            .take(TEST_SIZE)
+            .collect();
+
+        let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            S::sort(&mut elements);
+        }));
+
+        drop(elements);
+    }
+
+    // Test MaybePanickingOrdered slices
+    for _ in 0..RUNS {
+        let values: Box<[usize]> = (0..TEST_SIZE)
+            .map(|_| rng.random_range(0..TEST_SIZE))
+            .collect();
+        let mut expected = values.clone();
+        expected.sort();
+
+        let mut elements: Box<[MaybePanickingOrdered<TEST_SIZE, usize>]> =
+            MaybePanickingOrdered::map_iter(values.into_iter(), rng.next_u64()).collect();
+
+        // The types are not actually unwind safe but must not trigger UB anyway
+        let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            S::sort(&mut elements);
+        }));
+
+        // Make sure no elements got lost or duplicated
+        let mut collected: Box<[usize]> = elements
+            .into_iter()
+            .map(MaybePanickingOrdered::into_inner)
+            .collect();
+        collected.sort();
+        assert_eq!(collected, expected);
+    }
+}
+
 /// Utility methods for testing merging methods.
 #[cfg(test)]
 pub mod merging {
@@ -324,8 +424,8 @@ pub mod merging {
 
     /// How big the test arrays should be.
     const TEST_SIZE: usize = 100;
-    /// How many times to run each test.
-    const TEST_RUNS: usize = 100;
+    /// How many times to run each test, fewer to keep the tests feasible under MIRI.
+    const TEST_RUNS: usize = if cfg!(miri) { 5 } else { 100 };
 
     /// Tests merging an empty slice.
     pub fn test_empty_merge<
@@ -369,6 +469,169 @@ pub mod merging {
         }
     }
 
+    /// Splits the slice into `1..=K` sorted runs of arbitrary lengths, including empty runs and
+    /// runs of length 1.
+    ///
+    /// The lengths of all but the last run are pushed to `run_lengths`, the last run takes up the
+    /// rest of the slice.
+    fn generate_arbitrary_runs<T: Ord, const K: usize>(
+        slice: &mut [T],
+        run_lengths: &mut Vec<usize>,
+        rng: &mut crate::test::Rng,
+    ) {
+        run_lengths.clear();
+        let num_splits = rng.random_range(0..K);
+
+        let mut last = 0;
+        for _ in 0..num_splits {
+            let remaining = slice.len() - last;
+
+            // Favor the edge cases over uniformly random lengths
+            let run_len = match rng.random_range(0..5) {
+                0 => 0,
+                1 => std::cmp::min(1, remaining),
+                2 => remaining,
+                _ => rng.random_range(0..=remaining),
+            };
+
+            slice[last..last + run_len].sort();
+            run_lengths.push(run_len);
+            last += run_len;
+        }
+
+        slice[last..].sort();
+    }
+
+    /// Tests that arbitrary partitions into sorted runs, see [`generate_arbitrary_runs()`], are
+    /// merged correctly and stable (if `T` is stable).
+    pub fn test_arbitrary_runs_merge<
+        T: crate::algorithms::merging::MultiMergingMethod<K>,
+        const K: usize,
+    >() {
+        let mut rng = crate::test::test_rng();
+        let mut buffer = <Vec<_> as crate::algorithms::merging::BufGuard<_>>::with_capacity(
+            T::required_capacity(TEST_SIZE),
+        );
+        let mut run_lengths = Vec::with_capacity(K - 1);
+
+        for run in 0..TEST_RUNS * 10 {
+            // Favor tiny slices, where every run is likely to be empty or a single element
+            let len = match run % 2 {
+                0 => rng.random_range(0..=K),
+                _ => rng.random_range(0..=TEST_SIZE),
+            };
+            let mut elements: Box<[_]> = crate::test::IndexedOrdered::map_iter(
+                (0..len).map(|_| rng.random_range(0..std::cmp::max(len / 4, 1))),
+            )
+            .collect();
+
+            generate_arbitrary_runs::<_, K>(&mut elements, &mut run_lengths, &mut rng);
+
+            T::merge(&mut elements, &run_lengths, buffer.as_uninit_slice_mut());
+
+            // Make sure no elements got lost or duplicated
+            let mut indices: Box<[usize]> = elements
+                .iter()
+                .map(crate::test::IndexedOrdered::index)
+                .collect();
+            indices.sort();
+            assert!(indices.into_iter().eq(0..len));
+
+            match crate::test::IndexedOrdered::is_stable_sorted(elements.iter()) {
+                Ok(stable) => assert!(
+                    stable || !T::IS_STABLE,
+                    "Elements were not merged stable by {name} in run {run} with run lengths \
+                    {run_lengths:?}",
+                    name = T::display(),
+                ),
+                Err(()) => panic!(
+                    "Elements were not merged by {name} in run {run} with run lengths \
+                    {run_lengths:?}",
+                    name = T::display(),
+                ),
+            }
+        }
+    }
+
+    /// Tests merges with empty runs at every combination of positions, e.g. run lengths
+    /// `[10, 0, 5]`, for every number of runs up to `K`.
+    pub fn test_empty_runs_merge<
+        T: crate::algorithms::merging::MultiMergingMethod<K>,
+        const K: usize,
+    >() {
+        /// The lengths of the non-empty runs, by position
+        const RUN_LENGTHS: [usize; 8] = [10, 5, 7, 1, 3, 12, 2, 8];
+
+        let mut rng = crate::test::test_rng();
+        let mut buffer = <Vec<_> as crate::algorithms::merging::BufGuard<_>>::with_capacity(
+            T::required_capacity(TEST_SIZE),
+        );
+
+        for runs in 1..=K {
+            // Every subset of empty runs, including all runs being empty
+            for empty in 0..1_usize << runs {
+                let lengths: Vec<usize> = (0..runs)
+                    .map(|run| {
+                        if empty & (1 << run) != 0 {
+                            0
+                        } else {
+                            RUN_LENGTHS[run % RUN_LENGTHS.len()]
+                        }
+                    })
+                    .collect();
+                let len: usize = lengths.iter().sum();
+
+                let mut elements: Box<[_]> =
+                    crate::test::IndexedOrdered::map_iter((0..len).map(|_| rng.random_range(0..4)))
+                        .collect();
+                let mut start = 0;
+                for run_len in &lengths {
+                    elements[start..start + run_len].sort();
+                    start += run_len;
+                }
+
+                // The last run takes up the rest of the slice
+                let run_lengths = &lengths[..runs - 1];
+                T::merge(&mut elements, run_lengths, buffer.as_uninit_slice_mut());
+
+                let mut indices: Box<[usize]> = elements
+                    .iter()
+                    .map(crate::test::IndexedOrdered::index)
+                    .collect();
+                indices.sort();
+                assert!(
+                    indices.into_iter().eq(0..len),
+                    "Elements got lost or duplicated by {name} with run lengths {lengths:?}",
+                    name = T::display(),
+                );
+
+                match crate::test::IndexedOrdered::is_stable_sorted(elements.iter()) {
+                    Ok(stable) => assert!(
+                        stable || !T::IS_STABLE,
+                        "Elements were not merged stable by {name} with run lengths {lengths:?}",
+                        name = T::display(),
+                    ),
+                    Err(()) => panic!(
+                        "Elements were not merged by {name} with run lengths {lengths:?}",
+                        name = T::display(),
+                    ),
+                }
+            }
+        }
+    }
+
+    /// Tests that the merging description printed by the CLI reports the stability of `T`.
+    pub fn test_display_stability<
+        T: crate::algorithms::merging::MultiMergingMethod<K>,
+        const K: usize,
+    >() {
+        let description = crate::cli::display_merging::<T, K>();
+
+        assert!(description.starts_with(&T::display()));
+        assert_eq!(description.ends_with("(stable)"), T::IS_STABLE);
+        assert_eq!(description.ends_with("(unstable)"), !T::IS_STABLE);
+    }
+
     /// Tests that random runs are correctly merged.
     pub fn test_correct_merge<
         T: crate::algorithms::merging::MultiMergingMethod<K>,
@@ -437,6 +700,55 @@ pub mod merging {
         assert!(T::IS_STABLE);
     }
 
+    /// Tests that runs sorted in descending order are merged into descending order and stable by
+    /// [`crate::algorithms::merging::MultiMergingMethod::merge_by()`] with a reversed comparator.
+    pub fn test_reversed_merge<
+        T: crate::algorithms::merging::MultiMergingMethod<K>,
+        const K: usize,
+    >() {
+        let mut rng = crate::test::test_rng();
+        let mut buffer = <Vec<_> as crate::algorithms::merging::BufGuard<_>>::with_capacity(
+            T::required_capacity(TEST_SIZE),
+        );
+        let mut run_lengths = Vec::with_capacity(K - 1);
+
+        for run in 0..TEST_RUNS {
+            let mut elements: Box<[_]> = crate::test::IndexedOrdered::map_iter(
+                (0..TEST_SIZE).map(|_| rng.random_range(0..TEST_SIZE / 4)),
+            )
+            .collect();
+
+            // Sort the runs in descending order, keeping equal elements in their initial order
+            generate_random_runs::<_, K, false>(&mut elements, &mut run_lengths, &mut rng);
+            let mut start = 0;
+            for len in &run_lengths {
+                elements[start..start + len].sort_by(|a, b| b.cmp(a));
+                start += len;
+            }
+            elements[start..].sort_by(|a, b| b.cmp(a));
+
+            T::merge_by(
+                &mut elements,
+                &run_lengths,
+                buffer.as_uninit_slice_mut(),
+                &mut |a, b| b.cmp(a),
+            );
+
+            for pair in elements.windows(2) {
+                assert!(
+                    pair[0] >= pair[1],
+                    "Elements were not merged in descending order by {name} in run {run}",
+                    name = T::display(),
+                );
+                assert!(
+                    pair[0] != pair[1] || pair[0].index() < pair[1].index() || !T::IS_STABLE,
+                    "Elements were not merged stable by {name} in run {run}",
+                    name = T::display(),
+                );
+            }
+        }
+    }
+
     /// Runs `M` with [`crate::test::RandomOrdered`] elements and
     /// [`crate::test::MaybePanickingOrdered`] elements, mostly useful for running under MIRI.
     pub fn test_soundness_merge<
@@ -455,6 +767,10 @@ pub mod merging {
             <Vec<_> as crate::algorithms::merging::BufGuard<_>>::with_capacity(
                 T::required_capacity(TEST_SIZE),
             );
+        let mut panicking_comparator_buffer =
+            <Vec<_> as crate::algorithms::merging::BufGuard<_>>::with_capacity(
+                T::required_capacity(TEST_SIZE),
+            );
         let mut run_lengths = Vec::with_capacity(K - 1);
 
         // Test RandomOrdered runs
@@ -530,5 +846,39 @@ pub mod merging {
 
             drop(elements);
         }
+
+        // Test arbitrary runs, including empty ones, merged by a comparator panicking after a
+        // random number of comparisons. The elements own allocations, so lost or duplicated
+        // elements show up as leaks or double frees.
+        for _ in 0..TEST_RUNS {
+            let mut values: Box<[u32]> = std::iter::repeat_with(|| rng.random())
+                .take(TEST_SIZE)
+                .collect();
+
+            generate_arbitrary_runs::<_, K>(&mut values, &mut run_lengths, &mut rng);
+
+            let mut elements: Box<[Box<u32>]> = values.iter().copied().map(Box::new).collect();
+            values.sort();
+
+            let mut remaining_comparisons = rng.random_range(0..TEST_SIZE * K);
+            let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                T::merge_by(
+                    &mut elements,
+                    &run_lengths,
+                    panicking_comparator_buffer.as_uninit_slice_mut(),
+                    &mut |a, b| {
+                        remaining_comparisons = remaining_comparisons
+                            .checked_sub(1)
+                            .expect("Comparator panicked during merging");
+                        a.cmp(b)
+                    },
+                );
+            }));
+
+            // Make sure no elements got lost or duplicated
+            let mut merged_values: Box<[u32]> = elements.iter().map(|element| **element).collect();
+            merged_values.sort();
+            assert_eq!(merged_values, values);
+        }
     }
 }